@@ -0,0 +1,91 @@
+//! Decoding of search pages, with the same passes the client runs on a response body.
+//!
+//! * `typed` - [`PaymentSearchResponse`], with the JSON backend of the crate.
+//! * `ignored` - [`IgnoredAny`], the cost of scanning the JSON alone.
+//! * `live_mode` - Environment check of [`MercadoPagoClientBuilder::with_expected_environment`](mpago::client::MercadoPagoClientBuilder::with_expected_environment).
+//! * `audit_redact` - Redaction of the body passed to [`MercadoPagoClientBuilder::with_audit_hook`](mpago::client::MercadoPagoClientBuilder::with_audit_hook), without any redacted field in it.
+//!
+//! Run `cargo bench` for `serde_json` and `cargo bench --features simd-json` for `simd-json`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use mpago::{bench, payments::types::PaymentSearchResponse};
+use serde::de::IgnoredAny;
+
+/// Name of the backend used to decode the responses.
+const BACKEND: &str = if cfg!(feature = "simd-json") {
+    "simd-json"
+} else {
+    "serde_json"
+};
+
+/// Builds a `/v1/payments/search` page with `size` results, shaped like the ones sent by Mercado Pago.
+fn search_page(size: usize) -> String {
+    let results = (0..size)
+        .map(|i| {
+            format!(
+                r#"{{
+                    "id": {id},
+                    "date_created": "2023-09-08T22:33:32.000-04:00",
+                    "date_approved": "2023-09-08T22:34:01.000-04:00",
+                    "date_last_update": "2023-09-08T22:34:01.000-04:00",
+                    "date_of_expiration": "2023-09-09T22:33:32.000-04:00",
+                    "operation_type": "regular_payment",
+                    "payment_method_id": "pix",
+                    "payment_type_id": "bank_transfer",
+                    "status": "approved",
+                    "status_detail": "accredited",
+                    "currency_id": "BRL",
+                    "description": "Product {id}",
+                    "live_mode": true,
+                    "authorization_code": null,
+                    "payer": {{
+                        "id": "1234567{id}",
+                        "email": "buyer{id}@testmail.com",
+                        "identification": {{ "type": "CPF", "number": "19119119100" }},
+                        "first_name": null,
+                        "last_name": null,
+                        "entity_type": null,
+                        "type": null
+                    }},
+                    "external_reference": "order-{id}",
+                    "transaction_amount": 25.9,
+                    "installments": 1,
+                    "processing_mode": "aggregator"
+                }}"#,
+                id = 10_000_000 + i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        r#"{{"paging": {{"total": {size}, "limit": {size}, "offset": 0}}, "results": [{results}]}}"#
+    )
+}
+
+fn deserialize_search_page(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("payment_search_response/{BACKEND}"));
+
+    for size in [30, 300, 1000] {
+        let page = search_page(size).into_bytes();
+
+        group.throughput(Throughput::Bytes(page.len() as u64));
+        group.bench_function(BenchmarkId::new("typed", size), |b| {
+            b.iter(|| bench::parse_body::<PaymentSearchResponse>(page.clone()).unwrap())
+        });
+        group.bench_function(BenchmarkId::new("ignored", size), |b| {
+            b.iter(|| bench::parse_body::<IgnoredAny>(page.clone()).unwrap())
+        });
+        group.bench_function(BenchmarkId::new("live_mode", size), |b| {
+            b.iter(|| bench::live_mode(black_box(&page)))
+        });
+        group.bench_function(BenchmarkId::new("audit_redact", size), |b| {
+            b.iter(|| bench::redact(black_box(&page)).len())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, deserialize_search_page);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+use std::time::Instant;
+
+use mpago::{
+    audit::AuditEvent,
+    client::{MercadoPagoClient, MercadoPagoClientBuilder},
+};
+
+/// Client with the access token of `MERCADO_PAGO_ACCESS` (also read from `.env`), printing every request and response with the time since the start.
+pub fn instrumented_client() -> MercadoPagoClient {
+    dotenvy::dotenv().ok();
+
+    let access_token =
+        std::env::var("MERCADO_PAGO_ACCESS").expect("MERCADO_PAGO_ACCESS is not set");
+    let start = Instant::now();
+
+    MercadoPagoClientBuilder::builder(access_token)
+        .with_audit_hook(move |event: AuditEvent<'_>| {
+            let elapsed = start.elapsed().as_millis();
+            match event {
+                AuditEvent::Request { method, url, body } => {
+                    eprintln!("[{elapsed:>6}ms] -> {method} {url} ({} bytes)", body.len())
+                }
+                AuditEvent::Response { url, status, body } => {
+                    eprintln!("[{elapsed:>6}ms] <- {status} {url} ({} bytes)", body.len())
+                }
+            }
+        })
+        .build()
+}
+
+/// Value of the environment variable `name`, exiting with a message when it's not set.
+// Each example compiles its own copy of this module, and not all of them read variables.
+#[allow(dead_code)]
+pub fn env(name: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| {
+        eprintln!("{name} is not set");
+        std::process::exit(1)
+    })
+}
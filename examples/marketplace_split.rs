@@ -0,0 +1,66 @@
+//! Charges a card and splits the money between sellers, keeping 5% for the marketplace.
+//!
+//! `SELLERS` has the user id and the subtotal of each seller, and every seller must have authorized the application with OAuth. `CARD_TOKEN` is a card token of a test card.
+//!
+//! ```sh
+//! MERCADO_PAGO_ACCESS=TEST-... PAYER_EMAIL=test_user@testuser.com CARD_TOKEN=... SELLERS=1822339427:60,1822339428:40 \
+//!     cargo run --example marketplace_split --features flows
+//! ```
+
+mod common;
+
+use mpago::{
+    advanced_payments::types::{
+        AdvancedPaymentCreateOptions, AdvancedPaymentItem, AdvancedPaymentPayer,
+    },
+    flows::marketplace,
+    payments::types::PaymentMethodId,
+    Decimal,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mp_client = common::instrumented_client();
+
+    let sellers = common::env("SELLERS")
+        .split(',')
+        .map(|seller| {
+            let (user_id, subtotal) = seller.split_once(':').expect("SELLERS is id:subtotal,...");
+            Ok((
+                user_id.trim().parse::<u64>()?.into(),
+                subtotal.trim().parse()?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    let total = sellers
+        .iter()
+        .map(|(_, subtotal)| *subtotal)
+        .sum::<Decimal>();
+
+    let payment = marketplace::create_split_payment(
+        &mp_client,
+        AdvancedPaymentCreateOptions {
+            payer: AdvancedPaymentPayer {
+                email: common::env("PAYER_EMAIL"),
+                ..Default::default()
+            },
+            payments: vec![AdvancedPaymentItem::card(
+                common::env("CARD_TOKEN"),
+                PaymentMethodId::MasterCard,
+                total,
+                1,
+            )],
+            disbursements: marketplace::split(total, &sellers, Decimal::new(5, 2)),
+            ..Default::default()
+        },
+        format!("example-{}", std::process::id()),
+    )
+    .await?;
+
+    println!("Advanced payment {} is {:?}", payment.id, payment.status);
+    for disbursement in &payment.disbursements {
+        println!("{disbursement:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! Creates a Pix charge and waits for it to be paid.
+//!
+//! A real integration confirms the payment in the webhook handler with `pix::verified_payment_id` and `pix::confirm`, here the payment is polled instead, so no public URL is needed.
+//!
+//! ```sh
+//! MERCADO_PAGO_ACCESS=TEST-... PAYER_EMAIL=test_user@testuser.com cargo run --example pix_checkout --features flows
+//! ```
+
+mod common;
+
+use std::time::Duration;
+
+use mpago::{flows::pix, Decimal};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mp_client = common::instrumented_client();
+    let order_id = format!("example-{}", std::process::id());
+
+    let charge = pix::create_charge(
+        &mp_client,
+        pix::charge_options(
+            Decimal::new(1000, 2),
+            common::env("PAYER_EMAIL"),
+            "Example order",
+        ),
+        &order_id,
+    )
+    .await?;
+
+    println!("Payment {} created, pay it with:", charge.payment_id);
+    println!("{}", charge.qr_code);
+    if let Some(ticket_url) = &charge.ticket_url {
+        println!("or at {ticket_url}");
+    }
+
+    for _ in 0..60 {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        let payment = pix::confirm(&mp_client, charge.payment_id).await?;
+        if pix::is_paid(&payment) {
+            println!("Paid, order {order_id} released");
+            return Ok(());
+        }
+    }
+
+    println!("Not paid after 5 minutes");
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Creates a monthly plan and prints its checkout. With the id of a subscription as argument, checks if it is active instead.
+//!
+//! A real integration confirms the subscription in the webhook handler with `subscription::verified_subscription_id` and `subscription::confirm`.
+//!
+//! ```sh
+//! MERCADO_PAGO_ACCESS=TEST-... cargo run --example subscription_signup --features flows
+//! MERCADO_PAGO_ACCESS=TEST-... cargo run --example subscription_signup --features flows -- 2c938084726fca480172750000000000
+//! ```
+
+mod common;
+
+use mpago::{flows::subscription, payments::types::CurrencyId, Decimal};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mp_client = common::instrumented_client();
+
+    if let Some(subscription_id) = std::env::args().nth(1) {
+        let subscription = subscription::confirm(&mp_client, subscription_id.into()).await?;
+
+        println!(
+            "Subscription {} is {:?}, active: {}",
+            subscription.id,
+            subscription.status,
+            subscription::is_active(&subscription)
+        );
+        return Ok(());
+    }
+
+    let signup = subscription::create_signup(
+        &mp_client,
+        subscription::plan_options(
+            "Example monthly plan",
+            Decimal::new(50, 0),
+            CurrencyId::BRL,
+            "https://example.com/subscribed",
+        ),
+    )
+    .await?;
+
+    println!("Plan {} created, subscribe at:", signup.plan_id);
+    println!("{}", signup.checkout_url);
+
+    Ok(())
+}
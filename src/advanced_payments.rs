@@ -0,0 +1,4 @@
+pub use self::create_builder::AdvancedPaymentCreateBuilder;
+
+mod create_builder;
+pub mod types;
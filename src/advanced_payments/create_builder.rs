@@ -0,0 +1,176 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{
+    AdvancedPaymentCreateOptions, AdvancedPaymentResponse, AdvancedPaymentValidationError,
+};
+
+/// Builder for creating an advanced payment, splitting the money between sellers of a marketplace
+///
+/// The payer pays the `payments` and the money is split in the `disbursements`, each one to a seller that authorized your application with OAuth.
+///
+/// # Arguments
+///
+/// * `options` - Options to create the advanced payment.
+/// * `idempotency_key` - Idempotency key is a unique value that is used to prevent duplicate processing of requests
+///
+/// # Example
+/// ```
+/// use mpago::advanced_payments::{types::*, AdvancedPaymentCreateBuilder};
+///
+/// AdvancedPaymentCreateBuilder(
+///     AdvancedPaymentCreateOptions {
+///         payer: AdvancedPaymentPayer {
+///             email: "test_user@testmail.com".to_string(),
+///             ..Default::default()
+///         },
+///         payments: vec![AdvancedPaymentItem::card(
+///             card_token,
+///             PaymentMethodId::MasterCard,
+///             Decimal::new(100, 0),
+///             1,
+///         )],
+///         disbursements: vec![
+///             Disbursement::new(Decimal::new(60, 0), 1822339427).with_application_fee(Decimal::new(3, 0)),
+///             Disbursement::new(Decimal::new(40, 0), 1822339428),
+///         ],
+///         ..Default::default()
+///     },
+///     Some("order-9823".to_string()),
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/advanced_payments/_advanced_payments/post>
+#[derive(Debug, Clone)]
+pub struct AdvancedPaymentCreateBuilder(pub AdvancedPaymentCreateOptions, pub Option<String>);
+
+impl AdvancedPaymentCreateBuilder {
+    /// Checks that there are payments and disbursements, and that the disbursements add up to the amount paid.
+    pub fn validate(&self) -> Result<(), AdvancedPaymentValidationError> {
+        let options = &self.0;
+
+        if options.payments.is_empty() {
+            return Err(AdvancedPaymentValidationError::NoPayments);
+        }
+        if options.disbursements.is_empty() {
+            return Err(AdvancedPaymentValidationError::NoDisbursements);
+        }
+
+        let payments = options.payments.iter().map(|p| p.transaction_amount).sum();
+        let disbursements = options.disbursements.iter().map(|d| d.amount).sum();
+        if payments != disbursements {
+            return Err(AdvancedPaymentValidationError::AmountMismatch {
+                payments,
+                disbursements,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<AdvancedPaymentResponse, MercadoPagoRequestError> {
+        let mut req = mp_client.json_body(
+            mp_client.start_request(Method::POST, "/v1/advanced_payments"),
+            &self.0,
+        )?;
+
+        if let Some(idempotency_key) = self.1 {
+            req = req.header("X-Idempotency-Key", idempotency_key);
+        }
+
+        let res = mp_client.execute(req).await?;
+
+        mp_client.resolve_json::<AdvancedPaymentResponse>(res).await
+    }
+}
+
+impl SendRequest for AdvancedPaymentCreateBuilder {
+    type Output = AdvancedPaymentResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        AdvancedPaymentCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use rust_decimal::Decimal;
+
+    use super::AdvancedPaymentCreateBuilder;
+    use crate::{
+        advanced_payments::types::{
+            AdvancedPaymentCreateOptions, AdvancedPaymentItem, AdvancedPaymentValidationError,
+            Disbursement,
+        },
+        payments::types::PaymentMethodId,
+    };
+
+    fn builder(payment: i64, disbursements: &[i64]) -> AdvancedPaymentCreateBuilder {
+        AdvancedPaymentCreateBuilder(
+            AdvancedPaymentCreateOptions {
+                payments: vec![AdvancedPaymentItem::card(
+                    "token",
+                    PaymentMethodId::Visa,
+                    Decimal::new(payment, 0),
+                    1,
+                )],
+                disbursements: disbursements
+                    .iter()
+                    .map(|amount| Disbursement::new(Decimal::new(*amount, 0), 1))
+                    .collect(),
+                ..Default::default()
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn disbursements_must_add_up() {
+        assert!(builder(100, &[60, 40]).validate().is_ok());
+        assert_eq!(
+            builder(100, &[60, 30]).validate(),
+            Err(AdvancedPaymentValidationError::AmountMismatch {
+                payments: Decimal::new(100, 0),
+                disbursements: Decimal::new(90, 0),
+            })
+        );
+        assert_eq!(
+            builder(100, &[]).validate(),
+            Err(AdvancedPaymentValidationError::NoDisbursements)
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::AdvancedPaymentCreateBuilder;
+    use crate::{
+        advanced_payments::types::AdvancedPaymentCreateOptions, common::create_test_client,
+    };
+
+    #[tokio::test]
+    async fn fail_without_payments() {
+        let mp_client = create_test_client();
+
+        let res = AdvancedPaymentCreateBuilder(AdvancedPaymentCreateOptions::default(), None)
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
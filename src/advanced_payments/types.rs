@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+use thiserror::Error;
+
+use crate::{
+    ids::{AdvancedPaymentId, PaymentId, UserId},
+    payer::PayerIdentification,
+    payments::types::{AdditionalInfo, PaymentMethodId, PaymentStatus, PaymentTypeId},
+};
+
+/// # AdvancedPaymentCreateOptions
+/// Struct to use in [`AdvancedPaymentCreateBuilder`](crate::advanced_payments::AdvancedPaymentCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/advanced_payments/_advanced_payments/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct AdvancedPaymentCreateOptions {
+    /// Identifier of your marketplace application.
+    pub application_id: Option<String>,
+    pub payer: AdvancedPaymentPayer,
+    /// Payments made by the payer, usually only one.
+    pub payments: Vec<AdvancedPaymentItem>,
+    /// How the money of the payments is split between the sellers.
+    pub disbursements: Vec<Disbursement>,
+    pub external_reference: Option<String>,
+    pub description: Option<String>,
+    /// When `true`, payments are approved or rejected instantly, never pending.
+    pub binary_mode: Option<bool>,
+    /// When `false`, the payments are only authorized and must be captured later.
+    pub capture: Option<bool>,
+    pub additional_info: Option<AdditionalInfo>,
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Payer of an advanced payment.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct AdvancedPaymentPayer {
+    /// Customer id, when paying with a saved card.
+    pub id: Option<String>,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub identification: Option<PayerIdentification>,
+}
+
+/// Payment made by the payer in an advanced payment.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AdvancedPaymentItem {
+    pub payment_method_id: PaymentMethodId,
+    pub payment_type_id: PaymentTypeId,
+    /// Card token, required for card payments.
+    pub token: Option<String>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub transaction_amount: Decimal,
+    pub installments: Option<u32>,
+    pub processing_mode: Option<String>,
+    pub description: Option<String>,
+    pub external_reference: Option<String>,
+    /// Description that the payment will appear with in the card statement.
+    pub statement_descriptor: Option<String>,
+}
+
+impl AdvancedPaymentItem {
+    /// Card payment of `transaction_amount` with the card `token`.
+    pub fn card(
+        token: impl ToString,
+        payment_method_id: PaymentMethodId,
+        transaction_amount: Decimal,
+        installments: u32,
+    ) -> Self {
+        AdvancedPaymentItem {
+            payment_method_id,
+            payment_type_id: PaymentTypeId::CreditCard,
+            token: Some(token.to_string()),
+            transaction_amount,
+            installments: Some(installments),
+            processing_mode: None,
+            description: None,
+            external_reference: None,
+            statement_descriptor: None,
+        }
+    }
+}
+
+/// Part of the money sent to a seller.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Disbursement {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    /// Mercado Pago user id of the seller, the `user_id` of its OAuth access token.
+    pub collector_id: UserId,
+    /// Commission of the marketplace, discounted from `amount`.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub application_fee: Option<Decimal>,
+    /// Days until the money is released to the seller.
+    pub money_release_days: Option<u32>,
+    pub external_reference: Option<String>,
+}
+
+impl Disbursement {
+    /// Sends `amount` to the seller `collector_id`, without commission.
+    pub fn new(amount: Decimal, collector_id: impl Into<UserId>) -> Self {
+        Disbursement {
+            amount,
+            collector_id: collector_id.into(),
+            application_fee: None,
+            money_release_days: None,
+            external_reference: None,
+        }
+    }
+
+    /// Sets `application_fee`
+    pub fn with_application_fee(mut self, application_fee: Decimal) -> Self {
+        self.application_fee = Some(application_fee);
+
+        self
+    }
+}
+
+/// # AdvancedPaymentResponse
+/// Advanced payment returned by `/v1/advanced_payments`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/advanced_payments/_advanced_payments_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AdvancedPaymentResponse {
+    pub id: AdvancedPaymentId,
+    pub status: AdvancedPaymentStatus,
+    pub external_reference: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub payments: Vec<AdvancedPaymentResult>,
+    #[serde(default)]
+    pub disbursements: Vec<DisbursementResult>,
+    pub capture: Option<bool>,
+    pub binary_mode: Option<bool>,
+    /// Advanced payment create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// Date when advanced payment was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_last_updated: Option<String>,
+}
+
+/// Status of an advanced payment
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvancedPaymentStatus {
+    Approved,
+    /// Payments authorized, waiting to be captured.
+    Authorized,
+    InProcess,
+    Pending,
+    Rejected,
+    Cancelled,
+    Refunded,
+    /// For untracked advanced payment status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Payment of an [`AdvancedPaymentResponse`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AdvancedPaymentResult {
+    pub id: PaymentId,
+    pub status: PaymentStatus,
+    pub status_detail: Option<String>,
+    pub payment_method_id: Option<PaymentMethodId>,
+    pub payment_type_id: Option<PaymentTypeId>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub transaction_amount: Decimal,
+    pub installments: Option<u32>,
+}
+
+/// Disbursement of an [`AdvancedPaymentResponse`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DisbursementResult {
+    pub id: u64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    pub collector_id: UserId,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub application_fee: Option<Decimal>,
+    pub external_reference: Option<String>,
+    /// Date when the money is released to the seller. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub money_release_date: Option<String>,
+}
+
+/// Error when validating an [`AdvancedPaymentCreateBuilder`](crate::advanced_payments::AdvancedPaymentCreateBuilder)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AdvancedPaymentValidationError {
+    #[error("At least one payment is required")]
+    NoPayments,
+    #[error("At least one disbursement is required")]
+    NoDisbursements,
+    #[error("Disbursements add up to {disbursements}, but the payments to {payments}")]
+    AmountMismatch {
+        payments: Decimal,
+        disbursements: Decimal,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{AdvancedPaymentResponse, AdvancedPaymentStatus};
+
+    #[test]
+    fn deserialize_response() {
+        let response: AdvancedPaymentResponse = serde_json::from_value(serde_json::json!({
+            "id": 10234567,
+            "status": "approved",
+            "payments": [{
+                "id": 1319541965,
+                "status": "approved",
+                "status_detail": "accredited",
+                "payment_method_id": "master",
+                "payment_type_id": "credit_card",
+                "transaction_amount": 100,
+                "installments": 1,
+            }],
+            "disbursements": [{
+                "id": 4521,
+                "amount": 60,
+                "collector_id": 1822339427,
+                "application_fee": 3,
+                "money_release_date": "2024-06-15T10:00:00.000-04:00",
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(response.status, AdvancedPaymentStatus::Approved);
+        assert_eq!(
+            response.disbursements[0].application_fee,
+            Some(Decimal::new(3, 0))
+        );
+    }
+}
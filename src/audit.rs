@@ -0,0 +1,237 @@
+use std::{borrow::Cow, sync::Arc};
+
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::client::RateLimit;
+
+/// Fields replaced by [`REDACTED`] before a payload reaches the [`AuditHook`], at any depth of the body.
+///
+/// More fields can be added with [`MercadoPagoClientBuilder::with_audit_redaction`](crate::client::MercadoPagoClientBuilder::with_audit_redaction).
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "access_token",
+    "card_number",
+    "client_secret",
+    "code_verifier",
+    "refresh_token",
+    "security_code",
+];
+
+/// Value written in place of a redacted field.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A payload sent to or received from Mercado Pago, as seen by an [`AuditHook`].
+#[derive(Debug, Clone, Copy)]
+pub enum AuditEvent<'a> {
+    /// JSON body of a request, right before it is sent.
+    Request {
+        method: &'a Method,
+        url: &'a str,
+        body: &'a [u8],
+    },
+    /// Body of a JSON response, right before it is deserialized. Error responses are included.
+    Response {
+        url: &'a str,
+        status: u16,
+        body: &'a [u8],
+    },
+}
+
+/// Receives the serialized bytes of requests and responses, set with [`MercadoPagoClientBuilder::with_audit_hook`](crate::client::MercadoPagoClientBuilder::with_audit_hook).
+///
+/// Bodies without any redacted field are passed byte for byte as transmitted. When a field is redacted the body is serialized again, so key order and whitespace may differ.
+///
+/// Implemented for closures, so `|event: AuditEvent<'_>| ...` is a valid hook.
+pub trait AuditHook: Send + Sync {
+    fn audit(&self, event: AuditEvent<'_>);
+
+    /// Receives the `x-ratelimit-*` headers of every response that has them, see [`MercadoPagoClient::rate_limit`](crate::client::MercadoPagoClient::rate_limit). Ignored by default.
+    fn rate_limit(&self, url: &str, rate_limit: &RateLimit) {
+        let _ = (url, rate_limit);
+    }
+}
+
+impl<F> AuditHook for F
+where
+    F: Fn(AuditEvent<'_>) + Send + Sync,
+{
+    fn audit(&self, event: AuditEvent<'_>) {
+        self(event)
+    }
+}
+
+/// [`AuditHook`] with the fields it should not see.
+#[derive(Clone)]
+pub(crate) struct Auditor {
+    hook: Arc<dyn AuditHook>,
+    redacted_fields: Vec<String>,
+}
+
+impl Auditor {
+    pub(crate) fn new(hook: Arc<dyn AuditHook>) -> Self {
+        Auditor {
+            hook,
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn redact_field(&mut self, field: String) {
+        if !self.redacted_fields.contains(&field) {
+            self.redacted_fields.push(field);
+        }
+    }
+
+    pub(crate) fn request(&self, method: &Method, url: &str, body: &[u8]) {
+        self.hook.audit(AuditEvent::Request {
+            method,
+            url,
+            body: &self.redact(body),
+        });
+    }
+
+    pub(crate) fn rate_limit(&self, url: &str, rate_limit: &RateLimit) {
+        self.hook.rate_limit(url, rate_limit);
+    }
+
+    pub(crate) fn response(&self, url: &str, status: u16, body: &[u8]) {
+        self.hook.audit(AuditEvent::Response {
+            url,
+            status,
+            body: &self.redact(body),
+        });
+    }
+
+    /// Returns `body` untouched unless it is JSON with a redacted field.
+    pub(crate) fn redact<'b>(&self, body: &'b [u8]) -> Cow<'b, [u8]> {
+        if !self.may_have_redacted_field(body) {
+            return Cow::Borrowed(body);
+        }
+
+        let Ok(mut value) = serde_json::from_slice::<Value>(body) else {
+            return Cow::Borrowed(body);
+        };
+
+        if self.redact_value(&mut value) {
+            serde_json::to_vec(&value).map_or(Cow::Borrowed(body), Cow::Owned)
+        } else {
+            Cow::Borrowed(body)
+        }
+    }
+
+    /// Whether a redacted field is quoted in `body`, so it has to be parsed to redact it.
+    ///
+    /// Escaped strings may spell a field with escapes, or hide where the strings end, so bodies with a `\` are always parsed.
+    fn may_have_redacted_field(&self, body: &[u8]) -> bool {
+        body.split(|byte| *byte == b'"').any(|segment| {
+            segment.contains(&b'\\')
+                || self
+                    .redacted_fields
+                    .iter()
+                    .any(|field| field.as_bytes() == segment)
+        })
+    }
+
+    fn redact_value(&self, value: &mut Value) -> bool {
+        match value {
+            Value::Object(map) => {
+                let mut redacted = false;
+                for (key, value) in map.iter_mut() {
+                    if self.redacted_fields.iter().any(|field| field == key) {
+                        *value = Value::String(REDACTED.to_string());
+                        redacted = true;
+                    } else {
+                        redacted |= self.redact_value(value);
+                    }
+                }
+                redacted
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |redacted, value| self.redact_value(value) | redacted),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::Method;
+
+    use super::{AuditEvent, Auditor};
+
+    fn recording() -> (Auditor, Arc<Mutex<Vec<String>>>) {
+        let bodies = Arc::new(Mutex::new(vec![]));
+        let hook_bodies = bodies.clone();
+        let auditor = Auditor::new(Arc::new(move |event: AuditEvent<'_>| {
+            let body = match event {
+                AuditEvent::Request { body, .. } | AuditEvent::Response { body, .. } => body,
+            };
+            hook_bodies
+                .lock()
+                .unwrap()
+                .push(String::from_utf8(body.to_vec()).unwrap());
+        }));
+
+        (auditor, bodies)
+    }
+
+    #[test]
+    fn keeps_exact_bytes_without_redacted_fields() {
+        let (auditor, bodies) = recording();
+
+        let body =
+            br#"{ "transaction_amount": 10.0,  "token": "ff8080814c11e237014c1ff593b57b4d" }"#;
+        auditor.request(
+            &Method::POST,
+            "https://api.mercadopago.com/v1/payments",
+            body,
+        );
+        auditor.response("https://api.mercadopago.com/v1/payments", 201, b"not json");
+
+        assert_eq!(
+            *bodies.lock().unwrap(),
+            vec![
+                String::from_utf8(body.to_vec()).unwrap(),
+                "not json".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn redacts_nested_fields() {
+        let (mut auditor, bodies) = recording();
+        auditor.redact_field("email".to_string());
+
+        auditor.request(
+            &Method::POST,
+            "https://api.mercadopago.com/v1/card_tokens",
+            br#"{"card_number":"5031433215406351","cardholder":{"name":"APRO"},"payer":[{"email":"test@test.com","security_code":"123"}]}"#,
+        );
+
+        assert_eq!(
+            *bodies.lock().unwrap(),
+            vec![r#"{"card_number":"[REDACTED]","cardholder":{"name":"APRO"},"payer":[{"email":"[REDACTED]","security_code":"[REDACTED]"}]}"#.to_string()]
+        );
+    }
+
+    #[test]
+    fn redacts_fields_spelled_with_escapes() {
+        let (auditor, bodies) = recording();
+
+        auditor.response(
+            "https://api.mercadopago.com/v1/card_tokens",
+            201,
+            br#"{"card_number":"5031433215406351","status":"active"}"#,
+        );
+
+        assert_eq!(
+            *bodies.lock().unwrap(),
+            vec![r#"{"card_number":"[REDACTED]","status":"active"}"#.to_string()]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use futures_util::{stream, StreamExt};
+use tokio::time::{sleep_until, Instant};
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    payments::types::PaymentResponse,
+    refunds::types::RefundResponse,
+};
+
+type Operation<'a, T> = Box<
+    dyn FnOnce(
+            &'a MercadoPagoClient,
+        )
+            -> Pin<Box<dyn Future<Output = Result<T, MercadoPagoRequestError>> + Send + 'a>>
+        + Send
+        + 'a,
+>;
+
+/// Executes a mixed set of operations with shared rate limiting, returning one result per operation.
+///
+/// Every operation is a [`SendRequest`] whose output can be converted into `T`. [`BatchOutput`] covers the builders of this crate, but any type with the needed `From` implementations works.
+///
+/// Requires a tokio runtime with the time driver enabled.
+///
+/// # Example
+/// ```
+/// use mpago::{
+///     batch::{Batch, BatchOutput},
+///     payments::PaymentGetBuilder,
+///     refunds::RefundCreateBuilder,
+/// };
+///
+/// let results = Batch::<BatchOutput>::new()
+///     .with_concurrency(4)
+///     .with_min_interval(Duration::from_millis(100))
+///     .push(PaymentGetBuilder(87891224.into()))
+///     .push(RefundCreateBuilder {
+///         payment_id: 8972364.into(),
+///         amount: None,
+///         idempotency_key: Some("refund-8972364".to_string()),
+///     })
+///     .execute(&mp_client)
+///     .await;
+/// ```
+pub struct Batch<'a, T> {
+    operations: Vec<Operation<'a, T>>,
+    concurrency: usize,
+    min_interval: Option<Duration>,
+}
+
+impl<'a, T: 'a> Batch<'a, T> {
+    /// Create an empty batch, running one operation at a time with no interval.
+    pub fn new() -> Self {
+        Batch {
+            operations: vec![],
+            concurrency: 1,
+            min_interval: None,
+        }
+    }
+
+    /// Maximum number of operations running at the same time.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+
+        self
+    }
+
+    /// Minimum time between the start of two operations, to respect Mercado Pago's rate limits.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = Some(min_interval);
+
+        self
+    }
+
+    /// Add an operation to the batch
+    pub fn push<R>(mut self, request: R) -> Self
+    where
+        R: SendRequest + Send + 'a,
+        R::Output: Into<T>,
+    {
+        self.operations.push(Box::new(move |mp_client| {
+            Box::pin(async move { request.send(mp_client).await.map(Into::into) })
+        }));
+
+        self
+    }
+
+    /// Number of operations in the batch
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Checks if there are no operations in the batch
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Run every operation, returning the results in the same order they were pushed.
+    ///
+    /// A failed operation doesn't stop the others.
+    pub async fn execute(
+        self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Vec<Result<T, MercadoPagoRequestError>> {
+        let start = Instant::now();
+        let min_interval = self.min_interval;
+
+        stream::iter(self.operations.into_iter().enumerate())
+            .map(|(index, operation)| async move {
+                if let Some(min_interval) = min_interval {
+                    sleep_until(start + min_interval * index as u32).await;
+                }
+
+                operation(mp_client).await
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
+
+impl<'a, T: 'a> Default for Batch<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Output of the operations of this crate, to be used with [`Batch`].
+#[derive(Debug, Clone)]
+pub enum BatchOutput {
+    Payment(Box<PaymentResponse>),
+    Refund(Box<RefundResponse>),
+}
+
+impl From<PaymentResponse> for BatchOutput {
+    fn from(value: PaymentResponse) -> Self {
+        Self::Payment(Box::new(value))
+    }
+}
+
+impl From<RefundResponse> for BatchOutput {
+    fn from(value: RefundResponse) -> Self {
+        Self::Refund(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::Batch;
+    use crate::{
+        client::{MercadoPagoClient, MercadoPagoClientBuilder, SendRequest},
+        common::MercadoPagoRequestError,
+    };
+
+    /// Operation answering after `delay`, without touching the network.
+    struct Delayed {
+        value: u32,
+        delay: Duration,
+    }
+
+    impl SendRequest for Delayed {
+        type Output = u32;
+
+        async fn send(
+            self,
+            _: &MercadoPagoClient,
+        ) -> Result<Self::Output, MercadoPagoRequestError> {
+            tokio::time::sleep(self.delay).await;
+            Ok(self.value)
+        }
+    }
+
+    #[tokio::test]
+    async fn results_keep_push_order() {
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+
+        let results = Batch::<u64>::new()
+            .with_concurrency(3)
+            .push(Delayed {
+                value: 1,
+                delay: Duration::from_millis(30),
+            })
+            .push(Delayed {
+                value: 2,
+                delay: Duration::from_millis(10),
+            })
+            .push(Delayed {
+                value: 3,
+                delay: Duration::ZERO,
+            })
+            .execute(&mp_client)
+            .await;
+
+        let values = results.into_iter().map(Result::unwrap).collect::<Vec<_>>();
+
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn operations_are_spaced_by_min_interval() {
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        let start = Instant::now();
+
+        let mut batch = Batch::<u32>::new()
+            .with_concurrency(10)
+            .with_min_interval(Duration::from_millis(20));
+
+        for value in 0..4 {
+            batch = batch.push(Delayed {
+                value,
+                delay: Duration::ZERO,
+            });
+        }
+
+        assert_eq!(batch.len(), 4);
+
+        batch.execute(&mp_client).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+}
@@ -0,0 +1,28 @@
+//! Decoding passes of [`MercadoPagoClient`](crate::client::MercadoPagoClient), called directly by the benchmarks of the crate.
+//!
+//! Not part of the public API, it can change in any release.
+
+use std::{borrow::Cow, sync::Arc};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    audit::{AuditEvent, Auditor},
+    client::{Environment, LiveModeProbe},
+    common::MercadoPagoRequestError,
+};
+
+/// Deserializes a response body with the JSON backend of the crate.
+pub fn parse_body<T: DeserializeOwned>(body: Vec<u8>) -> Result<T, MercadoPagoRequestError> {
+    crate::common::parse_body(body)
+}
+
+/// Environment of a response body, read by [`MercadoPagoClientBuilder::with_expected_environment`](crate::client::MercadoPagoClientBuilder::with_expected_environment).
+pub fn live_mode(body: &[u8]) -> Option<Environment> {
+    LiveModeProbe::environment(body)
+}
+
+/// Body passed to the audit hook, with the [default redacted fields](crate::audit::DEFAULT_REDACTED_FIELDS).
+pub fn redact(body: &[u8]) -> Cow<'_, [u8]> {
+    Auditor::new(Arc::new(|_: AuditEvent<'_>| {})).redact(body)
+}
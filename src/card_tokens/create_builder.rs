@@ -0,0 +1,170 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::CardId,
+};
+
+use super::types::{CardTokenCreateOptions, CardTokenResponse};
+
+/// Builder for creating a card token
+///
+/// # Arguments
+///
+/// * `options` - Options to create the card token.
+///
+/// # Example
+/// ```
+/// use mpago::card_tokens::{types::CardTokenCreateOptions, CardTokenCreateBuilder};
+///
+/// CardTokenCreateBuilder(CardTokenCreateOptions {
+///     card_number: Some("5031433215406351".to_string()),
+///     security_code: Some("123".to_string()),
+///     expiration_month: Some(11),
+///     expiration_year: Some(2030),
+///     cardholder: Some(CardTokenCardholder {
+///         name: Some("APRO".to_string()),
+///         identification: None,
+///     }),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/card_tokens/_card_tokens/post>
+#[derive(Debug, Clone)]
+pub struct CardTokenCreateBuilder(pub CardTokenCreateOptions);
+
+impl CardTokenCreateBuilder {
+    /// Returns a [`CardTokenCreateBuilder`] for a card saved for a customer
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - Identifier of the saved card.
+    /// * `security_code` - Security code (CVV) of the card, typed by the payer.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::card_tokens::CardTokenCreateBuilder;
+    ///
+    /// CardTokenCreateBuilder::from_saved_card("9176235123", "123")
+    /// ```
+    pub fn from_saved_card(card_id: impl Into<CardId>, security_code: impl ToString) -> Self {
+        CardTokenCreateBuilder(CardTokenCreateOptions {
+            card_id: Some(card_id.into()),
+            security_code: Some(security_code.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<CardTokenResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/v1/card_tokens"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<CardTokenResponse>(res).await
+    }
+}
+
+impl SendRequest for CardTokenCreateBuilder {
+    type Output = CardTokenResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        CardTokenCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+mod audit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::CardTokenCreateBuilder;
+    use crate::{
+        audit::AuditEvent,
+        card_tokens::types::CardTokenCreateOptions,
+        client::MercadoPagoClientBuilder,
+        test_support::{serve, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn card_data_reaches_audit_hook_redacted() {
+        let url = serve(|request| {
+            assert!(String::from_utf8_lossy(&request.body).contains("5031433215406351"));
+
+            MockResponse::json(
+                "400 Bad Request",
+                serde_json::json!({
+                    "message": "invalid card",
+                    "error": "bad_request",
+                    "status": 400,
+                    "cause": []
+                }),
+            )
+        })
+        .await;
+
+        let audited = Arc::new(Mutex::new(vec![]));
+        let hook_audited = audited.clone();
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .with_audit_hook(move |event: AuditEvent<'_>| {
+                if let AuditEvent::Request { body, .. } = event {
+                    hook_audited
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(body).to_string());
+                }
+            })
+            .build();
+
+        let res = CardTokenCreateBuilder(CardTokenCreateOptions {
+            card_number: Some("5031433215406351".to_string()),
+            security_code: Some("123".to_string()),
+            expiration_month: Some(11),
+            expiration_year: Some(2030),
+            ..Default::default()
+        })
+        .send(&client)
+        .await;
+
+        assert!(res.is_err());
+        assert_eq!(
+            *audited.lock().unwrap(),
+            [
+                r#"{"card_number":"[REDACTED]","expiration_month":11,"expiration_year":2030,"security_code":"[REDACTED]"}"#
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use crate::common::create_test_client;
+
+    use super::CardTokenCreateBuilder;
+
+    #[tokio::test]
+    async fn fail_card_token_create() {
+        let mp_client = create_test_client();
+
+        let res = CardTokenCreateBuilder::from_saved_card("1234567890", "123")
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{ids::CardId, payer::PayerIdentification};
+
+/// # CardTokenCreateOptions
+/// Struct to use in [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/card_tokens/_card_tokens/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct CardTokenCreateOptions {
+    /// Identifier of a card saved for a customer. Used instead of the card data.
+    pub card_id: Option<CardId>,
+    /// Security code (CVV) of the card.
+    pub security_code: Option<String>,
+    /// Card number, without spaces.
+    pub card_number: Option<String>,
+    pub expiration_month: Option<u8>,
+    pub expiration_year: Option<u16>,
+    /// Cardholder data, as printed on the card.
+    pub cardholder: Option<CardTokenCardholder>,
+}
+
+/// # CardTokenResponse
+/// Response from `/v1/card_tokens`
+///
+/// The token `id` is used in [`PaymentCreateOptions::token`](crate::payments::types::PaymentCreateOptions::token). It can only be used once.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CardTokenResponse {
+    /// Card token, used to create a payment.
+    pub id: String,
+    /// Identifier of the saved card the token was created from.
+    pub card_id: Option<CardId>,
+    /// Status of the token, usually `"active"`.
+    pub status: Option<String>,
+    /// BIN (Bank Identification Number) of the card.
+    pub first_six_digits: Option<String>,
+    pub last_four_digits: Option<String>,
+    pub expiration_month: Option<u8>,
+    pub expiration_year: Option<u16>,
+    pub card_number_length: Option<u8>,
+    pub security_code_length: Option<u8>,
+    /// Whether the card number passed the Luhn check.
+    pub luhn_validation: Option<bool>,
+    pub cardholder: Option<CardTokenCardholder>,
+    /// Token create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// Date when the token expires. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_due: Option<String>,
+    pub live_mode: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CardTokenCardholder {
+    pub name: Option<String>,
+    pub identification: Option<PayerIdentification>,
+}
@@ -1,20 +1,269 @@
-use reqwest::Method;
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex},
+};
+
+use futures_util::future::{select, BoxFuture, Either};
+use reqwest::{
+    header::HeaderMap, redirect, IntoUrl, Method, RequestBuilder, Response, StatusCode, Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{
-    common::{MercadoPagoError, MercadoPagoRequestError},
+    audit::{AuditHook, Auditor},
+    common::{
+        parse_body, parse_error, parse_error_body, request_id, resolve_empty, resolve_json,
+        MercadoPagoRequestError,
+    },
     API_BASE_URL,
 };
 
 /// Client for Mercado Pago
+///
+/// Cloning is cheap, the underlying HTTP connection pool is shared between clones.
+#[derive(Clone)]
 pub struct MercadoPagoClient {
-    access_token: String,
+    access_token: AccessToken,
     client_http: reqwest::Client,
     base_url: String,
+    api_versions: Arc<ApiVersions>,
+    partner_headers: Arc<Vec<(&'static str, String)>>,
+    max_request_size: Option<usize>,
+    expected_environment: Option<Environment>,
+    auditor: Option<Auditor>,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+/// Source of the access token of a [`MercadoPagoClient`], for tokens that expire, like the ones created with OAuth.
+///
+/// Set with [`MercadoPagoClientBuilder::builder_with_token_provider`]. See [`OAuthTokenProvider`](crate::oauth::OAuthTokenProvider) for the built-in implementation.
+pub trait AccessTokenProvider: Send + Sync {
+    /// Token for the next request, refreshing it first when it's expired.
+    fn access_token(&self) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>>;
+
+    /// Token to retry a request answered with `401 Unauthorized` while sending `rejected`.
+    ///
+    /// Called by every request in flight when the token is revoked, so it should only refresh once, returning the new token when `rejected` was already replaced.
+    fn refresh(&self, rejected: &str) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>>;
+}
+
+/// Shares a provider between clients, or keeps a handle to it.
+impl<T: AccessTokenProvider + ?Sized> AccessTokenProvider for Arc<T> {
+    fn access_token(&self) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+        (**self).access_token()
+    }
+
+    fn refresh(&self, rejected: &str) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+        (**self).refresh(rejected)
+    }
+}
+
+#[derive(Clone)]
+enum AccessToken {
+    Static(String),
+    Provider(Arc<dyn AccessTokenProvider>),
+}
+
+/// Environment of an access token, production or sandbox (test credentials and test users).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// `live_mode` is `true`, money moves.
+    Production,
+    /// `live_mode` is `false`.
+    Sandbox,
+}
+
+impl Environment {
+    fn from_live_mode(live_mode: bool) -> Self {
+        if live_mode {
+            Environment::Production
+        } else {
+            Environment::Sandbox
+        }
+    }
+}
+
+/// Request quota of the access token, from the `x-ratelimit-*` headers of a response.
+///
+/// Mercado Pago only sends these headers on some endpoints and accounts, a missing header is `None`. See [`MercadoPagoClient::rate_limit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimit {
+    /// Requests allowed in the window, `x-ratelimit-limit`.
+    pub limit: Option<u64>,
+    /// Requests left in the window, `x-ratelimit-remaining`.
+    pub remaining: Option<u64>,
+    /// When the window resets, `x-ratelimit-reset`, as sent by the API.
+    pub reset: Option<u64>,
+}
+
+impl RateLimit {
+    /// Reads the `x-ratelimit-*` headers, `None` when the response has none of them.
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        let rate_limit = RateLimit {
+            limit: header("x-ratelimit-limit"),
+            remaining: header("x-ratelimit-remaining"),
+            reset: header("x-ratelimit-reset"),
+        };
+
+        (rate_limit != RateLimit::default()).then_some(rate_limit)
+    }
+}
+
+/// First `live_mode` of a response body, at the top level or in the results of a search page.
+///
+/// Scans the JSON tokens without deserializing them and stops at the first `live_mode`, so checking a search page costs little next to decoding it.
+pub(crate) struct LiveModeProbe;
+
+/// JSON container the [`LiveModeProbe`] is in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ProbeContainer {
+    Object,
+    Array,
+    /// `results` or `elements` array of a search page.
+    Results,
+}
+
+impl LiveModeProbe {
+    /// Environment of the response, `None` when the body doesn't say.
+    pub(crate) fn environment(body: &[u8]) -> Option<Environment> {
+        use ProbeContainer::{Array, Object, Results};
+
+        let mut containers = Vec::new();
+        let mut top_level_key: &[u8] = b"";
+        let mut i = 0;
+
+        while i < body.len() {
+            match body[i] {
+                b'{' => containers.push(Object),
+                b'[' => containers.push(match (containers.as_slice(), top_level_key) {
+                    ([Object], b"results" | b"elements") => Results,
+                    _ => Array,
+                }),
+                b'}' | b']' => {
+                    containers.pop();
+                }
+                b'"' => {
+                    let start = i + 1;
+                    i = start + string_len(&body[start..])?;
+                    let after = skip_whitespace(body, i + 1);
+
+                    if body.get(after) == Some(&b':') {
+                        let key = &body[start..i];
+                        if containers.len() == 1 {
+                            top_level_key = key;
+                        }
+
+                        if key == b"live_mode"
+                            && matches!(containers.as_slice(), [Object] | [Object, Results, Object])
+                        {
+                            let value = &body[skip_whitespace(body, after + 1)..];
+                            if value.starts_with(b"true") {
+                                return Some(Environment::from_live_mode(true));
+                            } else if value.starts_with(b"false") {
+                                return Some(Environment::from_live_mode(false));
+                            }
+                        }
+                        i = after;
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        None
+    }
+}
+
+/// Length of the JSON string starting at `body`, up to its closing quote. `None` when it's not closed.
+fn string_len(body: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    loop {
+        match body.get(i)? {
+            b'\\' => i += 2,
+            b'"' => return Some(i),
+            _ => i += 1,
+        }
+    }
+}
+
+/// Index of the first byte of `body` after `from` that is not JSON whitespace.
+fn skip_whitespace(body: &[u8], from: usize) -> usize {
+    from + body
+        .get(from..)
+        .unwrap_or_default()
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count()
+}
+
+/// Header used to pin the API version, see [`MercadoPagoClientBuilder::with_api_version`].
+pub const API_VERSION_HEADER: &str = "X-Api-Version";
+
+/// Header with the id of a certified integrator, see [`MercadoPagoClientBuilder::with_integrator_id`].
+pub const INTEGRATOR_ID_HEADER: &str = "X-Integrator-Id";
+
+/// Header with the id of a certified platform, see [`MercadoPagoClientBuilder::with_platform_id`].
+pub const PLATFORM_ID_HEADER: &str = "X-Platform-Id";
+
+/// Header with the id of a corporation, see [`MercadoPagoClientBuilder::with_corporation_id`].
+pub const CORPORATION_ID_HEADER: &str = "X-Corporation-Id";
+
+/// Pinned API versions, the default and the overrides by path prefix, and the versions in the paths of the resources.
+#[derive(Debug, Default)]
+struct ApiVersions {
+    default: Option<String>,
+    overrides: Vec<(String, String)>,
+    #[cfg(feature = "wallet_connect")]
+    paths: Vec<(String, String)>,
+}
+
+impl ApiVersions {
+    /// Version of the longest prefix matching whole segments of `path`, or the default one.
+    fn for_path(&self, path: &str) -> Option<&str> {
+        self.overrides
+            .iter()
+            .filter(|(prefix, _)| matches_segments(path, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, version)| version.as_str())
+            .or(self.default.as_deref())
+    }
+
+    /// `path`, a route without version like `/wallet_connect/payments`, under the version pinned for its resource or `default`.
+    #[cfg(feature = "wallet_connect")]
+    fn versioned_path(&self, default: &str, path: &str) -> String {
+        let resource = path.trim_start_matches('/').split(['/', '?']).next();
+        let version = self
+            .paths
+            .iter()
+            .find(|(pinned, _)| Some(pinned.as_str()) == resource)
+            .map_or(default, |(_, version)| version.as_str());
+
+        format!("/{version}{path}")
+    }
+}
+
+/// Whether `path` starts with the segments of `prefix`, so `/preapproval` matches `/preapproval/1` but not `/preapproval_plan/1`.
+fn matches_segments(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix).is_some_and(|rest| {
+        rest.is_empty() || rest.starts_with(['/', '?']) || prefix.ends_with('/')
+    })
 }
 
 impl MercadoPagoClient {
     /// Request builder that set API url and token
     ///
+    /// With an [`AccessTokenProvider`], the token is only set when sent with [`execute`](Self::execute), so send the request with it instead of [`RequestBuilder::send`]. GETs of endpoints not modeled by the crate can also be sent with [`get`](Self::get) and [`search`](Self::search).
+    ///
     /// # Arguments
     ///
     /// * `method` - Http method
@@ -28,40 +277,587 @@ impl MercadoPagoClient {
     ///
     /// client.start_request(request::Method::POST, "/v1/payment_methods")
     /// ```
-    pub fn start_request(&self, method: Method, path: impl ToString) -> reqwest::RequestBuilder {
-        self.client_http
-            .request(method, format!("{}{}", self.base_url, path.to_string()))
-            .bearer_auth(&self.access_token)
+    pub fn start_request(&self, method: Method, path: impl ToString) -> RequestBuilder {
+        self.with_static_token(self.start_request_without_token(method, path))
+    }
+
+    /// Sets the token of a client without [`AccessTokenProvider`], whose token is set by [`execute`](Self::execute).
+    fn with_static_token(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.access_token {
+            AccessToken::Static(access_token) => request.bearer_auth(access_token),
+            AccessToken::Provider(_) => request,
+        }
+    }
+
+    /// Like [`start_request`](Self::start_request), for a route whose version is in the path, like `/v2/wallet_connect/payments`.
+    ///
+    /// `path` is the route without its version, sent under `default_version` unless [`MercadoPagoClientBuilder::with_path_version_for`] pins another one for its resource.
+    #[cfg(feature = "wallet_connect")]
+    pub(crate) fn start_versioned_request(
+        &self,
+        method: Method,
+        default_version: &str,
+        path: &str,
+    ) -> RequestBuilder {
+        self.start_request(
+            method,
+            self.api_versions.versioned_path(default_version, path),
+        )
+    }
+
+    /// Like [`start_request`](Self::start_request), for the routes authenticated by their body, like the OAuth token.
+    pub(crate) fn start_request_without_token(
+        &self,
+        method: Method,
+        path: impl ToString,
+    ) -> RequestBuilder {
+        let path = path.to_string();
+        let mut request = self
+            .client_http
+            .request(method, format!("{}{}", self.base_url, path));
+
+        for (name, value) in self.partner_headers.iter() {
+            request = request.header(*name, value);
+        }
+
+        match self.api_versions.for_path(&path) {
+            Some(version) => request.header(API_VERSION_HEADER, version),
+            None => request,
+        }
+    }
+
+    /// Copy of the client sending `access_token` instead, for marketplaces acting on behalf of their sellers
+    ///
+    /// The copy shares the connection pool and the rest of the configuration, so it's cheap to create per request. See also [`SendRequest::send_as`].
+    ///
+    /// # Example
+    /// ```
+    /// let seller_client = mp_client.with_access_token(&seller.access_token);
+    /// ```
+    pub fn with_access_token(&self, access_token: impl ToString) -> MercadoPagoClient {
+        MercadoPagoClient {
+            access_token: AccessToken::Static(access_token.to_string()),
+            rate_limit: Arc::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Send a request started with [`start_request`](Self::start_request)
+    ///
+    /// With an [`AccessTokenProvider`], sets the token of the provider and, when the API answers `401 Unauthorized`, sends the request once more with the token returned by [`AccessTokenProvider::refresh`]. Requests with a streamed body can't be cloned, so they are not retried.
+    ///
+    /// # Example
+    /// ```
+    /// let res = mp_client
+    ///     .execute(mp_client.start_request(Method::DELETE, "/v1/customers/123/cards/456"))
+    ///     .await?;
+    /// ```
+    pub async fn execute(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Response, MercadoPagoRequestError> {
+        let response = self.send_authenticated(request).await?;
+
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(|err| err.into_inner()) = Some(rate_limit);
+            if let Some(auditor) = &self.auditor {
+                auditor.rate_limit(response.url().as_str(), &rate_limit);
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Sends `request` with the token of the client, see [`execute`](Self::execute).
+    async fn send_authenticated(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Response, MercadoPagoRequestError> {
+        let AccessToken::Provider(provider) = &self.access_token else {
+            return Ok(request.send().await?);
+        };
+
+        let retry = request.try_clone();
+        let access_token = provider.access_token().await?;
+        let response = request.bearer_auth(&access_token).send().await?;
+
+        match retry {
+            Some(retry) if response.status() == StatusCode::UNAUTHORIZED => {
+                let access_token = provider.refresh(&access_token).await?;
+
+                Ok(retry.bearer_auth(access_token).send().await?)
+            }
+            _ => Ok(response),
+        }
+    }
+
+    /// Last [`RateLimit`] sent by the API to this client, shared by its clones but not by the copies of [`with_access_token`](Self::with_access_token), which have their own quota.
+    ///
+    /// Use it to pace bulk jobs with the real limits of the account. `None` until a response has the `x-ratelimit-*` headers.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Sets `body` as the JSON body of `request`, checking the size set with [`MercadoPagoClientBuilder::with_max_request_size`].
+    pub(crate) fn json_body<T: Serialize>(
+        &self,
+        request: RequestBuilder,
+        body: &T,
+    ) -> Result<RequestBuilder, MercadoPagoRequestError> {
+        let body = serde_json::to_vec(body)?;
+
+        if let Some(limit) = self.max_request_size.filter(|limit| body.len() > *limit) {
+            return Err(MercadoPagoRequestError::RequestTooLarge {
+                size: body.len(),
+                limit,
+            });
+        }
+
+        let request = request.header(reqwest::header::CONTENT_TYPE, "application/json");
+
+        let Some(auditor) = &self.auditor else {
+            return Ok(request.body(body));
+        };
+
+        let (client, request) = request.body(body).build_split();
+        let request = request?;
+        if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+            auditor.request(request.method(), request.url().as_str(), body);
+        }
+
+        Ok(RequestBuilder::from_parts(client, request))
+    }
+
+    /// Parses a response like [`resolve_json`](crate::common::resolve_json), applying the checks configured in the client.
+    pub(crate) async fn resolve_json<T: DeserializeOwned>(
+        &self,
+        response: Response,
+    ) -> Result<T, MercadoPagoRequestError> {
+        if self.expected_environment.is_none() && self.auditor.is_none() {
+            return resolve_json::<T>(response).await;
+        }
+
+        parse_body::<T>(self.checked_body(response).await?)
+    }
+
+    /// Checks a response without a meaningful body like [`resolve_empty`](crate::common::resolve_empty), applying the checks configured in the client.
+    pub(crate) async fn resolve_empty(
+        &self,
+        response: Response,
+    ) -> Result<(), MercadoPagoRequestError> {
+        if self.expected_environment.is_none() && self.auditor.is_none() {
+            return resolve_empty(response).await;
+        }
+
+        self.checked_body(response).await.map(drop)
+    }
+
+    /// Reads the body of a response, passing it to the audit hook and checking its status and environment.
+    async fn checked_body(&self, response: Response) -> Result<Vec<u8>, MercadoPagoRequestError> {
+        let status = response.status();
+        let url = response.url().to_string();
+        let request_id = request_id(&response);
+        let body = Vec::from(response.bytes().await?);
+
+        if let Some(auditor) = &self.auditor {
+            auditor.response(&url, status.as_u16(), &body);
+        }
+
+        if !status.is_success() {
+            return Err(parse_error_body(request_id, body));
+        }
+
+        match self
+            .expected_environment
+            .zip(LiveModeProbe::environment(&body))
+        {
+            Some((expected, actual)) if actual != expected => {
+                Err(MercadoPagoRequestError::EnvironmentMismatch { expected, actual })
+            }
+            _ => Ok(body),
+        }
+    }
+
+    /// GET an endpoint not modeled by the crate, deserializing the response into `T`
+    ///
+    /// Errors are handled the same way as the builders do, so an API error is returned as [`MercadoPagoRequestError::MercadoPago`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path from API, like `"/v1/identification_types"`.
+    ///
+    /// # Example
+    /// ```
+    /// let identification_types = mp_client
+    ///     .get::<Vec<serde_json::Value>>("/v1/identification_types")
+    ///     .await?;
+    /// ```
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: impl ToString,
+    ) -> Result<T, MercadoPagoRequestError> {
+        let res = self.execute(self.start_request(Method::GET, path)).await?;
+
+        self.resolve_json::<T>(res).await
+    }
+
+    /// GET an endpoint not modeled by the crate with `params` as query string, deserializing the response into `T`
+    ///
+    /// Like [`get`](Self::get), useful for search endpoints the crate doesn't have a builder for.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path from API, like `"/v1/chargebacks/search"`.
+    /// * `params` - Anything serializable as query string, like a struct or `&[("key", "value")]`.
+    ///
+    /// # Example
+    /// ```
+    /// let chargebacks = mp_client
+    ///     .search::<serde_json::Value>("/v1/chargebacks/search", &[("payment_id", "123")])
+    ///     .await?;
+    /// ```
+    pub async fn search<T: DeserializeOwned>(
+        &self,
+        path: impl ToString,
+        params: &(impl Serialize + ?Sized),
+    ) -> Result<T, MercadoPagoRequestError> {
+        let res = self
+            .execute(self.start_request(Method::GET, path).query(params))
+            .await?;
+
+        self.resolve_json::<T>(res).await
     }
 
     ///Check if credentials (`access_token`) are valid
     pub async fn check_credentials(&self) -> Result<(), MercadoPagoRequestError> {
         let response = self
-            .start_request(Method::GET, "/v1/payment_methods")
-            .send()
+            .execute(self.start_request(Method::GET, "/v1/payment_methods"))
             .await?;
 
-        match response.status().as_u16() {
-            200 => Ok(()),
-            _ => Err(MercadoPagoRequestError::MercadoPago(
-                response.json::<MercadoPagoError>().await?,
-            )),
+        self.resolve_empty(response).await
+    }
+
+    /// Check if credentials (`access_token`) are valid, returning the account they belong to
+    ///
+    /// Same as [`check_credentials`](Self::check_credentials), with the collector id and site of the account, see [`UserMeBuilder`](crate::users::UserMeBuilder).
+    #[cfg(feature = "users")]
+    pub async fn check_credentials_with_account(
+        &self,
+    ) -> Result<crate::users::types::User, MercadoPagoRequestError> {
+        crate::users::UserMeBuilder.send(self).await
+    }
+
+    /// Fetch a resource provided by Mercado Pago, like an `external_resource_url` or a report file.
+    ///
+    /// The access token is only sent when `url` has the same origin as the API base url, refreshed like in the builders when the client has an [`AccessTokenProvider`]. When the server redirects to another origin (S3, for example), the `Authorization` header is dropped, so the token never leaks. Redirects follow the policy set with [`MercadoPagoClientBuilder::with_redirect_policy`].
+    ///
+    /// The body is not read, so the caller can stream the final file with [`reqwest::Response::chunk`].
+    pub async fn fetch_resource(
+        &self,
+        url: impl IntoUrl,
+    ) -> Result<reqwest::Response, MercadoPagoRequestError> {
+        let request = self.client_http.get(url).build()?;
+
+        let response = if self.is_api_origin(request.url()) {
+            let request = RequestBuilder::from_parts(self.client_http.clone(), request);
+
+            self.execute(self.with_static_token(request)).await?
+        } else {
+            self.client_http.execute(request).await?
+        };
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(parse_error(response).await)
+        }
+    }
+
+    /// Download a resource provided by Mercado Pago (boleto PDFs, report files, ...) into `writer`.
+    ///
+    /// The file is streamed chunk by chunk, so it is never fully loaded in memory. The access token is handled the same way as in [`fetch_resource`](Self::fetch_resource).
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL of the resource, like an `external_resource_url`.
+    /// * `writer` - Where the file is written to.
+    /// * `options` - Size limit and accepted content types.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::client::DownloadOptions;
+    ///
+    /// let mut file = tokio::fs::File::create("boleto.pdf").await?;
+    ///
+    /// mp_client
+    ///     .download(
+    ///         &payment.transaction_details.unwrap().external_resource_url.unwrap(),
+    ///         &mut file,
+    ///         &DownloadOptions {
+    ///             max_size: Some(5 * 1024 * 1024),
+    ///             content_types: vec!["application/pdf".to_string()],
+    ///         },
+    ///     )
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn download<W>(
+        &self,
+        url: impl IntoUrl,
+        writer: &mut W,
+        options: &DownloadOptions,
+    ) -> Result<u64, MercadoPagoRequestError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut response = self.fetch_resource(url).await?;
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+
+        if !options.accepts_content_type(content_type) {
+            return Err(MercadoPagoRequestError::UnexpectedContentType(
+                content_type.map(str::to_string),
+            ));
+        }
+
+        let too_large = |size: u64| options.max_size.is_some_and(|limit| size > limit);
+
+        if response.content_length().is_some_and(too_large) {
+            return Err(MercadoPagoRequestError::DownloadTooLarge {
+                limit: options.max_size.unwrap_or_default(),
+            });
+        }
+
+        let mut written = 0;
+
+        while let Some(chunk) = response.chunk().await? {
+            written += chunk.len() as u64;
+
+            if too_large(written) {
+                return Err(MercadoPagoRequestError::DownloadTooLarge {
+                    limit: options.max_size.unwrap_or_default(),
+                });
+            }
+
+            writer.write_all(&chunk).await?;
+        }
+
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Checks if `url` has the same origin (scheme, host and port) as the API base url.
+    fn is_api_origin(&self, url: &Url) -> bool {
+        Url::parse(&self.base_url).is_ok_and(|base_url| base_url.origin() == url.origin())
+    }
+}
+
+/// Request that can be sent with a [`MercadoPagoClient`].
+///
+/// Implemented by every builder with a `send` method, so generic code (retry wrappers, instrumentation, batch executors) can work over any operation.
+///
+/// The returned future is `Send`, so it can be awaited inside spawned tasks. The trait uses return position `impl Trait` instead of `async fn` (or the `async_trait` crate) so that bound is part of the signature, without boxing. Implementations can still be written with `async fn`, as long as their future is `Send`.
+///
+/// Since `send` takes `self` and returns `impl Future`, the trait is not object safe. Use generics, or [`Batch`](crate::batch::Batch) to mix different requests.
+///
+/// # Example
+/// ```
+/// use mpago::client::{MercadoPagoClient, SendRequest};
+///
+/// async fn send_logged<R: SendRequest>(request: R, mp_client: &MercadoPagoClient) -> Option<R::Output> {
+///     match request.send(mp_client).await {
+///         Ok(response) => Some(response),
+///         Err(err) => {
+///             eprintln!("Mercado Pago request failed: {err}");
+///             None
+///         }
+///     }
+/// }
+/// ```
+pub trait SendRequest {
+    /// Body of a successful response.
+    type Output;
+
+    /// Send the request
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send;
+
+    /// Send the request, aborting it with [`MercadoPagoRequestError::Cancelled`] if `cancel` completes first.
+    ///
+    /// See [`cancellable`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{client::SendRequest, payments::PaymentGetBuilder};
+    ///
+    /// let shutdown = tokio_util::sync::CancellationToken::new();
+    ///
+    /// let payment = PaymentGetBuilder(87891224.into())
+    ///     .send_until(&mp_client, shutdown.cancelled())
+    ///     .await?;
+    /// ```
+    fn send_until(
+        self,
+        mp_client: &MercadoPagoClient,
+        cancel: impl Future + Send,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send
+    where
+        Self: Sized,
+    {
+        cancellable(self.send(mp_client), cancel)
+    }
+
+    /// Send the request with `access_token` instead of the token of `mp_client`, like the OAuth token of a seller.
+    ///
+    /// See [`MercadoPagoClient::with_access_token`].
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{client::SendRequest, payments::PaymentGetBuilder};
+    ///
+    /// let payment = PaymentGetBuilder(87891224.into())
+    ///     .send_as(&mp_client, &seller.access_token)
+    ///     .await?;
+    /// ```
+    fn send_as(
+        self,
+        mp_client: &MercadoPagoClient,
+        access_token: impl ToString,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send
+    where
+        Self: Sized + Send,
+    {
+        let mp_client = mp_client.with_access_token(access_token);
+
+        async move { self.send(&mp_client).await }
+    }
+
+    /// Send the request, retrying retryable errors as configured in `policy`.
+    ///
+    /// See [`RetryPolicy`](crate::retry::RetryPolicy) for an example.
+    #[cfg(feature = "retry")]
+    fn send_with_retry(
+        self,
+        mp_client: &MercadoPagoClient,
+        policy: &crate::retry::RetryPolicy,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send
+    where
+        Self: Clone + Send,
+    {
+        crate::retry::send_with_retry(self, mp_client, policy)
+    }
+}
+
+/// Runs `request` until `cancel` completes, then fails with [`MercadoPagoRequestError::Cancelled`].
+///
+/// `cancel` can be any future, like `CancellationToken::cancelled` of `tokio-util`, a shutdown signal or a timer. The request is dropped when cancelled, closing its connection, so it's safe to use for long-running calls like [`MercadoPagoClient::download`] during a service shutdown. The API may still process a request that was already sent, use idempotency keys to retry it safely.
+///
+/// Search streams don't need it, dropping the stream stops it.
+pub async fn cancellable<T>(
+    request: impl Future<Output = Result<T, MercadoPagoRequestError>>,
+    cancel: impl Future,
+) -> Result<T, MercadoPagoRequestError> {
+    match select(pin!(request), pin!(cancel)).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(MercadoPagoRequestError::Cancelled),
+    }
+}
+
+/// Restrictions applied by [`MercadoPagoClient::download`]
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    /// Maximum size of the file in bytes. If `None`, there is no limit.
+    pub max_size: Option<u64>,
+    /// Accepted content types, like `"application/pdf"`. If empty, any content type is accepted.
+    pub content_types: Vec<String>,
+}
+
+#[cfg(feature = "tokio")]
+impl DownloadOptions {
+    /// Checks the `Content-Type` header against [`content_types`](Self::content_types), ignoring parameters like `charset`.
+    fn accepts_content_type(&self, content_type: Option<&str>) -> bool {
+        if self.content_types.is_empty() {
+            return true;
         }
+
+        let Some(content_type) = content_type else {
+            return false;
+        };
+
+        let essence = content_type.split(';').next().unwrap_or_default().trim();
+
+        self.content_types
+            .iter()
+            .any(|accepted| accepted.eq_ignore_ascii_case(essence))
     }
 }
 
 /// Builder for [`MercadoPagoClient`]
 pub struct MercadoPagoClientBuilder {
-    access_token: String,
+    access_token: AccessToken,
     base_url: String,
+    redirect_policy: Option<redirect::Policy>,
+    api_versions: ApiVersions,
+    partner_headers: Vec<(&'static str, String)>,
+    max_request_size: Option<usize>,
+    expected_environment: Option<Environment>,
+    audit_hook: Option<Arc<dyn AuditHook>>,
+    audit_redacted_fields: Vec<String>,
 }
 
 impl MercadoPagoClientBuilder {
     /// Create a new client builder
     pub fn builder(access_token: impl ToString) -> MercadoPagoClientBuilder {
+        Self::new(AccessToken::Static(access_token.to_string()))
+    }
+
+    /// Create a new client builder, taking the access token from `provider` instead of a fixed one
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{client::MercadoPagoClientBuilder, oauth::{self, OAuthTokenProvider}};
+    ///
+    /// let tokens = oauth::create_access(client_id, client_secret, code, redirect_uri, None).await?;
+    ///
+    /// let client = MercadoPagoClientBuilder::builder_with_token_provider(OAuthTokenProvider::new(
+    ///     client_id,
+    ///     client_secret,
+    ///     tokens,
+    /// ))
+    /// .build();
+    /// ```
+    pub fn builder_with_token_provider(
+        provider: impl AccessTokenProvider + 'static,
+    ) -> MercadoPagoClientBuilder {
+        Self::new(AccessToken::Provider(Arc::new(provider)))
+    }
+
+    fn new(access_token: AccessToken) -> MercadoPagoClientBuilder {
         MercadoPagoClientBuilder {
-            access_token: access_token.to_string(),
+            access_token,
             base_url: API_BASE_URL.to_string(),
+            redirect_policy: None,
+            api_versions: ApiVersions::default(),
+            partner_headers: vec![],
+            max_request_size: None,
+            expected_environment: None,
+            audit_hook: None,
+            audit_redacted_fields: vec![],
         }
     }
 
@@ -72,16 +868,738 @@ impl MercadoPagoClientBuilder {
         self
     }
 
+    /// Set how the client follows redirects.
+    ///
+    /// Some routes, like report downloads, answer with a redirect to the file storage. By default, up to 10 redirects are followed. The `Authorization` header is never forwarded to another origin, whatever the policy.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::client::MercadoPagoClientBuilder;
+    ///
+    /// let client = MercadoPagoClientBuilder::builder("SOME_ACCESS_TOKEN")
+    ///     .with_redirect_policy(reqwest::redirect::Policy::none())
+    ///     .build();
+    /// ```
+    pub fn with_redirect_policy(mut self, policy: redirect::Policy) -> Self {
+        self.redirect_policy = Some(policy);
+
+        self
+    }
+
+    /// Pin the API version of every request, sent in the [`API_VERSION_HEADER`] header.
+    ///
+    /// Endpoints that change behavior by version keep the pinned one until you opt into a new version deliberately. Without it, Mercado Pago uses its current default.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::client::MercadoPagoClientBuilder;
+    ///
+    /// let client = MercadoPagoClientBuilder::builder("SOME_ACCESS_TOKEN")
+    ///     .with_api_version("2024-01-01")
+    ///     .with_api_version_for("/v1/orders", "2024-06-01")
+    ///     .build();
+    /// ```
+    pub fn with_api_version(mut self, version: impl ToString) -> Self {
+        self.api_versions.default = Some(version.to_string());
+
+        self
+    }
+
+    /// Pin the API version only for the paths starting with `path_prefix`, like `"/preapproval"`.
+    ///
+    /// The prefix matches whole segments, so `"/preapproval"` pins `/preapproval/{id}` but not `/preapproval_plan`. Overrides the version set with [`with_api_version`](Self::with_api_version). When several prefixes match, the longest one wins.
+    pub fn with_api_version_for(
+        mut self,
+        path_prefix: impl ToString,
+        version: impl ToString,
+    ) -> Self {
+        self.api_versions
+            .overrides
+            .push((path_prefix.to_string(), version.to_string()));
+
+        self
+    }
+
+    /// Pin the version in the path of `resource`, like `"wallet_connect"` for `/v2/wallet_connect/payments`.
+    ///
+    /// Unlike [`with_api_version_for`](Self::with_api_version_for), which sets a header, this replaces the `/v2` segment of the routes of the resources versioned by path.
+    #[cfg(feature = "wallet_connect")]
+    pub fn with_path_version_for(
+        mut self,
+        resource: impl ToString,
+        version: impl ToString,
+    ) -> Self {
+        self.api_versions
+            .paths
+            .push((resource.to_string(), version.to_string()));
+
+        self
+    }
+
+    /// Send `integrator_id` in the [`INTEGRATOR_ID_HEADER`] of every request, required for certified partners.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::client::MercadoPagoClientBuilder;
+    ///
+    /// let client = MercadoPagoClientBuilder::builder("SOME_ACCESS_TOKEN")
+    ///     .with_integrator_id("dev_24c65fb163bf11ea96500242ac130004")
+    ///     .with_platform_id("BCUV6H7O2JHG01LJJC7G")
+    ///     .build();
+    /// ```
+    pub fn with_integrator_id(self, integrator_id: impl ToString) -> Self {
+        self.with_partner_header(INTEGRATOR_ID_HEADER, integrator_id)
+    }
+
+    /// Send `platform_id` in the [`PLATFORM_ID_HEADER`] of every request, for certified platforms.
+    pub fn with_platform_id(self, platform_id: impl ToString) -> Self {
+        self.with_partner_header(PLATFORM_ID_HEADER, platform_id)
+    }
+
+    /// Send `corporation_id` in the [`CORPORATION_ID_HEADER`] of every request, for corporations with several accounts.
+    pub fn with_corporation_id(self, corporation_id: impl ToString) -> Self {
+        self.with_partner_header(CORPORATION_ID_HEADER, corporation_id)
+    }
+
+    fn with_partner_header(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.partner_headers.retain(|(header, _)| *header != name);
+        self.partner_headers.push((name, value.to_string()));
+
+        self
+    }
+
+    /// Refuse to send create requests with a JSON body bigger than `bytes`.
+    ///
+    /// Requests over the limit fail with [`MercadoPagoRequestError::RequestTooLarge`] before reaching the network. Useful when the body is built from external data, like a product catalog. See also [`PaymentCreateBuilder::trim_free_text`](crate::payments::PaymentCreateBuilder::trim_free_text).
+    pub fn with_max_request_size(mut self, bytes: usize) -> Self {
+        self.max_request_size = Some(bytes);
+
+        self
+    }
+
+    /// Check the `live_mode` of every response against `environment`.
+    ///
+    /// Responses from the other environment fail with [`MercadoPagoRequestError::EnvironmentMismatch`], catching a production token deployed to staging, or a test token deployed to production. Responses without `live_mode` are not checked.
+    pub fn with_expected_environment(mut self, environment: Environment) -> Self {
+        self.expected_environment = Some(environment);
+
+        self
+    }
+
+    /// Send the JSON bodies of requests and responses to `hook`, for audits of what was transmitted.
+    ///
+    /// Fields in [`DEFAULT_REDACTED_FIELDS`](crate::audit::DEFAULT_REDACTED_FIELDS) are redacted, more can be added with [`with_audit_redaction`](Self::with_audit_redaction). Responses handled without parsing a body, like `204 No Content`, are not audited.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{audit::AuditEvent, client::MercadoPagoClientBuilder};
+    ///
+    /// let client = MercadoPagoClientBuilder::builder("SOME_ACCESS_TOKEN")
+    ///     .with_audit_hook(|event: AuditEvent<'_>| match event {
+    ///         AuditEvent::Request { method, url, body } => audit_log.write(method, url, body),
+    ///         AuditEvent::Response { url, status, body } => audit_log.write(status, url, body),
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_audit_hook(mut self, hook: impl AuditHook + 'static) -> Self {
+        self.audit_hook = Some(Arc::new(hook));
+
+        self
+    }
+
+    /// Redact `fields` too, at any depth of the bodies sent to the hook of [`with_audit_hook`](Self::with_audit_hook).
+    pub fn with_audit_redaction(mut self, fields: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.audit_redacted_fields
+            .extend(fields.into_iter().map(|field| field.to_string()));
+
+        self
+    }
+
     /// Build a [`MercadoPagoClient`] with the current builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, same as [`reqwest::Client::new`].
     pub fn build(self) -> MercadoPagoClient {
+        let mut client_http = reqwest::Client::builder();
+
+        if let Some(policy) = self.redirect_policy {
+            client_http = client_http.redirect(policy);
+        }
+
         MercadoPagoClient {
             access_token: self.access_token,
             base_url: self.base_url,
-            client_http: reqwest::Client::new(),
+            api_versions: Arc::new(self.api_versions),
+            partner_headers: Arc::new(self.partner_headers),
+            max_request_size: self.max_request_size,
+            expected_environment: self.expected_environment,
+            auditor: self.audit_hook.map(|hook| {
+                let mut auditor = Auditor::new(hook);
+                for field in self.audit_redacted_fields {
+                    auditor.redact_field(field);
+                }
+                auditor
+            }),
+            client_http: client_http
+                .build()
+                .expect("TLS backend should be initialized"),
+            rate_limit: Arc::default(),
         }
     }
 }
 
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod download_tests {
+    use super::{DownloadOptions, MercadoPagoClientBuilder};
+    use crate::{
+        common::MercadoPagoRequestError,
+        test_support::{serve, MockResponse},
+    };
+
+    /// Serves `body` with `content_type`, returning the url of the file.
+    async fn serve_file(content_type: &'static str, body: &'static [u8]) -> String {
+        let url = serve(move |_| MockResponse::new("200 OK", content_type, body)).await;
+
+        format!("{url}/file")
+    }
+
+    #[tokio::test]
+    async fn download_writes_file() {
+        let url = serve_file("application/pdf", b"%PDF-1.4").await;
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        let mut file = Vec::new();
+
+        let written = client
+            .download(
+                url,
+                &mut file,
+                &DownloadOptions {
+                    max_size: Some(1024),
+                    content_types: vec!["application/pdf".to_string()],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(written, 8);
+        assert_eq!(file, b"%PDF-1.4");
+    }
+
+    #[tokio::test]
+    async fn download_checks_limits() {
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+
+        let url = serve_file("application/pdf", b"%PDF-1.4").await;
+        let res = client
+            .download(
+                url,
+                &mut Vec::new(),
+                &DownloadOptions {
+                    max_size: Some(4),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(MercadoPagoRequestError::DownloadTooLarge { limit: 4 })
+        ));
+
+        let url = serve_file("text/html; charset=utf-8", b"<html></html>").await;
+        let res = client
+            .download(
+                url,
+                &mut Vec::new(),
+                &DownloadOptions {
+                    content_types: vec!["application/pdf".to_string()],
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(MercadoPagoRequestError::UnexpectedContentType(Some(_)))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod token_provider_tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures_util::{future::BoxFuture, FutureExt};
+    use reqwest::Method;
+
+    use super::{AccessTokenProvider, MercadoPagoClientBuilder};
+    use crate::{
+        common::MercadoPagoRequestError,
+        test_support::{serve, MockResponse},
+    };
+
+    /// Rotates from `OLD` to `NEW` on refresh, recording the rejected tokens.
+    #[derive(Default)]
+    struct RotatingToken {
+        rejected: Mutex<Vec<String>>,
+    }
+
+    impl AccessTokenProvider for RotatingToken {
+        fn access_token(&self) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+            async { Ok("OLD".to_string()) }.boxed()
+        }
+
+        fn refresh(
+            &self,
+            rejected: &str,
+        ) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+            self.rejected.lock().unwrap().push(rejected.to_string());
+
+            async { Ok("NEW".to_string()) }.boxed()
+        }
+    }
+
+    /// Answers `401` unless the request has the `NEW` token, returning the server url.
+    async fn serve_requiring_new_token() -> String {
+        serve(|request| {
+            let status = match request.header("authorization") {
+                Some("bearer new") => "200 OK",
+                _ => "401 Unauthorized",
+            };
+
+            MockResponse::json(status, serde_json::json!({}))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn retries_once_with_refreshed_token() {
+        let url = serve_requiring_new_token().await;
+        let provider = Arc::new(RotatingToken::default());
+        let client = MercadoPagoClientBuilder::builder_with_token_provider(provider.clone())
+            .with_base_url(url)
+            .build();
+
+        let res = client.get::<serde_json::Value>("/v1/payment_methods").await;
+
+        assert!(res.is_ok());
+        assert_eq!(*provider.rejected.lock().unwrap(), ["OLD"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_resource_retries_with_refreshed_token() {
+        let url = serve_requiring_new_token().await;
+        let provider = Arc::new(RotatingToken::default());
+        let client = MercadoPagoClientBuilder::builder_with_token_provider(provider.clone())
+            .with_base_url(&url)
+            .build();
+
+        let res = client
+            .fetch_resource(format!("{url}/v1/reports/file.csv"))
+            .await;
+
+        assert!(res.is_ok());
+        assert_eq!(*provider.rejected.lock().unwrap(), ["OLD"]);
+    }
+
+    #[test]
+    fn provider_token_is_set_by_execute() {
+        let client =
+            MercadoPagoClientBuilder::builder_with_token_provider(RotatingToken::default()).build();
+
+        let request = client
+            .start_request(Method::GET, "/v1/payment_methods")
+            .build()
+            .unwrap();
+
+        assert!(request.headers().get("authorization").is_none());
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{Environment, MercadoPagoClientBuilder};
+    use crate::{
+        audit::AuditEvent,
+        common::MercadoPagoRequestError,
+        test_support::{serve, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn decode_error_is_the_same_with_any_configuration() {
+        let url = serve(|_| MockResponse::new("200 OK", "application/json", b"{\"id\":")).await;
+
+        let clients = [
+            MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN"),
+            MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+                .with_expected_environment(Environment::Sandbox),
+            MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+                .with_audit_hook(|_: AuditEvent<'_>| {}),
+        ];
+
+        for client in clients {
+            let res = client
+                .with_base_url(&url)
+                .build()
+                .get::<serde_json::Value>("/v1/payments/1")
+                .await;
+
+            assert!(matches!(res, Err(MercadoPagoRequestError::Decode(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_responses_are_checked() {
+        let url = serve(|_| MockResponse::new("204 No Content", "application/json", b"")).await;
+        let audited = Arc::new(Mutex::new(vec![]));
+        let hook_audited = audited.clone();
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(&url)
+            .with_audit_hook(move |event: AuditEvent<'_>| {
+                if let AuditEvent::Response { status, .. } = event {
+                    hook_audited.lock().unwrap().push(status);
+                }
+            })
+            .build();
+
+        assert!(client.check_credentials().await.is_ok());
+        assert_eq!(*audited.lock().unwrap(), [204]);
+
+        let url =
+            serve(|_| MockResponse::new("200 OK", "application/json", br#"{"live_mode": true}"#))
+                .await;
+        let res = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(&url)
+            .with_expected_environment(Environment::Sandbox)
+            .build()
+            .check_credentials()
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(MercadoPagoRequestError::EnvironmentMismatch { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{MercadoPagoClientBuilder, RateLimit};
+    use crate::{
+        audit::{AuditEvent, AuditHook},
+        test_support::{serve, MockResponse},
+    };
+
+    struct RateLimitRecorder(Arc<Mutex<Vec<RateLimit>>>);
+
+    impl AuditHook for RateLimitRecorder {
+        fn audit(&self, _: AuditEvent<'_>) {}
+
+        fn rate_limit(&self, _: &str, rate_limit: &RateLimit) {
+            self.0.lock().unwrap().push(*rate_limit);
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limit_headers_are_surfaced() {
+        let url = serve(|request| {
+            let response = MockResponse::json("200 OK", serde_json::json!({}));
+            if request.head.starts_with("get /limited ") {
+                response
+                    .with_header("x-ratelimit-limit", 1000)
+                    .with_header("x-ratelimit-remaining", 998)
+                    .with_header("x-ratelimit-reset", 60)
+            } else {
+                response
+            }
+        })
+        .await;
+        let recorded = Arc::new(Mutex::new(vec![]));
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .with_audit_hook(RateLimitRecorder(recorded.clone()))
+            .build();
+
+        client.get::<serde_json::Value>("/unlimited").await.unwrap();
+        assert_eq!(client.rate_limit(), None);
+
+        client.get::<serde_json::Value>("/limited").await.unwrap();
+        client.get::<serde_json::Value>("/unlimited").await.unwrap();
+        let expected = RateLimit {
+            limit: Some(1000),
+            remaining: Some(998),
+            reset: Some(60),
+        };
+        assert_eq!(client.clone().rate_limit(), Some(expected));
+        assert_eq!(client.with_access_token("SELLER_TOKEN").rate_limit(), None);
+        assert_eq!(*recorded.lock().unwrap(), [expected]);
+    }
+}
+
+#[cfg(test)]
+mod resource_tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures_util::{
+        future::{pending, ready},
+        FutureExt,
+    };
+    use reqwest::{Method, Url};
+
+    use super::{
+        cancellable, Environment, LiveModeProbe, MercadoPagoClientBuilder, API_VERSION_HEADER,
+        INTEGRATOR_ID_HEADER, PLATFORM_ID_HEADER,
+    };
+    use crate::{audit::AuditEvent, common::MercadoPagoRequestError};
+
+    #[test]
+    fn access_token_override() {
+        let client = MercadoPagoClientBuilder::builder("PLATFORM_TOKEN")
+            .with_platform_id("BCUV6H7O2JHG01LJJC7G")
+            .build();
+        let authorization = |client: &super::MercadoPagoClient| {
+            client
+                .start_request(Method::GET, "/v1/payments/1")
+                .build()
+                .unwrap()
+                .headers()["authorization"]
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+
+        let seller_client = client.with_access_token("SELLER_TOKEN");
+
+        assert_eq!(authorization(&seller_client), "Bearer SELLER_TOKEN");
+        assert_eq!(authorization(&client), "Bearer PLATFORM_TOKEN");
+        assert!(seller_client
+            .start_request(Method::GET, "/v1/payments/1")
+            .build()
+            .unwrap()
+            .headers()
+            .contains_key(PLATFORM_ID_HEADER));
+    }
+
+    #[test]
+    fn partner_headers_on_every_request() {
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_integrator_id("dev_old")
+            .with_integrator_id("dev_24c65fb163bf11ea96500242ac130004")
+            .with_platform_id("BCUV6H7O2JHG01LJJC7G")
+            .build();
+
+        let request = client
+            .start_request(Method::POST, "/v1/payments")
+            .build()
+            .unwrap();
+
+        let integrator_ids = request
+            .headers()
+            .get_all(INTEGRATOR_ID_HEADER)
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(integrator_ids, ["dev_24c65fb163bf11ea96500242ac130004"]);
+        assert_eq!(
+            request.headers()[PLATFORM_ID_HEADER],
+            "BCUV6H7O2JHG01LJJC7G"
+        );
+        assert!(request.headers().get("X-Corporation-Id").is_none());
+    }
+
+    #[test]
+    fn api_version_by_path() {
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_api_version("v1")
+            .with_api_version_for("/preapproval", "v2")
+            .with_api_version_for("/preapproval_plan", "v3")
+            .build();
+
+        let version = |path: &str| {
+            client
+                .start_request(Method::GET, path)
+                .build()
+                .unwrap()
+                .headers()
+                .get(API_VERSION_HEADER)
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+
+        assert_eq!(version("/v1/payments/1").as_deref(), Some("v1"));
+        assert_eq!(version("/preapproval/abc").as_deref(), Some("v2"));
+        assert_eq!(version("/preapproval_plan/abc").as_deref(), Some("v3"));
+
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        assert!(client
+            .start_request(Method::GET, "/v1/payments/1")
+            .build()
+            .unwrap()
+            .headers()
+            .get(API_VERSION_HEADER)
+            .is_none());
+    }
+
+    #[test]
+    fn api_version_matches_whole_segments() {
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_api_version("v1")
+            .with_api_version_for("/preapproval", "v2")
+            .build();
+
+        let version = |path: &str| {
+            client
+                .start_request(Method::GET, path)
+                .build()
+                .unwrap()
+                .headers()
+                .get(API_VERSION_HEADER)
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+
+        assert_eq!(version("/preapproval").as_deref(), Some("v2"));
+        assert_eq!(version("/preapproval/abc").as_deref(), Some("v2"));
+        assert_eq!(version("/preapproval?status=paused").as_deref(), Some("v2"));
+        assert_eq!(version("/preapproval_plan/abc").as_deref(), Some("v1"));
+    }
+
+    #[cfg(feature = "wallet_connect")]
+    #[test]
+    fn path_version_by_resource() {
+        use super::MercadoPagoClient;
+
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        let path = |client: &MercadoPagoClient, route: &str| {
+            client
+                .start_versioned_request(Method::GET, "v2", route)
+                .build()
+                .unwrap()
+                .url()
+                .path()
+                .to_string()
+        };
+
+        assert_eq!(
+            path(&client, "/wallet_connect/payments"),
+            "/v2/wallet_connect/payments"
+        );
+
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_path_version_for("wallet_connect", "v3")
+            .build();
+
+        assert_eq!(
+            path(&client, "/wallet_connect/agreements/abc"),
+            "/v3/wallet_connect/agreements/abc"
+        );
+        assert_eq!(path(&client, "/preapproval/abc"), "/v2/preapproval/abc");
+    }
+
+    #[test]
+    fn only_api_origin_receives_token() {
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+
+        assert!(
+            client.is_api_origin(&Url::parse("https://api.mercadopago.com/v1/reports").unwrap())
+        );
+        assert!(
+            !client.is_api_origin(&Url::parse("http://api.mercadopago.com/v1/reports").unwrap())
+        );
+        assert!(!client
+            .is_api_origin(&Url::parse("https://mp-reports.s3.amazonaws.com/file.csv").unwrap()));
+    }
+
+    #[test]
+    fn audits_request_body() {
+        let audited = Arc::new(Mutex::new(vec![]));
+        let hook_audited = audited.clone();
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_audit_hook(move |event: AuditEvent<'_>| {
+                if let AuditEvent::Request { method, url, body } = event {
+                    hook_audited
+                        .lock()
+                        .unwrap()
+                        .push(format!("{method} {url} {}", String::from_utf8_lossy(body)));
+                }
+            })
+            .with_audit_redaction(["email"])
+            .build();
+
+        let request = client
+            .json_body(
+                client.start_request(Method::POST, "/v1/payments"),
+                &serde_json::json!({"payer": {"email": "test@testuser.com"}}),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.body().and_then(|body| body.as_bytes()),
+            Some(br#"{"payer":{"email":"test@testuser.com"}}"#.as_slice())
+        );
+        assert_eq!(
+            *audited.lock().unwrap(),
+            vec![
+                r#"POST https://api.mercadopago.com/v1/payments {"payer":{"email":"[REDACTED]"}}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn cancellation() {
+        let cancelled = cancellable(pending::<Result<(), _>>(), ready(())).now_or_never();
+        assert!(matches!(
+            cancelled,
+            Some(Err(MercadoPagoRequestError::Cancelled))
+        ));
+
+        let completed = cancellable(ready(Ok(8)), pending::<()>()).now_or_never();
+        assert!(matches!(completed, Some(Ok(8))));
+    }
+
+    #[test]
+    fn live_mode_environment() {
+        assert_eq!(
+            LiveModeProbe::environment(br#"{"id": 1, "live_mode": true}"#),
+            Some(Environment::Production)
+        );
+        assert_eq!(
+            LiveModeProbe::environment(br#"{"paging": {}, "results": [{"live_mode": false}]}"#),
+            Some(Environment::Sandbox)
+        );
+        assert_eq!(
+            LiveModeProbe::environment(br#"{"elements": [{"id": "1"}, {"live_mode": true}]}"#),
+            Some(Environment::Production)
+        );
+        assert_eq!(LiveModeProbe::environment(br#"{"id": 1}"#), None);
+        assert_eq!(
+            LiveModeProbe::environment(br#"[{"live_mode": true}]"#),
+            None
+        );
+
+        // Only the top level and the results, not strings or nested objects
+        assert_eq!(
+            LiveModeProbe::environment(
+                br#"{"description": "{\"live_mode\": false}", "payer": {"live_mode": false}, "live_mode": true}"#
+            ),
+            Some(Environment::Production)
+        );
+        assert_eq!(
+            LiveModeProbe::environment(
+                br#"{"live_mode": null, "paging": {"results": [{"live_mode": true}]}, "results": [{"payer": [{"live_mode": true}]}, {"live_mode": false}]}"#
+            ),
+            Some(Environment::Sandbox)
+        );
+        assert_eq!(
+            LiveModeProbe::environment(br#"{"id": "1, "live_mode"#),
+            None
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(ignore)]
 mod tests {
@@ -97,4 +1615,19 @@ mod tests {
 
         assert!(client.check_credentials().await.is_ok())
     }
+
+    #[tokio::test]
+    async fn get_and_search_unmodeled_endpoints() {
+        let client = crate::common::create_test_client();
+
+        let identification_types = client
+            .get::<Vec<serde_json::Value>>("/v1/identification_types")
+            .await;
+        assert!(identification_types.is_ok());
+
+        let payments = client
+            .search::<serde_json::Value>("/v1/payments/search", &[("limit", "1")])
+            .await;
+        assert!(payments.is_ok());
+    }
 }
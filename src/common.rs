@@ -1,7 +1,22 @@
+#[cfg(feature = "subscriptions")]
+use crate::subscriptions::types::SubscriptionValidationError;
 #[allow(unused_imports)]
-use crate::{client::MercadoPagoClient, payments::types::PaymentCreateOptions};
+use crate::{
+    client::{Environment, MercadoPagoClient},
+    payments::types::{PaymentCreateOptions, PaymentValidationError},
+};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 use reqwest::Response;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use thiserror::Error;
 
 /// Used for solving json responses from Mercado Pago. If there is an error, [`MercadoPagoRequestError`] handles both the request body errors from Mercado Pago and Reqwest errors.
@@ -10,13 +25,84 @@ where
     T: DeserializeOwned,
 {
     match response.status().as_u16() {
-        200..=299 => Ok(response.json::<T>().await?),
-        _ => Err(MercadoPagoRequestError::MercadoPago(
-            response.json::<MercadoPagoError>().await?,
-        )),
+        200..=299 => parse_json::<T>(response).await,
+        _ => Err(parse_error(response).await),
+    }
+}
+
+/// Used for responses without a meaningful body, like `DELETE` routes answering `204 No Content`.
+///
+/// Any successful status is treated as success without trying to parse the body. Errors are handled the same way as [`resolve_json`].
+pub async fn resolve_empty(response: Response) -> Result<(), MercadoPagoRequestError> {
+    match response.status().as_u16() {
+        200..=299 => Ok(()),
+        _ => Err(parse_error(response).await),
+    }
+}
+
+/// Parses the error body, keeping the `x-request-id` header to identify the request.
+pub(crate) async fn parse_error(response: Response) -> MercadoPagoRequestError {
+    let request_id = request_id(&response);
+
+    match parse_json::<MercadoPagoError>(response).await {
+        Ok(error) => MercadoPagoRequestError::MercadoPago(MercadoPagoError {
+            request_id,
+            ..error
+        }),
+        Err(err) => err,
     }
 }
 
+/// The `x-request-id` header of a response.
+pub(crate) fn request_id(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get("x-request-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Parses an error body already read, see [`parse_error`].
+pub(crate) fn parse_error_body(
+    request_id: Option<String>,
+    body: Vec<u8>,
+) -> MercadoPagoRequestError {
+    match parse_body::<MercadoPagoError>(body) {
+        Ok(error) => MercadoPagoRequestError::MercadoPago(MercadoPagoError {
+            request_id,
+            ..error
+        }),
+        Err(err) => err,
+    }
+}
+
+/// Read and deserialize the response body, see [`parse_body`].
+async fn parse_json<T>(response: Response) -> Result<T, MercadoPagoRequestError>
+where
+    T: DeserializeOwned,
+{
+    parse_body(Vec::from(response.bytes().await?))
+}
+
+/// Deserialize a response body already read, with `serde_json`.
+#[cfg(not(feature = "simd-json"))]
+pub(crate) fn parse_body<T>(body: Vec<u8>) -> Result<T, MercadoPagoRequestError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice::<T>(&body).map_err(MercadoPagoRequestError::Decode)
+}
+
+/// Deserialize a response body already read, with `simd-json`.
+#[cfg(feature = "simd-json")]
+pub(crate) fn parse_body<T>(mut body: Vec<u8>) -> Result<T, MercadoPagoRequestError>
+where
+    T: DeserializeOwned,
+{
+    simd_json::serde::from_slice::<T>(&mut body)
+        .map_err(|err| MercadoPagoRequestError::Decode(serde::de::Error::custom(err)))
+}
+
 /// Enum to handle Mercado Pago errors and Reqwest errors
 #[derive(Error, Debug)]
 pub enum MercadoPagoRequestError {
@@ -24,10 +110,54 @@ pub enum MercadoPagoRequestError {
     Request(#[from] reqwest::Error),
     #[error("MercadoPago Error: {0:?}")]
     MercadoPago(MercadoPagoError),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("Download is bigger than the limit of {limit} bytes")]
+    DownloadTooLarge { limit: u64 },
+    #[error("Download has an unexpected content type: {0:?}")]
+    UnexpectedContentType(Option<String>),
+    /// The `live_mode` of the response does not match [`MercadoPagoClientBuilder::with_expected_environment`](crate::client::MercadoPagoClientBuilder::with_expected_environment), the access token is from the wrong environment.
+    #[error("Response is from {actual:?}, but the client expects {expected:?}")]
+    EnvironmentMismatch {
+        expected: Environment,
+        actual: Environment,
+    },
+    /// The cancellation future of [`cancellable`](crate::client::cancellable) completed before the response.
+    #[error("Request was cancelled")]
+    Cancelled,
+    #[error("Request body has {size} bytes, more than the limit of {limit} bytes")]
+    RequestTooLarge { size: usize, limit: usize },
+    /// The request body can't be serialized.
+    #[error("{0}")]
+    Serialize(#[from] serde_json::Error),
+    /// The response body doesn't match the expected type.
+    #[error("Response body can't be decoded: {0}")]
+    Decode(#[source] serde_json::Error),
+    /// A pre-send check that needed data from the API failed.
+    #[error("{0}")]
+    Validation(#[from] PaymentValidationError),
+    /// The subscription failed [`SubscriptionCreateBuilder::validate`](crate::subscriptions::SubscriptionCreateBuilder::validate) before sending it.
+    #[cfg(feature = "subscriptions")]
+    #[error("{0}")]
+    SubscriptionValidation(#[from] SubscriptionValidationError),
+}
+
+impl MercadoPagoRequestError {
+    /// Whether sending the same request again may succeed: timeouts, connection errors, `429 Too Many Requests` and `5xx` errors from Mercado Pago.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            MercadoPagoRequestError::Request(err) => err.is_timeout() || err.is_connect(),
+            MercadoPagoRequestError::MercadoPago(err) => err.status == 429 || err.status >= 500,
+            _ => false,
+        }
+    }
 }
 
 /// Body sent by Mercado Pago when there is something wrong
+///
+/// Fields may be added, so outside of the crate it's created with [`new`](Self::new).
 #[derive(Deserialize, Serialize, Debug)]
+#[non_exhaustive]
 pub struct MercadoPagoError {
     /// Resume of the error
     pub message: String,
@@ -37,6 +167,55 @@ pub struct MercadoPagoError {
     pub status: u16,
     /// A list of causes of the error
     pub cause: Vec<MercadoPagoErrorCause>,
+    /// Value of the `x-request-id` header of the response, which Mercado Pago support uses to find the request.
+    #[serde(skip)]
+    pub request_id: Option<String>,
+}
+
+impl MercadoPagoError {
+    /// Error without `request_id`, for example to mock API errors in tests.
+    pub fn new(
+        message: impl ToString,
+        error: impl ToString,
+        status: u16,
+        cause: Vec<MercadoPagoErrorCause>,
+    ) -> Self {
+        MercadoPagoError {
+            message: message.to_string(),
+            error: error.to_string(),
+            status,
+            cause,
+            request_id: None,
+        }
+    }
+
+    /// Single line summary of the error, in the format Mercado Pago support asks for in tickets
+    ///
+    /// It only has the status, error code, cause codes and request id. `message` and the cause descriptions are left out, since they can echo payer data.
+    ///
+    /// # Example
+    /// ```
+    /// if let Err(MercadoPagoRequestError::MercadoPago(err)) = result {
+    ///     // status=400 error=bad_request causes=[2067,4020] request_id=6b7c2d8a-...
+    ///     println!("{}", err.support_summary());
+    /// }
+    /// ```
+    pub fn support_summary(&self) -> String {
+        let causes = self
+            .cause
+            .iter()
+            .map(|cause| cause.code.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "status={} error={} causes=[{}] request_id={}",
+            self.status,
+            self.error,
+            causes,
+            self.request_id.as_deref().unwrap_or("-")
+        )
+    }
 }
 
 /// Error cause
@@ -56,6 +235,337 @@ pub struct MercadoPagoErrorCause {
     pub date: String,
 }
 
+/// Reference of your system attached to Mercado Pago resources (payments, preferences, subscriptions, merchant orders, orders), used to join them with your own records.
+///
+/// Validated on creation with the rules of the resource `R`, see [`ResourceLimits`]. The Orders API, the default, only accepts up to 64 ASCII letters, numbers, hyphens (`-`) and underscores (`_`), the other resources accept longer references of any character.
+///
+/// References of different resources compare with `==`, and [`convert`](Self::convert) checks a reference against the rules of another resource, so the same reference can be attached to the payment, preference and order of a purchase.
+///
+/// # Example
+/// ```
+/// use mpago::common::{ExternalReference, OrderLimits, PaymentLimits};
+///
+/// let reference = ExternalReference::<PaymentLimits>::new("order-1234")?;
+/// let order_reference = reference.convert::<OrderLimits>()?;
+/// assert_eq!(reference, order_reference);
+///
+/// assert!(ExternalReference::<PaymentLimits>::new("order #1234").is_ok());
+/// assert!(ExternalReference::<OrderLimits>::new("order #1234").is_err());
+/// ```
+pub struct ExternalReference<R = OrderLimits> {
+    reference: String,
+    limits: PhantomData<R>,
+}
+
+/// Rules of the external references of a resource, for [`ExternalReference`].
+pub trait ResourceLimits {
+    /// Maximum number of characters.
+    const MAX_LEN: usize;
+
+    /// Whether `c` can be part of a reference.
+    fn valid_char(c: char) -> bool {
+        !c.is_control()
+    }
+}
+
+/// Limits of the references of orders of the Orders API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrderLimits;
+
+impl ResourceLimits for OrderLimits {
+    const MAX_LEN: usize = 64;
+
+    fn valid_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '-' || c == '_'
+    }
+}
+
+/// Limits of the references of payments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PaymentLimits;
+
+impl ResourceLimits for PaymentLimits {
+    const MAX_LEN: usize = 256;
+}
+
+/// Limits of the references of Checkout Pro preferences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PreferenceLimits;
+
+impl ResourceLimits for PreferenceLimits {
+    const MAX_LEN: usize = 256;
+}
+
+/// Limits of the references of subscriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionLimits;
+
+impl ResourceLimits for SubscriptionLimits {
+    const MAX_LEN: usize = 256;
+}
+
+/// Limits of the references of merchant orders, the references of the preferences they were created from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MerchantOrderLimits;
+
+impl ResourceLimits for MerchantOrderLimits {
+    const MAX_LEN: usize = PreferenceLimits::MAX_LEN;
+}
+
+impl<R: ResourceLimits> ExternalReference<R> {
+    /// Maximum number of characters accepted by the resource.
+    pub const MAX_LEN: usize = R::MAX_LEN;
+
+    /// Validates and creates an external reference
+    pub fn new(reference: impl ToString) -> Result<Self, ExternalReferenceError> {
+        let reference = reference.to_string();
+
+        if reference.is_empty() {
+            return Err(ExternalReferenceError::Empty);
+        }
+
+        let len = reference.chars().count();
+        if len > R::MAX_LEN {
+            return Err(ExternalReferenceError::TooLong {
+                len,
+                max: R::MAX_LEN,
+            });
+        }
+
+        if let Some(c) = reference.chars().find(|c| !R::valid_char(*c)) {
+            return Err(ExternalReferenceError::InvalidCharacter(c));
+        }
+
+        Ok(ExternalReference {
+            reference,
+            limits: PhantomData,
+        })
+    }
+
+    /// The same reference, validated with the rules of the resource `S`.
+    pub fn convert<S: ResourceLimits>(
+        &self,
+    ) -> Result<ExternalReference<S>, ExternalReferenceError> {
+        ExternalReference::new(&self.reference)
+    }
+}
+
+impl<R> ExternalReference<R> {
+    pub fn as_str(&self) -> &str {
+        &self.reference
+    }
+}
+
+impl<R> Clone for ExternalReference<R> {
+    fn clone(&self) -> Self {
+        ExternalReference {
+            reference: self.reference.clone(),
+            limits: PhantomData,
+        }
+    }
+}
+
+impl<R> Debug for ExternalReference<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ExternalReference")
+            .field(&self.reference)
+            .finish()
+    }
+}
+
+impl<R, S> PartialEq<ExternalReference<S>> for ExternalReference<R> {
+    fn eq(&self, other: &ExternalReference<S>) -> bool {
+        self.reference == other.reference
+    }
+}
+
+impl<R> Eq for ExternalReference<R> {}
+
+impl<R> Hash for ExternalReference<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.reference.hash(state)
+    }
+}
+
+impl<R> PartialOrd for ExternalReference<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R> Ord for ExternalReference<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.reference.cmp(&other.reference)
+    }
+}
+
+impl<R> Serialize for ExternalReference<R> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.reference)
+    }
+}
+
+impl<'de, R: ResourceLimits> Deserialize<'de> for ExternalReference<R> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<R: ResourceLimits> TryFrom<String> for ExternalReference<R> {
+    type Error = ExternalReferenceError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<R: ResourceLimits> TryFrom<&str> for ExternalReference<R> {
+    type Error = ExternalReferenceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<R: ResourceLimits> FromStr for ExternalReference<R> {
+    type Err = ExternalReferenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<R> From<ExternalReference<R>> for String {
+    fn from(value: ExternalReference<R>) -> Self {
+        value.reference
+    }
+}
+
+impl<R> AsRef<str> for ExternalReference<R> {
+    fn as_ref(&self) -> &str {
+        &self.reference
+    }
+}
+
+impl<R> Display for ExternalReference<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.reference)
+    }
+}
+
+impl<R> PartialEq<str> for ExternalReference<R> {
+    fn eq(&self, other: &str) -> bool {
+        self.reference == other
+    }
+}
+
+impl<R> PartialEq<String> for ExternalReference<R> {
+    fn eq(&self, other: &String) -> bool {
+        &self.reference == other
+    }
+}
+
+/// Error when validating an [`ExternalReference`]
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ExternalReferenceError {
+    #[error("External reference is empty")]
+    Empty,
+    #[error("External reference has {len} characters, the maximum is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("External reference has an invalid character: {0:?}")]
+    InvalidCharacter(char),
+}
+
+/// Mercado Pago site (country) of an account, like `"MLB"` for Brazil
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SiteId {
+    #[serde(rename = "MLA")]
+    Argentina,
+    #[serde(rename = "MLB")]
+    Brazil,
+    #[serde(rename = "MLC")]
+    Chile,
+    #[serde(rename = "MCO")]
+    Colombia,
+    #[serde(rename = "MLM")]
+    Mexico,
+    #[serde(rename = "MPE")]
+    Peru,
+    #[serde(rename = "MLU")]
+    Uruguay,
+    /// For untracked sites
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Formats a date the way Mercado Pago expects it, like `2022-11-17T09:37:52.000-04:00`.
+#[cfg(feature = "chrono")]
+pub fn format_date<Tz>(date: &chrono::DateTime<Tz>) -> String
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: Display,
+{
+    date.format("%Y-%m-%dT%H:%M:%S%.3f%:z").to_string()
+}
+
+/// Formats `time` the way Mercado Pago expects it, in UTC, like `2022-11-17T13:37:52.000+00:00`.
+pub(crate) fn format_system_time(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // Civil date from days since 1970-01-01, from Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}+00:00",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Free-text field shortened to fit the API limits, see [`PaymentCreateBuilder::trim_free_text`](crate::payments::PaymentCreateBuilder::trim_free_text).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrimWarning {
+    /// Path of the field, like `"additional_info.items[0].title"`.
+    pub field: String,
+    /// Length before trimming, in characters.
+    pub original_len: usize,
+    /// Length after trimming, in characters.
+    pub limit: usize,
+}
+
+/// Shortens `value` to `limit` characters, recording a [`TrimWarning`] when it was longer.
+pub(crate) fn trim_field(
+    warnings: &mut Vec<TrimWarning>,
+    field: impl FnOnce() -> String,
+    value: &mut String,
+    limit: usize,
+) {
+    let original_len = value.chars().count();
+    if original_len <= limit {
+        return;
+    }
+
+    *value = value.chars().take(limit).collect();
+    warnings.push(TrimWarning {
+        field: field(),
+        original_len,
+        limit,
+    });
+}
+
 /// Function to create client for testing
 #[cfg(test)]
 pub fn create_test_client() -> MercadoPagoClient {
@@ -87,3 +597,139 @@ pub fn get_test_payment_options() -> PaymentCreateOptions {
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{
+        format_system_time, resolve_empty, resolve_json, ExternalReference, ExternalReferenceError,
+        MercadoPagoRequestError, OrderLimits, PaymentLimits, PreferenceLimits,
+    };
+
+    #[test]
+    fn format_system_time_in_utc() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_668_692_272_042);
+        assert_eq!(format_system_time(time), "2022-11-17T13:37:52.042+00:00");
+
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400);
+        assert_eq!(
+            format_system_time(leap_day),
+            "2000-02-29T00:00:00.000+00:00"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn format_date_with_offset() {
+        use chrono::{FixedOffset, TimeZone};
+
+        let date = FixedOffset::west_opt(4 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2022, 11, 17, 9, 37, 52)
+            .unwrap();
+
+        assert_eq!(super::format_date(&date), "2022-11-17T09:37:52.000-04:00");
+    }
+
+    #[test]
+    fn external_reference_validation() {
+        let reference = ExternalReference::<OrderLimits>::new("order_1234-A").unwrap();
+        assert_eq!(reference, *"order_1234-A");
+
+        assert_eq!(
+            ExternalReference::<OrderLimits>::new(""),
+            Err(ExternalReferenceError::Empty)
+        );
+        assert_eq!(
+            ExternalReference::<OrderLimits>::new("a".repeat(65)),
+            Err(ExternalReferenceError::TooLong { len: 65, max: 64 })
+        );
+        assert_eq!(
+            ExternalReference::<OrderLimits>::new("order #1234"),
+            Err(ExternalReferenceError::InvalidCharacter(' '))
+        );
+        assert!(serde_json::from_str::<ExternalReference>(r#""pedido:1""#).is_err());
+        assert_eq!(
+            serde_json::to_string(&ExternalReference::<OrderLimits>::new("pedido-1").unwrap())
+                .unwrap(),
+            r#""pedido-1""#
+        );
+    }
+
+    #[test]
+    fn external_reference_across_resources() {
+        let payment = ExternalReference::<PaymentLimits>::new("pedido 1234.5").unwrap();
+        assert!(payment.convert::<PreferenceLimits>().is_ok());
+        assert_eq!(
+            payment.convert::<OrderLimits>(),
+            Err(ExternalReferenceError::InvalidCharacter(' '))
+        );
+        assert!(serde_json::from_str::<ExternalReference<PaymentLimits>>(r#""pedido:1""#).is_ok());
+
+        let payment = ExternalReference::<PaymentLimits>::new("order-4711").unwrap();
+        let order = payment.convert::<OrderLimits>().unwrap();
+        assert_eq!(payment, order);
+        assert_eq!(order, "order-4711".to_string());
+
+        assert_eq!(
+            ExternalReference::<PaymentLimits>::new("a".repeat(257)),
+            Err(ExternalReferenceError::TooLong { len: 257, max: 256 })
+        );
+    }
+
+    fn response(status: u16, body: &'static str) -> reqwest::Response {
+        http::Response::builder()
+            .status(status)
+            .body(body)
+            .unwrap()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn resolve_empty_accepts_no_content() {
+        assert!(resolve_empty(response(204, "")).await.is_ok());
+        assert!(resolve_empty(response(200, "{}")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn resolve_empty_and_json_parse_errors() {
+        const ERROR_BODY: &str =
+            r#"{"message":"not found","error":"not_found","status":404,"cause":[]}"#;
+
+        assert!(matches!(
+            resolve_empty(response(404, ERROR_BODY)).await,
+            Err(MercadoPagoRequestError::MercadoPago(err)) if err.status == 404
+        ));
+        assert!(matches!(
+            resolve_json::<serde_json::Value>(response(404, ERROR_BODY)).await,
+            Err(MercadoPagoRequestError::MercadoPago(err)) if err.status == 404
+        ));
+    }
+
+    #[tokio::test]
+    async fn support_summary_has_no_messages() {
+        let response: reqwest::Response = http::Response::builder()
+            .status(400)
+            .header("x-request-id", "6b7c2d8a-1f0e-4a3b-9c8d-7e6f5a4b3c2d")
+            .body(
+                r#"{"message":"invalid email test@testmail.com","error":"bad_request","status":400,"cause":[
+                    {"code":2067,"description":"Invalid user identification number","data":"08-09-2023T22:33:32UTC;c68defe3"},
+                    {"code":4020,"description":"notificaction_url attribute must be url valid","data":"08-09-2023T22:33:32UTC;c68defe3"}
+                ]}"#,
+            )
+            .unwrap()
+            .into();
+
+        let Err(MercadoPagoRequestError::MercadoPago(err)) =
+            resolve_json::<serde_json::Value>(response).await
+        else {
+            panic!("Expected a Mercado Pago error");
+        };
+
+        assert_eq!(
+            err.support_summary(),
+            "status=400 error=bad_request causes=[2067,4020] request_id=6b7c2d8a-1f0e-4a3b-9c8d-7e6f5a4b3c2d"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub use self::card_create_builder::CustomerCardCreateBuilder;
+pub use self::create_builder::CustomerCreateBuilder;
+pub use self::search_builder::CustomerSearchBuilder;
+
+mod card_create_builder;
+mod create_builder;
+mod search_builder;
+pub mod types;
+mod vault;
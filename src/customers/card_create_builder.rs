@@ -0,0 +1,92 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::CustomerId,
+};
+
+use super::types::{CustomerCard, CustomerCardCreateOptions};
+
+/// Builder for saving a card for a customer
+///
+/// # Arguments
+///
+/// * `customer_id` - Unique customer identifier, automatically generated by Mercado Pago.
+/// * `options` - Card token to save. The token is used up, create another one from the saved card to pay.
+///
+/// # Example
+/// ```
+/// use mpago::customers::{types::CustomerCardCreateOptions, CustomerCardCreateBuilder};
+///
+/// CustomerCardCreateBuilder {
+///     customer_id: "123456789-jxOV430go9fx2e".into(),
+///     options: CustomerCardCreateOptions {
+///         token: "9b2d63e00d66a8c721607214cedaecda".to_string(),
+///     },
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/cards/_customers_customer_id_cards/post>
+#[derive(Debug, Clone)]
+pub struct CustomerCardCreateBuilder {
+    pub customer_id: CustomerId,
+    pub options: CustomerCardCreateOptions,
+}
+
+impl CustomerCardCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<CustomerCard, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::POST,
+                    format!("/v1/customers/{}/cards", self.customer_id),
+                ),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<CustomerCard>(res).await
+    }
+}
+
+impl SendRequest for CustomerCardCreateBuilder {
+    type Output = CustomerCard;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        CustomerCardCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::CustomerCardCreateBuilder;
+    use crate::{common::create_test_client, customers::types::CustomerCardCreateOptions};
+
+    #[tokio::test]
+    async fn fail_with_invalid_token() {
+        let mp_client = create_test_client();
+
+        let res = CustomerCardCreateBuilder {
+            customer_id: "invalid".into(),
+            options: CustomerCardCreateOptions {
+                token: "invalid".to_string(),
+            },
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
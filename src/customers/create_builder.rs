@@ -0,0 +1,81 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{CustomerCreateOptions, CustomerResponse};
+
+/// Builder for creating a customer, to save cards for future payments
+///
+/// # Arguments
+///
+/// * `options` - Options to create the customer.
+///
+/// # Example
+/// ```
+/// use mpago::customers::{types::CustomerCreateOptions, CustomerCreateBuilder};
+///
+/// CustomerCreateBuilder(CustomerCreateOptions {
+///     email: "test_user@testmail.com".to_string(),
+///     first_name: Some("João".to_string()),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/customers/_customers/post>
+#[derive(Debug, Clone)]
+pub struct CustomerCreateBuilder(pub CustomerCreateOptions);
+
+impl CustomerCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<CustomerResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/v1/customers"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<CustomerResponse>(res).await
+    }
+}
+
+impl SendRequest for CustomerCreateBuilder {
+    type Output = CustomerResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        CustomerCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::CustomerCreateBuilder;
+    use crate::{common::create_test_client, customers::types::CustomerCreateOptions};
+
+    #[tokio::test]
+    async fn fail_with_invalid_email() {
+        let mp_client = create_test_client();
+
+        let res = CustomerCreateBuilder(CustomerCreateOptions {
+            email: "invalid".to_string(),
+            ..Default::default()
+        })
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
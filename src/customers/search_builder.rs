@@ -0,0 +1,84 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{CustomerSearchOptions, CustomerSearchResponse};
+
+/// Builder for search customers
+///
+/// Mostly used to find the customer of an email, since there is only one customer per email.
+///
+/// # Arguments
+///
+/// * `options` - Options to search for customers.
+///
+/// # Example
+/// ```
+/// use mpago::customers::{types::CustomerSearchOptions, CustomerSearchBuilder};
+///
+/// CustomerSearchBuilder(CustomerSearchOptions {
+///     email: Some("test_user@testmail.com".to_string()),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/customers/_customers_search/get>
+#[derive(Debug, Clone)]
+pub struct CustomerSearchBuilder(pub CustomerSearchOptions);
+
+impl CustomerSearchBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<CustomerSearchResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::GET, "/v1/customers/search")
+                    .query(&self.0),
+            )
+            .await?;
+
+        mp_client.resolve_json::<CustomerSearchResponse>(res).await
+    }
+}
+
+impl SendRequest for CustomerSearchBuilder {
+    type Output = CustomerSearchResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        CustomerSearchBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::CustomerSearchBuilder;
+    use crate::{common::create_test_client, customers::types::CustomerSearchOptions};
+
+    #[tokio::test]
+    async fn search_customers() {
+        let mp_client = create_test_client();
+
+        let res = CustomerSearchBuilder(CustomerSearchOptions {
+            limit: Some(1),
+            ..Default::default()
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        assert!(res.results.len() <= 1);
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    ids::{CardId, CustomerId},
+    payer::PayerIdentification,
+    payments::types::{Paging, PaymentMethodId, PaymentResponse, PhoneNumber},
+};
+
+/// # CustomerCreateOptions
+/// Struct to use in [`CustomerCreateBuilder`](crate::customers::CustomerCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/customers/_customers/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct CustomerCreateOptions {
+    /// Email of the customer, there can be only one customer per email.
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone: Option<PhoneNumber>,
+    pub identification: Option<PayerIdentification>,
+    pub description: Option<String>,
+}
+
+/// # CustomerResponse
+/// Customer returned by `/v1/customers`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/customers/_customers_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CustomerResponse {
+    pub id: CustomerId,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone: Option<PhoneNumber>,
+    pub identification: Option<PayerIdentification>,
+    pub description: Option<String>,
+    /// Identifier of the card used by default.
+    pub default_card: Option<String>,
+    /// Saved cards.
+    #[serde(default)]
+    pub cards: Vec<CustomerCard>,
+    /// Customer create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    pub live_mode: Option<bool>,
+}
+
+/// # CustomerSearchOptions
+/// Struct to use in [`CustomerSearchBuilder`](crate::customers::CustomerSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/customers/_customers_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct CustomerSearchOptions {
+    /// Quantity of customers returned.
+    pub limit: Option<usize>,
+    /// Quantity of customers to skip.
+    pub offset: Option<usize>,
+    pub email: Option<String>,
+}
+
+/// # CustomerSearchResponse
+/// Response from `/v1/customers/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CustomerSearchResponse {
+    pub paging: Paging,
+    pub results: Vec<CustomerResponse>,
+}
+
+/// # CustomerCardCreateOptions
+/// Struct to use in [`CustomerCardCreateBuilder`](crate::customers::CustomerCardCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/cards/_customers_customer_id_cards/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CustomerCardCreateOptions {
+    /// Card token created with the card data, see [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder).
+    pub token: String,
+}
+
+/// Card saved for a customer.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CustomerCard {
+    pub id: CardId,
+    pub customer_id: CustomerId,
+    pub expiration_month: Option<u8>,
+    pub expiration_year: Option<u16>,
+    pub first_six_digits: Option<String>,
+    pub last_four_digits: Option<String>,
+    pub payment_method: Option<CustomerCardPaymentMethod>,
+    /// Card save date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+}
+
+/// Brand of a [`CustomerCard`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CustomerCardPaymentMethod {
+    pub id: PaymentMethodId,
+    pub name: Option<String>,
+}
+
+/// Result of [`PaymentCreateBuilder::send_with_vaulted_card`](crate::payments::PaymentCreateBuilder::send_with_vaulted_card).
+#[derive(Debug, Clone)]
+pub struct VaultedPayment {
+    /// Customer of the payer, found by email or created.
+    pub customer: CustomerResponse,
+    /// Card saved from the one-time token, use it in the next payments.
+    pub card: CustomerCard,
+    pub payment: PaymentResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomerResponse;
+    use crate::payments::types::PaymentMethodId;
+
+    #[test]
+    fn deserialize_customer() {
+        let customer: CustomerResponse = serde_json::from_value(serde_json::json!({
+            "id": "123456789-jxOV430go9fx2e",
+            "email": "test_user@testmail.com",
+            "default_card": "9176235123",
+            "cards": [{
+                "id": "9176235123",
+                "customer_id": "123456789-jxOV430go9fx2e",
+                "last_four_digits": "6351",
+                "payment_method": { "id": "master", "name": "Mastercard" },
+            }],
+        }))
+        .unwrap();
+
+        let card = &customer.cards[0];
+        assert_eq!(
+            card.payment_method.as_ref().unwrap().id,
+            PaymentMethodId::MasterCard
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    card_tokens::{types::CardTokenCreateOptions, CardTokenCreateBuilder},
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    payer::PayerType,
+    payments::PaymentCreateBuilder,
+};
+
+use super::{
+    types::{
+        CustomerCardCreateOptions, CustomerCreateOptions, CustomerResponse, CustomerSearchOptions,
+        VaultedPayment,
+    },
+    CustomerCardCreateBuilder, CustomerCreateBuilder, CustomerSearchBuilder,
+};
+
+impl PaymentCreateBuilder {
+    /// Saves the card of a one-time token for the payer and pays with the saved card
+    ///
+    /// Follows the documented sequence for recurring card payments:
+    /// 1. Finds the customer with the payer's email, or creates it.
+    /// 2. Saves the card of `card_token` for the customer.
+    /// 3. Creates a card token from the saved card, without the security code when `security_code` is `None`. Only accounts enabled by Mercado Pago can create tokens without it.
+    /// 4. Sends the payment with `payer.type` `customer`, the customer id and the brand of the saved card.
+    ///
+    /// Keep [`VaultedPayment::card`] to charge the card again with [`with_saved_card`](Self::with_saved_card).
+    ///
+    /// # Example
+    /// ```
+    /// let vaulted = PaymentCreateBuilder(options, None)
+    ///     .send_with_vaulted_card(&mp_client, "9b2d63e00d66a8c721607214cedaecda", Some("123"))
+    ///     .await?;
+    ///
+    /// save_card_for_user(vaulted.customer.id, vaulted.card.id);
+    /// ```
+    ///
+    /// # Docs
+    /// <https://www.mercadopago.com.br/developers/pt/docs/checkout-api/customer-management>
+    pub async fn send_with_vaulted_card(
+        mut self,
+        mp_client: &MercadoPagoClient,
+        card_token: impl ToString,
+        security_code: Option<impl ToString>,
+    ) -> Result<VaultedPayment, MercadoPagoRequestError> {
+        let customer = self.find_or_create_customer(mp_client).await?;
+
+        let card = CustomerCardCreateBuilder {
+            customer_id: customer.id.clone(),
+            options: CustomerCardCreateOptions {
+                token: card_token.to_string(),
+            },
+        }
+        .send(mp_client)
+        .await?;
+
+        let token = CardTokenCreateBuilder(CardTokenCreateOptions {
+            card_id: Some(card.id.clone()),
+            security_code: security_code.map(|code| code.to_string()),
+            ..Default::default()
+        })
+        .send(mp_client)
+        .await?;
+
+        self.0.token = Some(token.id);
+        self.0.payer.r#type = Some(PayerType::Customer);
+        self.0.payer.id = Some(customer.id.clone().into());
+        if let Some(payment_method) = &card.payment_method {
+            self.0.payment_method_id = payment_method.id.clone();
+        }
+
+        let payment = self.send(mp_client).await?;
+
+        Ok(VaultedPayment {
+            customer,
+            card,
+            payment,
+        })
+    }
+
+    /// Customer with the payer's email, created from the payer when there is none.
+    async fn find_or_create_customer(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<CustomerResponse, MercadoPagoRequestError> {
+        let payer = &self.0.payer;
+
+        let found = CustomerSearchBuilder(CustomerSearchOptions {
+            email: Some(payer.email.clone()),
+            ..Default::default()
+        })
+        .send(mp_client)
+        .await?
+        .results
+        .into_iter()
+        .next();
+
+        match found {
+            Some(customer) => Ok(customer),
+            None => {
+                CustomerCreateBuilder(CustomerCreateOptions {
+                    email: payer.email.clone(),
+                    first_name: payer.first_name.clone(),
+                    last_name: payer.last_name.clone(),
+                    identification: payer.identification.clone(),
+                    ..Default::default()
+                })
+                .send(mp_client)
+                .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        fixtures::PAYMENT_APPROVED_PIX,
+        payer::Payer,
+        payments::{
+            types::{PaymentCreateOptions, PaymentMethodId},
+            PaymentCreateBuilder,
+        },
+        test_support::{serve_in_groups, MockRequest, MockResponse},
+    };
+
+    const CUSTOMER: &str = "123456789-jxov430go9fx2e";
+
+    /// Serves the vault sequence, with the customer already created when `existing`, recording the request line and body of each request.
+    async fn serve_vault(existing: bool) -> (String, Arc<Mutex<Vec<(String, serde_json::Value)>>>) {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        let customer = json!({ "id": CUSTOMER, "email": "test_user@testmail.com" });
+
+        let url = serve_in_groups(1, move |request: MockRequest| {
+            let line = request.head.lines().next().unwrap().to_string();
+            let body = serde_json::from_slice(&request.body).unwrap_or_default();
+            recorded.lock().unwrap().push((line.clone(), body));
+
+            let path = line.split(' ').nth(1).unwrap();
+            match path.split('?').next().unwrap() {
+                "/v1/customers/search" => {
+                    let results = if existing {
+                        vec![customer.clone()]
+                    } else {
+                        vec![]
+                    };
+                    MockResponse::json(
+                        "200 OK",
+                        json!({
+                            "paging": { "total": results.len(), "limit": 10, "offset": 0 },
+                            "results": results,
+                        }),
+                    )
+                }
+                "/v1/customers" => MockResponse::json("201 Created", customer.clone()),
+                "/v1/customers/123456789-jxov430go9fx2e/cards" => MockResponse::json(
+                    "201 Created",
+                    json!({
+                        "id": "9176235123",
+                        "customer_id": CUSTOMER,
+                        "payment_method": { "id": "master", "name": "Mastercard" },
+                    }),
+                ),
+                "/v1/card_tokens" => MockResponse::json(
+                    "201 Created",
+                    json!({ "id": "ff8080814c11e237014c1ff593b57b4d", "card_id": "9176235123" }),
+                ),
+                "/v1/payments" => MockResponse::new(
+                    "201 Created",
+                    "application/json",
+                    PAYMENT_APPROVED_PIX.as_bytes(),
+                ),
+                path => panic!("unexpected request to {path}"),
+            }
+        })
+        .await;
+
+        (url, requests)
+    }
+
+    fn builder() -> PaymentCreateBuilder {
+        PaymentCreateBuilder(
+            PaymentCreateOptions {
+                payment_method_id: PaymentMethodId::Visa,
+                payer: Payer {
+                    email: "test_user@testmail.com".to_string(),
+                    first_name: Some("Fulano".to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            None,
+        )
+    }
+
+    /// Asserts the card, token and payment requests that follow finding or creating the customer.
+    fn assert_vaulted(requests: &[(String, serde_json::Value)]) {
+        let [(card_line, card), (token_line, token), (payment_line, payment)] = requests else {
+            panic!("expected 3 requests, got {requests:?}");
+        };
+
+        assert!(card_line.starts_with("post /v1/customers/123456789-jxov430go9fx2e/cards "));
+        assert_eq!(card["token"], "9b2d63e00d66a8c721607214cedaecda");
+
+        assert!(token_line.starts_with("post /v1/card_tokens "));
+        assert_eq!(token["card_id"], "9176235123");
+        assert_eq!(token["security_code"], "123");
+
+        assert!(payment_line.starts_with("post /v1/payments "));
+        assert_eq!(payment["token"], "ff8080814c11e237014c1ff593b57b4d");
+        assert_eq!(payment["payer"]["type"], "customer");
+        assert_eq!(payment["payer"]["id"], CUSTOMER);
+        assert_eq!(payment["payment_method_id"], "master");
+    }
+
+    #[tokio::test]
+    async fn vaults_card_of_new_customer() {
+        let (url, requests) = serve_vault(false).await;
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        let vaulted = builder()
+            .send_with_vaulted_card(&client, "9b2d63e00d66a8c721607214cedaecda", Some("123"))
+            .await
+            .unwrap();
+
+        assert_eq!(vaulted.customer.id.to_string(), CUSTOMER);
+        assert_eq!(vaulted.card.id.to_string(), "9176235123");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0]
+            .0
+            .starts_with("get /v1/customers/search?email=test_user%40testmail.com "));
+        assert!(requests[1].0.starts_with("post /v1/customers "));
+        assert_eq!(requests[1].1["email"], "test_user@testmail.com");
+        assert_eq!(requests[1].1["first_name"], "Fulano");
+        assert_vaulted(&requests[2..]);
+    }
+
+    #[tokio::test]
+    async fn vaults_card_of_existing_customer() {
+        let (url, requests) = serve_vault(true).await;
+        let client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        let vaulted = builder()
+            .send_with_vaulted_card(&client, "9b2d63e00d66a8c721607214cedaecda", Some("123"))
+            .await
+            .unwrap();
+
+        assert_eq!(vaulted.customer.id.to_string(), CUSTOMER);
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].0.starts_with("get /v1/customers/search?"));
+        assert_vaulted(&requests[1..]);
+    }
+}
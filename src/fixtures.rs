@@ -0,0 +1,112 @@
+//! Realistic API payloads, to write tests against the same bodies the crate is tested with.
+//!
+//! Every fixture is a JSON string that deserializes into the type named in its doc, the crate's own tests check it.
+//!
+//! # Example
+//! ```
+//! use mpago::{fixtures, payments::types::PaymentResponse};
+//!
+//! let payment: PaymentResponse = serde_json::from_str(fixtures::PAYMENT_APPROVED_PIX)?;
+//! ```
+
+/// Approved pix payment, a [`PaymentResponse`](crate::payments::types::PaymentResponse).
+pub const PAYMENT_APPROVED_PIX: &str = include_str!("fixtures/payment_approved_pix.json");
+
+/// Boleto payment waiting to be paid, a [`PaymentResponse`](crate::payments::types::PaymentResponse).
+pub const PAYMENT_PENDING_BOLETO: &str = include_str!("fixtures/payment_pending_boleto.json");
+
+/// Approved partial refund of [`PAYMENT_APPROVED_PIX`], a [`RefundResponse`](crate::refunds::types::RefundResponse).
+pub const REFUND_APPROVED: &str = include_str!("fixtures/refund_approved.json");
+
+/// Checkout Pro preference, a `PreferenceResponse` of the `preferences` feature.
+pub const PREFERENCE: &str = include_str!("fixtures/preference.json");
+
+/// Authorized subscription, a `Subscription` of the `subscriptions` feature.
+pub const SUBSCRIPTION_AUTHORIZED: &str = include_str!("fixtures/subscription_authorized.json");
+
+/// Merchant order paid by [`PAYMENT_APPROVED_PIX`] after a rejected attempt, a `MerchantOrderResponse` of the `merchant_orders` feature.
+pub const MERCHANT_ORDER_PAID: &str = include_str!("fixtures/merchant_order_paid.json");
+
+/// Webhook notification of an update of [`PAYMENT_APPROVED_PIX`], a `WebhookBody` of the `webhooks` feature.
+pub const WEBHOOK_PAYMENT_UPDATED: &str = include_str!("fixtures/webhook_payment_updated.json");
+
+/// Error for an invalid identification number, a [`MercadoPagoError`](crate::common::MercadoPagoError).
+pub const ERROR_BAD_REQUEST: &str = include_str!("fixtures/error_bad_request.json");
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::{
+        common::MercadoPagoError,
+        payments::types::{PaymentResponse, PaymentStatus},
+        refunds::types::RefundResponse,
+    };
+
+    #[test]
+    fn payments() {
+        let pix: PaymentResponse = serde_json::from_str(PAYMENT_APPROVED_PIX).unwrap();
+        assert_eq!(pix.status, PaymentStatus::Approved);
+        assert!(pix
+            .point_of_interaction
+            .transaction_data
+            .and_then(|data| data.qr_code)
+            .is_some());
+
+        let boleto: PaymentResponse = serde_json::from_str(PAYMENT_PENDING_BOLETO).unwrap();
+        assert_eq!(boleto.status, PaymentStatus::Pending);
+        assert!(boleto
+            .transaction_details
+            .and_then(|details| details.external_resource_url)
+            .is_some());
+    }
+
+    #[test]
+    fn refund() {
+        let refund: RefundResponse = serde_json::from_str(REFUND_APPROVED).unwrap();
+        assert_eq!(refund.amount, Decimal::new(40, 0));
+    }
+
+    #[test]
+    fn error() {
+        let error: MercadoPagoError = serde_json::from_str(ERROR_BAD_REQUEST).unwrap();
+        assert_eq!(error.cause[0].code, 2067);
+    }
+
+    #[cfg(feature = "preferences")]
+    #[test]
+    fn preference() {
+        let preference: crate::preferences::types::PreferenceResponse =
+            serde_json::from_str(PREFERENCE).unwrap();
+        assert_eq!(preference.items[0].quantity, 2);
+    }
+
+    #[cfg(feature = "subscriptions")]
+    #[test]
+    fn subscription() {
+        let subscription: crate::subscriptions::types::Subscription =
+            serde_json::from_str(SUBSCRIPTION_AUTHORIZED).unwrap();
+        assert_eq!(
+            subscription.status,
+            crate::subscriptions::types::SubscriptionStatus::Authorized
+        );
+    }
+
+    #[cfg(feature = "merchant_orders")]
+    #[test]
+    fn merchant_order() {
+        let order: crate::merchant_orders::types::MerchantOrderResponse =
+            serde_json::from_str(MERCHANT_ORDER_PAID).unwrap();
+        let pix: PaymentResponse = serde_json::from_str(PAYMENT_APPROVED_PIX).unwrap();
+        assert_eq!(pix.merchant_order_id(), Some(order.id));
+    }
+
+    #[cfg(feature = "webhooks")]
+    #[test]
+    fn webhook() {
+        let webhook: crate::webhooks::WebhookBody =
+            serde_json::from_str(WEBHOOK_PAYMENT_UPDATED).unwrap();
+        assert_eq!(webhook.data.unwrap().payment_id(), Some(1319541963.into()));
+    }
+}
@@ -0,0 +1,55 @@
+//! Reusable code for the documented integration flows, as small functions that can be composed in any web framework.
+//!
+//! * [`pix`] - Pix checkout, confirmed by the payment notification.
+//! * [`subscription`] - Subscription signup with a plan, confirmed by the subscription notification.
+//! * [`marketplace`] - Marketplace payment split between sellers.
+//!
+//! The functions that don't call the API, like checking a notification, are pure, so they can be tested without credentials. The `examples/` of the repository run each flow against the sandbox.
+
+use thiserror::Error;
+
+use crate::{
+    advanced_payments::types::AdvancedPaymentValidationError, common::MercadoPagoRequestError,
+    webhooks::WebhookVerifyError,
+};
+
+pub mod marketplace;
+pub mod pix;
+pub mod subscription;
+
+/// Error of a step of a flow
+#[derive(Error, Debug)]
+pub enum FlowError {
+    #[error("{0}")]
+    Request(#[from] MercadoPagoRequestError),
+    #[error("{0}")]
+    SplitValidation(#[from] AdvancedPaymentValidationError),
+    #[error("Notification body is invalid: {0}")]
+    InvalidNotification(#[from] serde_json::Error),
+    /// The `x-signature` of the notification doesn't match, it may not be from Mercado Pago.
+    #[error("Notification signature is invalid")]
+    InvalidSignature,
+    /// The response lacks a field the flow needs, like the QR code of a Pix payment.
+    #[error("Response has no {0}")]
+    MissingField(&'static str),
+}
+
+impl From<WebhookVerifyError> for FlowError {
+    fn from(err: WebhookVerifyError) -> Self {
+        match err {
+            WebhookVerifyError::InvalidBody(err) => FlowError::InvalidNotification(err),
+            WebhookVerifyError::InvalidSignature => FlowError::InvalidSignature,
+        }
+    }
+}
+
+/// Headers of a notification used to verify its origin, and the `data.id` of its URL.
+#[derive(Debug, Clone)]
+pub struct NotificationHeaders {
+    /// `data.id` of the query string. The `data.id` of the body is used when `None`.
+    pub data_id: Option<String>,
+    /// Value of the `x-signature` header.
+    pub x_signature: String,
+    /// Value of the `x-request-id` header.
+    pub x_request_id: Option<String>,
+}
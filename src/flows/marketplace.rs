@@ -0,0 +1,116 @@
+//! Marketplace split: one payment of the buyer, split between the sellers of the cart with the commission of the marketplace.
+//!
+//! ```
+//! use mpago::flows::marketplace;
+//!
+//! let disbursements = marketplace::split(
+//!     Decimal::new(100, 0),
+//!     &[(1822339427.into(), Decimal::new(60, 0)), (1822339428.into(), Decimal::new(40, 0))],
+//!     Decimal::new(5, 2), // 5% for the marketplace
+//! );
+//!
+//! let payment = marketplace::create_split_payment(&mp_client, options_with(disbursements), "cart-9823").await?;
+//! ```
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{
+    advanced_payments::{
+        types::{AdvancedPaymentCreateOptions, AdvancedPaymentResponse, Disbursement},
+        AdvancedPaymentCreateBuilder,
+    },
+    client::MercadoPagoClient,
+    ids::UserId,
+};
+
+use super::FlowError;
+
+/// Splits `total` between `sellers` in proportion to their weights, like the subtotal of each seller in the cart, keeping `application_fee_rate` of each part for the marketplace.
+///
+/// Amounts are rounded to cents and the rounding difference goes to the first seller, so the disbursements always add up to `total`. Sellers with a weight of zero are left out.
+pub fn split(
+    total: Decimal,
+    sellers: &[(UserId, Decimal)],
+    application_fee_rate: Decimal,
+) -> Vec<Disbursement> {
+    let sellers = sellers
+        .iter()
+        .filter(|(_, weight)| *weight > Decimal::ZERO)
+        .collect::<Vec<_>>();
+    let total_weight = sellers.iter().map(|(_, weight)| *weight).sum::<Decimal>();
+    if total_weight.is_zero() {
+        return vec![];
+    }
+
+    let mut amounts = sellers
+        .iter()
+        .map(|(_, weight)| cents(total * weight / total_weight))
+        .collect::<Vec<_>>();
+    let rounding = total - amounts.iter().sum::<Decimal>();
+    amounts[0] += rounding;
+
+    sellers
+        .iter()
+        .zip(amounts)
+        .map(|((collector_id, _), amount)| {
+            Disbursement::new(amount, *collector_id)
+                .with_application_fee(cents(amount * application_fee_rate))
+        })
+        .collect()
+}
+
+fn cents(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven)
+}
+
+/// Validates the split and creates the advanced payment.
+///
+/// `idempotency_key` should identify the cart, so a retried checkout doesn't charge the buyer twice.
+pub async fn create_split_payment(
+    mp_client: &MercadoPagoClient,
+    options: AdvancedPaymentCreateOptions,
+    idempotency_key: impl ToString,
+) -> Result<AdvancedPaymentResponse, FlowError> {
+    let builder = AdvancedPaymentCreateBuilder(options, Some(idempotency_key.to_string()));
+    builder.validate()?;
+
+    Ok(builder.send(mp_client).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::split;
+
+    #[test]
+    fn split_adds_up_to_total() {
+        let disbursements = split(
+            Decimal::new(100, 0),
+            &[
+                (1.into(), Decimal::ONE),
+                (2.into(), Decimal::ONE),
+                (3.into(), Decimal::ONE),
+                (4.into(), Decimal::ZERO),
+            ],
+            Decimal::new(5, 2),
+        );
+
+        let amounts = disbursements
+            .iter()
+            .map(|disbursement| disbursement.amount)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            amounts,
+            [
+                Decimal::new(3334, 2),
+                Decimal::new(3333, 2),
+                Decimal::new(3333, 2)
+            ]
+        );
+        assert_eq!(disbursements[0].application_fee, Some(Decimal::new(167, 2)));
+        assert_eq!(disbursements[2].collector_id, 3);
+
+        assert!(split(Decimal::new(100, 0), &[], Decimal::ZERO).is_empty());
+    }
+}
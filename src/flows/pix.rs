@@ -0,0 +1,200 @@
+//! Pix checkout: create the charge, show the QR to the payer and confirm the payment when the notification arrives.
+//!
+//! ```
+//! use mpago::flows::pix;
+//!
+//! // Checkout
+//! let charge = pix::create_charge(&mp_client, pix::charge_options(amount, "payer@email.com", "Pedido 4711"), "order-4711").await?;
+//! render_qr(&charge.qr_code);
+//!
+//! // Webhook handler
+//! if let Some(payment_id) = pix::verified_payment_id(body, &secret, &headers)? {
+//!     let payment = pix::confirm(&mp_client, payment_id).await?;
+//!     if pix::is_paid(&payment) { /* release the order */ }
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+
+use crate::{
+    client::MercadoPagoClient,
+    ids::PaymentId,
+    payer::Payer,
+    payments::{
+        types::{PaymentCreateOptions, PaymentMethodId, PaymentResponse, PaymentStatus},
+        PaymentCreateBuilder, PaymentGetBuilder,
+    },
+    webhooks::{SecretResolver, WebhookBody, WebhookType},
+};
+
+use super::{FlowError, NotificationHeaders};
+
+/// Pix charge to show to the payer.
+#[derive(Debug, Clone)]
+pub struct PixCharge {
+    pub payment_id: PaymentId,
+    /// Pix "copia e cola" code, also the content of the QR.
+    pub qr_code: String,
+    /// PNG of the QR, in base64.
+    pub qr_code_base64: Option<String>,
+    /// Page of Mercado Pago with the QR and the instructions to pay.
+    pub ticket_url: Option<String>,
+}
+
+/// Options of a Pix payment of `amount`.
+pub fn charge_options(
+    amount: Decimal,
+    payer_email: impl ToString,
+    description: impl ToString,
+) -> PaymentCreateOptions {
+    PaymentCreateOptions {
+        transaction_amount: amount,
+        installments: 1,
+        description: Some(description.to_string()),
+        payment_method_id: PaymentMethodId::Pix,
+        payer: Payer {
+            email: payer_email.to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Creates the Pix payment and extracts the QR of the response.
+///
+/// `idempotency_key` should identify the order, so a retried checkout doesn't create a second charge.
+pub async fn create_charge(
+    mp_client: &MercadoPagoClient,
+    options: PaymentCreateOptions,
+    idempotency_key: impl ToString,
+) -> Result<PixCharge, FlowError> {
+    let payment = PaymentCreateBuilder(options, Some(idempotency_key.to_string()))
+        .send(mp_client)
+        .await?;
+
+    charge_of(&payment)
+}
+
+/// The [`PixCharge`] of a Pix payment.
+pub fn charge_of(payment: &PaymentResponse) -> Result<PixCharge, FlowError> {
+    let transaction_data = payment
+        .point_of_interaction
+        .transaction_data
+        .as_ref()
+        .ok_or(FlowError::MissingField(
+            "point_of_interaction.transaction_data",
+        ))?;
+
+    Ok(PixCharge {
+        payment_id: payment.id,
+        qr_code: transaction_data
+            .qr_code
+            .clone()
+            .ok_or(FlowError::MissingField("qr_code"))?,
+        qr_code_base64: transaction_data.qr_code_base64.clone(),
+        ticket_url: transaction_data.ticket_url.clone(),
+    })
+}
+
+/// Verifies a notification and returns the id of its payment.
+///
+/// Returns `None` for notifications of other topics, which should still be answered with `200`.
+pub fn verified_payment_id(
+    body: &[u8],
+    secrets: &(impl SecretResolver + ?Sized),
+    headers: &NotificationHeaders,
+) -> Result<Option<PaymentId>, FlowError> {
+    let notification = WebhookBody::verify_and_parse(
+        body,
+        secrets,
+        headers.data_id.as_deref(),
+        &headers.x_signature,
+        headers.x_request_id.clone(),
+    )?;
+
+    if notification.r#type != WebhookType::Payment {
+        return Ok(None);
+    }
+
+    Ok(notification.data.and_then(|data| data.payment_id()))
+}
+
+/// Gets the current state of the payment. The notification only says it changed, never trust its content.
+pub async fn confirm(
+    mp_client: &MercadoPagoClient,
+    payment_id: PaymentId,
+) -> Result<PaymentResponse, FlowError> {
+    Ok(PaymentGetBuilder(payment_id).send(mp_client).await?)
+}
+
+/// Whether the order of the payment can be released.
+pub fn is_paid(payment: &PaymentResponse) -> bool {
+    payment.status == PaymentStatus::Approved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{charge_of, is_paid, verified_payment_id};
+    use crate::{
+        fixtures,
+        flows::{FlowError, NotificationHeaders},
+        payments::types::PaymentResponse,
+    };
+
+    #[test]
+    fn charge_of_pix_payment() {
+        let payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+
+        let charge = charge_of(&payment).unwrap();
+
+        assert_eq!(charge.payment_id, payment.id);
+        assert!(charge.qr_code.starts_with("000201"));
+        assert!(is_paid(&payment));
+    }
+
+    #[test]
+    fn verifies_notification() {
+        let body = br#"{"id": 12345, "live_mode": true, "type": "payment", "date_created": "2015-03-25T10:04:58.396-04:00", "user_id": 44444, "api_version": "v1", "action": "payment.created", "data": {"id": "999999999"}}"#;
+        let secret = b"secret".to_vec();
+        let headers = |v1: &str| NotificationHeaders {
+            data_id: None,
+            x_signature: format!("ts=1704908010,v1={v1}"),
+            x_request_id: Some("bb56a2f1-6aae-46ac-982e-9dcd3581d08e".to_string()),
+        };
+
+        let v1 = signature(&secret, "999999999");
+        assert_eq!(
+            verified_payment_id(body, &secret, &headers(&v1)).unwrap(),
+            Some(999999999.into())
+        );
+        let from_query = NotificationHeaders {
+            data_id: Some("999999999".to_string()),
+            ..headers(&v1)
+        };
+        assert!(verified_payment_id(body, &secret, &from_query).is_ok());
+        assert!(matches!(
+            verified_payment_id(body, &secret, &headers(&signature(&secret, "12345"))),
+            Err(FlowError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verified_payment_id(body, &secret, &headers("00")),
+            Err(FlowError::InvalidSignature)
+        ));
+    }
+
+    fn signature(secret: &[u8], data_id: &str) -> String {
+        use hmac::{Hmac, Mac};
+
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret).unwrap();
+        mac.update(
+            format!("id:{data_id};request-id:bb56a2f1-6aae-46ac-982e-9dcd3581d08e;ts:1704908010;")
+                .as_bytes(),
+        );
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
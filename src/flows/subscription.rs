@@ -0,0 +1,150 @@
+//! Subscription signup with a plan: create the plan, send the subscriber to its checkout and confirm the subscription when the notification arrives.
+//!
+//! ```
+//! use mpago::flows::subscription;
+//!
+//! let signup = subscription::create_signup(
+//!     &mp_client,
+//!     subscription::plan_options("Plano mensal", Decimal::new(50, 0), CurrencyId::BRL, "https://example.com/subscribed"),
+//! )
+//! .await?;
+//! redirect_to(&signup.checkout_url);
+//!
+//! // Webhook handler
+//! if let Some(subscription_id) = subscription::verified_subscription_id(body, &secret, &headers)? {
+//!     let subscription = subscription::confirm(&mp_client, subscription_id).await?;
+//!     if subscription::is_active(&subscription) { /* unlock the content */ }
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+
+use crate::{
+    client::MercadoPagoClient,
+    ids::{PlanId, SubscriptionId},
+    payments::types::CurrencyId,
+    plans::{
+        types::{AutoRecurring, PlanCreateOptions},
+        PlanCreateBuilder,
+    },
+    subscriptions::{
+        types::{Subscription, SubscriptionStatus},
+        SubscriptionGetBuilder,
+    },
+    webhooks::{SecretResolver, WebhookBody, WebhookType},
+};
+
+use super::{FlowError, NotificationHeaders};
+
+/// Plan created for the signup.
+#[derive(Debug, Clone)]
+pub struct Signup {
+    pub plan_id: PlanId,
+    /// Checkout where the subscriber authorizes the subscription.
+    pub checkout_url: String,
+}
+
+/// Options of a plan charging `amount` every month, redirecting to `back_url` after the checkout.
+pub fn plan_options(
+    reason: impl ToString,
+    amount: Decimal,
+    currency_id: CurrencyId,
+    back_url: impl ToString,
+) -> PlanCreateOptions {
+    PlanCreateOptions {
+        reason: reason.to_string(),
+        auto_recurring: AutoRecurring::monthly(amount, currency_id),
+        payment_methods_allowed: None,
+        back_url: back_url.to_string(),
+    }
+}
+
+/// Creates the plan and returns its checkout.
+pub async fn create_signup(
+    mp_client: &MercadoPagoClient,
+    options: PlanCreateOptions,
+) -> Result<Signup, FlowError> {
+    let plan = PlanCreateBuilder(options).send(mp_client).await?;
+
+    Ok(Signup {
+        checkout_url: plan
+            .init_point
+            .ok_or(FlowError::MissingField("init_point"))?,
+        plan_id: plan.id,
+    })
+}
+
+/// Verifies a notification and returns the id of its subscription.
+///
+/// Returns `None` for notifications of other topics, which should still be answered with `200`.
+pub fn verified_subscription_id(
+    body: &[u8],
+    secrets: &(impl SecretResolver + ?Sized),
+    headers: &NotificationHeaders,
+) -> Result<Option<SubscriptionId>, FlowError> {
+    let notification = WebhookBody::verify_and_parse(
+        body,
+        secrets,
+        headers.data_id.as_deref(),
+        &headers.x_signature,
+        headers.x_request_id.clone(),
+    )?;
+
+    if notification.r#type != WebhookType::SubscriptionPreApproval {
+        return Ok(None);
+    }
+
+    Ok(notification.data.and_then(|data| data.subscription_id()))
+}
+
+/// Gets the current state of the subscription. The notification only says it changed, never trust its content.
+pub async fn confirm(
+    mp_client: &MercadoPagoClient,
+    subscription_id: SubscriptionId,
+) -> Result<Subscription, FlowError> {
+    Ok(SubscriptionGetBuilder(subscription_id)
+        .send(mp_client)
+        .await?)
+}
+
+/// Whether the subscriber is being charged, so the content can be unlocked.
+pub fn is_active(subscription: &Subscription) -> bool {
+    subscription.status == SubscriptionStatus::Authorized
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{plan_options, verified_subscription_id};
+    use crate::{flows::NotificationHeaders, payments::types::CurrencyId};
+
+    #[test]
+    fn monthly_plan() {
+        let options = plan_options(
+            "Plano mensal",
+            Decimal::new(50, 0),
+            CurrencyId::BRL,
+            "https://example.com/subscribed",
+        );
+
+        assert_eq!(options.auto_recurring.frequency, 1);
+        assert_eq!(
+            options.auto_recurring.transaction_amount,
+            Some(Decimal::new(50, 0))
+        );
+    }
+
+    #[test]
+    fn ignores_other_topics_and_rejects_bad_signatures() {
+        let headers = NotificationHeaders {
+            data_id: None,
+            x_signature: "ts=1704908010,v1=00".to_string(),
+            x_request_id: None,
+        };
+        let body = br#"{"id": 12345, "type": "subscription_preapproval", "data": {"id": "2c938084726fca480172750000000000"}}"#;
+
+        assert!(verified_subscription_id(body, "secret", &headers).is_err());
+        assert!(verified_subscription_id(br#"{"#, "secret", &headers).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Typed identifiers of Mercado Pago resources.
+//!
+//! Every resource has its own id type, so a collector id can't be passed where a payment id is expected. They convert from and into the raw values with `From`/`Into`, and serialize as the raw value. Builders taking only an id convert from it and from the raw value too.
+//!
+//! ```
+//! use mpago::{ids::PaymentId, payments::PaymentGetBuilder};
+//!
+//! let payment = PaymentGetBuilder(87891224.into()).send(&mp_client).await?;
+//! let payment = PaymentGetBuilder::from(87891224).send(&mp_client).await?;
+//! let raw: u64 = payment.id.into();
+//! ```
+
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// Id backed by a number.
+macro_rules! numeric_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<u64> for $name {
+            fn eq(&self, other: &u64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+/// Id backed by a string. With `from number`, it also deserializes from the numbers some APIs send instead.
+macro_rules! string_id {
+    (@define $(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                $name(id.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident) => {
+        string_id!(@define $(#[$meta])* #[derive(Deserialize)] $name);
+    };
+    ($(#[$meta:meta])* $name:ident from number) => {
+        string_id!(@define $(#[$meta])* $name);
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(untagged)]
+                enum NumberOrString {
+                    Number(u64),
+                    String(String),
+                }
+
+                Ok(match NumberOrString::deserialize(deserializer)? {
+                    NumberOrString::Number(id) => $name(id.to_string()),
+                    NumberOrString::String(id) => $name(id),
+                })
+            }
+        }
+    };
+}
+
+numeric_id!(
+    /// Id of a payment, in [`payments`](crate::payments).
+    PaymentId
+);
+numeric_id!(
+    /// Id of a refund of a payment, in [`refunds`](crate::refunds).
+    RefundId
+);
+numeric_id!(
+    /// Id of a Mercado Pago user, like the collector of a payment or a seller of a marketplace.
+    UserId
+);
+numeric_id!(
+    /// Id of a point of sale, in `pos`.
+    PosId
+);
+numeric_id!(
+    /// Id of an advanced payment, in `advanced_payments`.
+    AdvancedPaymentId
+);
+numeric_id!(
+    /// Id of a merchant order, in `merchant_orders`.
+    MerchantOrderId
+);
+numeric_id!(
+    /// Id of a charge (`authorized_payment`) of a subscription, in `subscriptions`.
+    InvoiceId
+);
+string_id!(
+    /// Id of a customer, in `customers`.
+    CustomerId
+);
+string_id!(
+    /// Id of a saved card of a customer, in `customers`.
+    ///
+    /// Subscriptions send it as a number, so it deserializes from numbers too.
+    CardId from number
+);
+string_id!(
+    /// Id of a subscription (`preapproval`), in `subscriptions`.
+    SubscriptionId
+);
+string_id!(
+    /// Id of a subscription plan (`preapproval_plan`), in `plans`.
+    PlanId
+);
+string_id!(
+    /// Id of a Checkout Pro preference, in `preferences`.
+    PreferenceId
+);
+string_id!(
+    /// Id of a physical store, in `stores`.
+    StoreId
+);
+string_id!(
+    /// Id of an order of the Orders API, like `ORD01JQ4S4KY8HWQ6NA5PXB65B3D3`, in `orders`.
+    OrderId
+);
+string_id!(
+    /// Id of a transaction of an order, like `PAY01JQ4S4KY8HWQ6NA5PXB65B3D3`, in `orders`.
+    OrderTransactionId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{CardId, PaymentId, PreferenceId, UserId};
+    use crate::payments::PaymentGetBuilder;
+
+    #[test]
+    fn conversions_and_serialization() {
+        let id = PaymentId::from(87891224);
+        assert_eq!(id, 87891224);
+        assert_eq!(u64::from(id), 87891224);
+        assert_eq!(id.to_string(), "87891224");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "87891224");
+        assert_eq!(serde_json::from_str::<PaymentId>("87891224").unwrap(), id);
+        assert_eq!(
+            serde_json::from_str::<UserId>("1822339427").unwrap(),
+            1822339427
+        );
+
+        let id = PreferenceId::from("1234567-abcdef");
+        assert_eq!(id, "1234567-abcdef");
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""1234567-abcdef""#);
+        assert_eq!(String::from(id), "1234567-abcdef");
+
+        let id = serde_json::from_str::<CardId>("9176235123").unwrap();
+        assert_eq!(id, "9176235123");
+        assert_eq!(
+            serde_json::from_str::<CardId>(r#""9176235123""#).unwrap(),
+            id
+        );
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""9176235123""#);
+        assert!(serde_json::from_str::<CardId>("true").is_err());
+    }
+
+    #[test]
+    fn builders_from_raw_ids() {
+        assert_eq!(PaymentGetBuilder::from(87891224).0, 87891224);
+        assert_eq!(PaymentGetBuilder::from(PaymentId(87891224)).0, 87891224);
+    }
+}
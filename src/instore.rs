@@ -0,0 +1,10 @@
+pub use self::qr_order_create_builder::QrOrderCreateBuilder;
+pub use self::static_qr_get_builder::StaticQrGetBuilder;
+pub use self::static_qr_order_builder::StaticQrOrderBuilder;
+pub use self::static_qr_order_delete_builder::StaticQrOrderDeleteBuilder;
+
+mod qr_order_create_builder;
+mod static_qr_get_builder;
+mod static_qr_order_builder;
+mod static_qr_order_delete_builder;
+pub mod types;
@@ -0,0 +1,110 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+};
+
+use super::types::{QrOrderCreateOptions, QrOrderResponse};
+
+/// Builder for creating an in-store order paid with a dynamic QR
+///
+/// The response has the `qr_data` to render as a QR code, valid for this order only.
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that receives the payment.
+/// * `external_pos_id` - `external_id` of the point of sale where the order is charged.
+/// * `options` - Items and amount of the order.
+///
+/// # Example
+/// ```
+/// use mpago::instore::{types::{QrOrderCreateOptions, QrOrderItem}, QrOrderCreateBuilder};
+///
+/// QrOrderCreateBuilder {
+///     user_id: 1234567890.into(),
+///     external_pos_id: "SUC001POS001".to_string(),
+///     options: QrOrderCreateOptions::new(
+///         "Pedido 4711",
+///         vec![QrOrderItem::new("Café", Decimal::new(850, 2), Decimal::new(2, 0))],
+///     )
+///     .with_external_reference("order-4711")
+///     .with_notification_url("https://example.com/webhooks/mercadopago"),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/qr-dynamic/_instore_orders_qr_seller_collectors_user_id_pos_external_pos_id_qrs/post>
+#[derive(Debug, Clone)]
+pub struct QrOrderCreateBuilder {
+    pub user_id: UserId,
+    pub external_pos_id: String,
+    pub options: QrOrderCreateOptions,
+}
+
+impl QrOrderCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<QrOrderResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::POST,
+                    format!(
+                        "/instore/orders/qr/seller/collectors/{}/pos/{}/qrs",
+                        self.user_id, self.external_pos_id
+                    ),
+                ),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<QrOrderResponse>(res).await
+    }
+}
+
+impl SendRequest for QrOrderCreateBuilder {
+    type Output = QrOrderResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        QrOrderCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::QrOrderCreateBuilder;
+    use crate::{
+        common::create_test_client,
+        instore::types::{QrOrderCreateOptions, QrOrderItem},
+    };
+
+    #[tokio::test]
+    async fn fail_with_unknown_pos() {
+        let mp_client = create_test_client();
+
+        let res = QrOrderCreateBuilder {
+            user_id: 1.into(),
+            external_pos_id: "UNKNOWN".to_string(),
+            options: QrOrderCreateOptions::new(
+                "Pedido",
+                vec![QrOrderItem::new("Café", Decimal::new(850, 2), Decimal::ONE)],
+            ),
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{StaticQr, StaticQrSearchResponse};
+
+/// Builder for getting the static QR of a point of sale, with its images and EMV data
+///
+/// Returns `None` when there is no point of sale with this `external_id`.
+///
+/// # Arguments
+///
+/// * `external_pos_id` - `external_id` of the point of sale.
+///
+/// # Example
+/// ```
+/// use mpago::instore::StaticQrGetBuilder;
+///
+/// let qr = StaticQrGetBuilder("SUC001POS001".to_string())
+///     .send(&mp_client)
+///     .await?
+///     .expect("point of sale exists");
+///
+/// println!("{}", qr.qr.image);
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/get>
+#[derive(Debug, Clone)]
+pub struct StaticQrGetBuilder(pub String);
+
+impl StaticQrGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Option<StaticQr>, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::GET, "/pos")
+                    .query(&[("external_id", self.0)]),
+            )
+            .await?;
+
+        Ok(mp_client
+            .resolve_json::<StaticQrSearchResponse>(res)
+            .await?
+            .results
+            .into_iter()
+            .next())
+    }
+}
+
+impl SendRequest for StaticQrGetBuilder {
+    type Output = Option<StaticQr>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StaticQrGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::StaticQrGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn unknown_pos_is_none() {
+        let mp_client = create_test_client();
+
+        let res = StaticQrGetBuilder("UNKNOWN".to_string())
+            .send(&mp_client)
+            .await;
+
+        assert!(res.unwrap().is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+};
+
+use super::types::QrOrderCreateOptions;
+
+/// Builder for creating or updating the order of the static QR of a point of sale
+///
+/// In the attended model the QR printed at the point of sale never changes, the cashier puts the order on it and the payer scans it. Sending a new order replaces the current one.
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that receives the payment.
+/// * `external_pos_id` - `external_id` of the point of sale that owns the QR.
+/// * `options` - Items and amount of the order.
+///
+/// # Example
+/// ```
+/// use mpago::instore::{types::{QrOrderCreateOptions, QrOrderItem}, StaticQrOrderBuilder};
+///
+/// StaticQrOrderBuilder {
+///     user_id: 1234567890.into(),
+///     external_pos_id: "SUC001POS001".to_string(),
+///     options: QrOrderCreateOptions::new(
+///         "Pedido 4711",
+///         vec![QrOrderItem::new("Café", Decimal::new(850, 2), Decimal::new(2, 0))],
+///     )
+///     .with_external_reference("order-4711"),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/qr-static/_instore_qr_seller_collectors_user_id_pos_external_pos_id_orders/put>
+#[derive(Debug, Clone)]
+pub struct StaticQrOrderBuilder {
+    pub user_id: UserId,
+    pub external_pos_id: String,
+    pub options: QrOrderCreateOptions,
+}
+
+impl StaticQrOrderBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<(), MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::PUT,
+                    format!(
+                        "/instore/qr/seller/collectors/{}/pos/{}/orders",
+                        self.user_id, self.external_pos_id
+                    ),
+                ),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_empty(res).await
+    }
+}
+
+impl SendRequest for StaticQrOrderBuilder {
+    type Output = ();
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StaticQrOrderBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::StaticQrOrderBuilder;
+    use crate::{
+        common::create_test_client,
+        instore::types::{QrOrderCreateOptions, QrOrderItem},
+    };
+
+    #[tokio::test]
+    async fn fail_with_unknown_pos() {
+        let mp_client = create_test_client();
+
+        let res = StaticQrOrderBuilder {
+            user_id: 1.into(),
+            external_pos_id: "UNKNOWN".to_string(),
+            options: QrOrderCreateOptions::new(
+                "Pedido",
+                vec![QrOrderItem::new("Café", Decimal::new(850, 2), Decimal::ONE)],
+            ),
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
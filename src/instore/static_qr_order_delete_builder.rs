@@ -0,0 +1,64 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+};
+
+/// Builder for removing the order from the static QR of a point of sale
+///
+/// Use it when the sale is cancelled at the counter, so the payer can't pay it anymore.
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller.
+/// * `external_pos_id` - `external_id` of the point of sale that owns the QR.
+///
+/// # Example
+/// ```
+/// use mpago::instore::StaticQrOrderDeleteBuilder;
+///
+/// StaticQrOrderDeleteBuilder {
+///     user_id: 1234567890.into(),
+///     external_pos_id: "SUC001POS001".to_string(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/qr-static/_instore_qr_seller_collectors_user_id_pos_external_pos_id_orders/delete>
+#[derive(Debug, Clone)]
+pub struct StaticQrOrderDeleteBuilder {
+    pub user_id: UserId,
+    pub external_pos_id: String,
+}
+
+impl StaticQrOrderDeleteBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<(), MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(
+                Method::DELETE,
+                format!(
+                    "/instore/qr/seller/collectors/{}/pos/{}/orders",
+                    self.user_id, self.external_pos_id
+                ),
+            ))
+            .await?;
+
+        mp_client.resolve_empty(res).await
+    }
+}
+
+impl SendRequest for StaticQrOrderDeleteBuilder {
+    type Output = ();
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StaticQrOrderDeleteBuilder::send(self, mp_client)
+    }
+}
@@ -0,0 +1,244 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::ids::{PosId, StoreId, UserId};
+
+/// # QrOrderCreateOptions
+/// Struct to use in [`QrOrderCreateBuilder`](crate::instore::QrOrderCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/qr-dynamic/_instore_orders_qr_seller_collectors_user_id_pos_external_pos_id_qrs/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QrOrderCreateOptions {
+    /// Title shown to the payer in the app.
+    pub title: String,
+    pub description: Option<String>,
+    pub external_reference: Option<String>,
+    /// URL that receives the notifications of the order.
+    pub notification_url: Option<String>,
+    /// Date the QR stops accepting payments. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub expiration_date: Option<String>,
+    /// Sum of the `total_amount` of the items.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_amount: Decimal,
+    pub items: Vec<QrOrderItem>,
+    /// Cash given to the payer at the store, withdrawn from their account with the payment.
+    pub cash_out: Option<CashOut>,
+    /// Integrator that created the order.
+    pub sponsor: Option<QrOrderSponsor>,
+}
+
+impl QrOrderCreateOptions {
+    /// Order of `items`, with `total_amount` as the sum of their totals.
+    pub fn new(title: impl ToString, items: Vec<QrOrderItem>) -> Self {
+        QrOrderCreateOptions {
+            title: title.to_string(),
+            description: None,
+            external_reference: None,
+            notification_url: None,
+            expiration_date: None,
+            total_amount: items.iter().map(|item| item.total_amount).sum(),
+            items,
+            cash_out: None,
+            sponsor: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn with_description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets `external_reference`.
+    pub fn with_external_reference(mut self, external_reference: impl ToString) -> Self {
+        self.external_reference = Some(external_reference.to_string());
+
+        self
+    }
+
+    /// Sets `notification_url`.
+    pub fn with_notification_url(mut self, notification_url: impl ToString) -> Self {
+        self.notification_url = Some(notification_url.to_string());
+
+        self
+    }
+
+    /// Sets `cash_out`, the cash given to the payer at the store.
+    pub fn with_cash_out(mut self, amount: Decimal) -> Self {
+        self.cash_out = Some(CashOut { amount });
+
+        self
+    }
+}
+
+/// Item of an in-store order.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QrOrderItem {
+    pub sku_number: Option<String>,
+    pub category: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unit_price: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    /// Unit of `quantity`, like `"unit"` or `"kg"`.
+    pub unit_measure: String,
+    /// `unit_price` times `quantity`.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_amount: Decimal,
+}
+
+impl QrOrderItem {
+    /// Item sold by unit, with `total_amount` as `unit_price` times `quantity`.
+    pub fn new(title: impl ToString, unit_price: Decimal, quantity: Decimal) -> Self {
+        QrOrderItem {
+            sku_number: None,
+            category: None,
+            title: title.to_string(),
+            description: None,
+            unit_price,
+            quantity,
+            unit_measure: "unit".to_string(),
+            total_amount: unit_price * quantity,
+        }
+    }
+}
+
+/// Cash withdrawn at the store with the payment.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CashOut {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+}
+
+/// Integrator of an in-store order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QrOrderSponsor {
+    /// User id of the integrator.
+    pub id: UserId,
+}
+
+/// # QrOrderResponse
+/// Response from `/instore/orders/qr/seller/collectors/{user_id}/pos/{external_pos_id}/qrs`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QrOrderResponse {
+    pub in_store_order_id: String,
+    /// EMVCo data of the QR code, render it as a QR for the payer to scan.
+    pub qr_data: String,
+}
+
+/// # StaticQr
+/// Point of sale with its static QR, from [`StaticQrGetBuilder`](crate::instore::StaticQrGetBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaticQr {
+    /// Id of the point of sale.
+    pub id: PosId,
+    pub name: Option<String>,
+    pub external_id: Option<String>,
+    pub store_id: Option<StoreId>,
+    pub external_store_id: Option<String>,
+    pub qr: StaticQrImages,
+    /// EMVCo data of the QR, to render it instead of downloading the images.
+    pub qr_code: Option<String>,
+}
+
+/// Images of a static QR, to print and place at the point of sale.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaticQrImages {
+    /// URL of the QR code image.
+    pub image: String,
+    /// URL of a PDF with the QR and the Mercado Pago instructions.
+    pub template_document: Option<String>,
+    /// URL of an image with the QR and the Mercado Pago instructions.
+    pub template_image: Option<String>,
+}
+
+/// # StaticQrSearchResponse
+/// Response from `/pos`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaticQrSearchResponse {
+    pub results: Vec<StaticQr>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{QrOrderCreateOptions, QrOrderItem, QrOrderResponse, StaticQrSearchResponse};
+
+    #[test]
+    fn order_body() {
+        let options = QrOrderCreateOptions::new(
+            "Pedido 4711",
+            vec![
+                QrOrderItem::new("Café", Decimal::new(850, 2), Decimal::new(2, 0)),
+                QrOrderItem::new("Pão de queijo", Decimal::new(600, 2), Decimal::ONE),
+            ],
+        )
+        .with_external_reference("order-4711")
+        .with_cash_out(Decimal::new(20, 0));
+
+        assert_eq!(options.total_amount, Decimal::new(23, 0));
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "title": "Pedido 4711",
+                "external_reference": "order-4711",
+                "total_amount": 23.0,
+                "items": [
+                    {"title": "Café", "unit_price": 8.5, "quantity": 2.0, "unit_measure": "unit", "total_amount": 17.0},
+                    {"title": "Pão de queijo", "unit_price": 6.0, "quantity": 1.0, "unit_measure": "unit", "total_amount": 6.0}
+                ],
+                "cash_out": {"amount": 20.0}
+            })
+        );
+    }
+
+    #[test]
+    fn parse_response() {
+        let response = serde_json::from_str::<QrOrderResponse>(
+            r#"{"in_store_order_id": "d4e8ca59-3e1d-4c03-b1f6-580353b8ad1e", "qr_data": "00020101021243650016COM.MERCADOLIBRE0201306361fe0a4c5-6a5c-4e8c-a1a3-1f9d0cfb1e9b5204970053039865802BR5909Test Test6009SAO PAULO62070503***6304B6F2"}"#,
+        )
+        .unwrap();
+
+        assert!(response.qr_data.starts_with("000201"));
+    }
+
+    #[test]
+    fn parse_static_qr() {
+        let response = serde_json::from_str::<StaticQrSearchResponse>(
+            r#"{
+                "paging": {"total": 1, "offset": 0, "limit": 50},
+                "results": [{
+                    "id": 2711382,
+                    "name": "Caixa 1",
+                    "fixed_amount": false,
+                    "category": 621102,
+                    "store_id": "1234567",
+                    "external_store_id": "SUC001",
+                    "external_id": "SUC001POS001",
+                    "qr": {
+                        "image": "https://www.mercadopago.com/instore/merchant/qr/2711382/0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.png",
+                        "template_document": "https://www.mercadopago.com/instore/merchant/qr/2711382/template_0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.pdf",
+                        "template_image": "https://www.mercadopago.com/instore/merchant/qr/2711382/template_0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.png"
+                    },
+                    "qr_code": "00020101021126940014BR.GOV.BCB.PIX2572pix-qr.mercadopago.com/instore/o/v2/0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e5204000053039865802BR5909Test Test6009SAO PAULO62070503***6304A1B2",
+                    "date_created": "2024-05-02T13:18:05.000Z"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let qr = &response.results[0];
+        assert_eq!(qr.external_id.as_deref(), Some("SUC001POS001"));
+        assert!(qr.qr.image.ends_with(".png"));
+        assert!(qr.qr_code.as_deref().unwrap().starts_with("000201"));
+    }
+}
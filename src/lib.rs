@@ -1,9 +1,104 @@
+//! Rust SDK for Mercado Pago.
+//!
+//! # Features
+//!
+//! Payments, refunds, card tokens and payment methods are always available. Everything else is behind a feature, all of them enabled by default:
+//!
+//! * `advanced_payments` - [`advanced_payments`], splitting the money of a payment between marketplace sellers.
+//! * `tokio` - Tokio integrations, like [`MercadoPagoClient::download`](client::MercadoPagoClient::download) into a tokio `AsyncWrite`, the webhook pipeline and [`payments::wait_for_challenge`].
+//! * `oauth` - [`oauth`] access token creation and refresh.
+//! * `webhooks` - [`webhooks`] body types and signature verification.
+//! * `batch` - [`batch`] executor with shared rate limiting, and [`payments::bulk_refund`]. Enables `tokio`.
+//! * `retry` - [`retry`] with backoff and a retry budget shared across the process. Enables `tokio`.
+//! * `customers` - [`customers`] and their saved cards, for recurring card payments.
+//! * `preferences` - [`preferences`] for Checkout Pro.
+//! * `merchant_orders` - [`merchant_orders`] get and search, for reconciliation.
+//! * `subscriptions` - [`subscriptions`] and their [`plans`].
+//! * `wallet_connect` - [`wallet_connect`] payments with linked Mercado Pago wallets.
+//! * `point` - [`point`] terminals, for in-person card payments.
+//! * `instore` - [`instore`] orders paid with QR codes in physical stores.
+//! * `stores` - Physical [`stores`] of a seller, with location and business hours.
+//! * `pos` - Points of sale ([`pos`]) of the stores, with their static QR.
+//! * `orders` - [`orders`] of the Orders API (`/v1/orders`), the unified API recommended for new integrations.
+//! * `test_users` - [`test_users`], sandbox buyers and sellers for integration tests.
+//! * `users` - [`users`], the account of the access token.
+//!
+//! For a minimal payments-only build, like in serverless functions where binary size and cold start matter, disable the default features:
+//!
+//! ```toml
+//! mpago = { version = "0.1", default-features = false }
+//! ```
+//!
+//! Optional features:
+//!
+//! * `chrono` - Accept `chrono` dates and durations in the builders.
+//! * `qr` - [`qr`] images (PNG or SVG) rendered from the Pix code, instead of using the `qr_code_base64` of the response.
+//! * `reports` - [`reports`] parsing the downloaded CSVs of the release and settlement reports into typed records.
+//! * `simd-json` - Parse response bodies with `simd-json`.
+//! * `flows` - [`flows`] with reusable code of the documented integrations, like a Pix checkout confirmed by webhook. The `examples/` of the repository use it.
+//! * `fixtures` - [`fixtures`] with JSON payloads of API responses, to use in your tests, usually from `dev-dependencies`.
+//!
+//! # Runtimes
+//!
+//! The search streams implement [`futures_core::Stream`], so they can be consumed with the `StreamExt` of `futures`, `async-std` or `smol`. Without the `tokio` feature the crate does not use tokio APIs itself, but `reqwest` still drives its connections with tokio's reactor, so on other runtimes wrap the calls with a compatibility layer like `async-compat`.
 pub use rust_decimal::Decimal;
+#[cfg(feature = "advanced_payments")]
+pub mod advanced_payments;
+pub mod audit;
+#[cfg(feature = "batch")]
+pub mod batch;
+#[doc(hidden)]
+pub mod bench;
+pub mod card_tokens;
 pub mod client;
 pub mod common;
+#[cfg(feature = "customers")]
+pub mod customers;
+#[cfg(any(feature = "fixtures", test))]
+pub mod fixtures;
+#[cfg(feature = "flows")]
+pub mod flows;
+pub mod ids;
+#[cfg(feature = "instore")]
+pub mod instore;
+#[cfg(feature = "merchant_orders")]
+pub mod merchant_orders;
+#[cfg(feature = "oauth")]
 pub mod oauth;
+#[cfg(feature = "orders")]
+pub mod orders;
+mod pagination;
 pub mod payer;
+pub mod payment_methods;
 pub mod payments;
+#[cfg(feature = "subscriptions")]
+pub mod plans;
+#[cfg(feature = "point")]
+pub mod point;
+#[cfg(feature = "pos")]
+pub mod pos;
+#[cfg(feature = "preferences")]
+pub mod preferences;
+#[cfg(feature = "qr")]
+pub mod qr;
+pub mod refunds;
+#[cfg(feature = "reports")]
+pub mod reports;
+#[cfg(feature = "retry")]
+pub mod retry;
+#[cfg(feature = "stores")]
+pub mod stores;
+#[cfg(feature = "subscriptions")]
+pub mod subscriptions;
+#[cfg(test)]
+mod test_support;
+#[cfg(feature = "test_users")]
+pub mod test_users;
+#[cfg(feature = "users")]
+pub mod users;
+#[cfg(feature = "wallet_connect")]
+pub mod wallet_connect;
+#[cfg(feature = "webhooks")]
 pub mod webhooks;
 
 /// The base URL for Mercado Pago API
@@ -0,0 +1,6 @@
+pub use self::{get_builder::MerchantOrderGetBuilder, search_builder::MerchantOrderSearchBuilder};
+
+mod get_builder;
+mod reconcile;
+mod search_builder;
+pub mod types;
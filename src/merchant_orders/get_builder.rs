@@ -0,0 +1,60 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::MerchantOrderId,
+};
+
+use super::types::MerchantOrderResponse;
+
+/// Builder for get a merchant order using the ID
+///
+/// # Arguments
+///
+/// * `id` - Unique order identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::merchant_orders::MerchantOrderGetBuilder;
+///
+/// MerchantOrderGetBuilder(19846519025.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/merchant_orders/_merchant_orders_id/get>
+#[derive(Debug, Clone)]
+pub struct MerchantOrderGetBuilder(pub MerchantOrderId);
+
+impl MerchantOrderGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<MerchantOrderResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/merchant_orders/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<MerchantOrderResponse>(res).await
+    }
+}
+
+impl<T: Into<MerchantOrderId>> From<T> for MerchantOrderGetBuilder {
+    fn from(id: T) -> Self {
+        MerchantOrderGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for MerchantOrderGetBuilder {
+    type Output = MerchantOrderResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        MerchantOrderGetBuilder::send(self, mp_client)
+    }
+}
@@ -0,0 +1,117 @@
+use futures_util::future::try_join_all;
+
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    payments::{types::PaymentResponse, PaymentGetBuilder},
+};
+
+use super::{types::MerchantOrderResponse, MerchantOrderGetBuilder};
+
+impl MerchantOrderResponse {
+    /// Fetches every payment of the order, in the order of `payments`, with the fields the order doesn't carry, like fees and payer.
+    ///
+    /// # Example
+    /// ```
+    /// let order = MerchantOrderGetBuilder(19846519025.into()).send(&mp_client).await?;
+    ///
+    /// for payment in order.fetch_payments(&mp_client).await? {
+    ///     println!("{} {:?}", payment.id, payment.status);
+    /// }
+    /// ```
+    pub async fn fetch_payments(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Vec<PaymentResponse>, MercadoPagoRequestError> {
+        try_join_all(
+            self.payments
+                .iter()
+                .map(|payment| PaymentGetBuilder(payment.id).send(mp_client)),
+        )
+        .await
+    }
+}
+
+impl<M> PaymentResponse<M> {
+    /// Fetches the merchant order of the payment, `None` when [`merchant_order_id`](Self::merchant_order_id) is `None`.
+    ///
+    /// # Example
+    /// ```
+    /// let payment = PaymentGetBuilder(1319541963.into()).send(&mp_client).await?;
+    ///
+    /// if let Some(order) = payment.fetch_merchant_order(&mp_client).await? {
+    ///     println!("paid: {}", order.is_fully_paid());
+    /// }
+    /// ```
+    pub async fn fetch_merchant_order(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Option<MerchantOrderResponse>, MercadoPagoRequestError> {
+        match self.merchant_order_id() {
+            Some(id) => MerchantOrderGetBuilder(id).send(mp_client).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        fixtures::{MERCHANT_ORDER_PAID, PAYMENT_APPROVED_PIX, PAYMENT_PENDING_BOLETO},
+        payments::types::{PaymentResponse, PaymentStatus},
+        test_support::{serve, MockResponse},
+    };
+
+    async fn fixtures_client() -> crate::client::MercadoPagoClient {
+        let url = serve(|request| {
+            let body = if request
+                .head
+                .starts_with("get /merchant_orders/19846519025 ")
+            {
+                MERCHANT_ORDER_PAID
+            } else if request.head.starts_with("get /v1/payments/1319541963 ") {
+                PAYMENT_APPROVED_PIX
+            } else {
+                PAYMENT_PENDING_BOLETO
+            };
+            MockResponse::new("200 OK", "application/json", body.as_bytes())
+        })
+        .await;
+
+        MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn payment_to_order_and_back() {
+        let mp_client = fixtures_client().await;
+        let payment: PaymentResponse = serde_json::from_str(PAYMENT_APPROVED_PIX).unwrap();
+
+        let order = payment
+            .fetch_merchant_order(&mp_client)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(order.is_fully_paid());
+
+        let payments = order.fetch_payments(&mp_client).await.unwrap();
+        assert_eq!(payments.len(), 2);
+        assert_eq!(payments[1].id, payment.id);
+        assert_eq!(payments[1].status, PaymentStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn payment_without_order() {
+        let mp_client = fixtures_client().await;
+        let payment: PaymentResponse = serde_json::from_str(PAYMENT_PENDING_BOLETO).unwrap();
+
+        assert_eq!(payment.merchant_order_id(), None);
+        assert!(payment
+            .fetch_merchant_order(&mp_client)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,112 @@
+use futures_core::Stream;
+use std::{borrow::Borrow, pin::Pin};
+
+use super::types::{
+    MerchantOrderResponse, MerchantOrderSearchOptions, MerchantOrderSearchResponse,
+};
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    pagination::{collect_all, paginate, search_query, SearchPage, SearchStream},
+};
+
+/// Builder for search merchant orders
+///
+/// # Arguments
+///
+/// * `options` - Options to search for orders
+///
+/// # Example
+/// ```
+/// use mpago::merchant_orders::MerchantOrderSearchBuilder;
+///
+/// MerchantOrderSearchBuilder(
+///     MerchantOrderSearchOptions {
+///         external_reference: Some(ExternalReference::new("order-123")?),
+///         status: Some(MerchantOrderStatus::Opened),
+///         ..Default::default()
+///     }
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/merchant_orders/_merchant_orders_search/get>
+#[derive(Debug, Clone)]
+pub struct MerchantOrderSearchBuilder(pub MerchantOrderSearchOptions);
+
+impl MerchantOrderSearchBuilder {
+    /// This function creates a stream of orders, it goes through all the pages.
+    ///
+    /// Works like [`PaymentSearchBuilder::fetch_all_streamed`](crate::payments::PaymentSearchBuilder::fetch_all_streamed).
+    pub async fn fetch_all_streamed<'a>(
+        self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Pin<
+        Box<dyn Stream<Item = Result<MerchantOrderResponse, MercadoPagoRequestError>> + Send + 'a>,
+    > {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Same as [`fetch_all_streamed`](Self::fetch_all_streamed), but the stream owns the client.
+    pub async fn fetch_all_streamed_owned(
+        self,
+        mp_client: MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<MerchantOrderResponse, MercadoPagoRequestError>> + Send>>
+    {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Fetches every page and collects the orders into a [`Vec`].
+    ///
+    /// Stops at the first error, or once `max_items` orders were collected.
+    pub async fn collect_all(
+        self,
+        mp_client: &MercadoPagoClient,
+        max_items: Option<usize>,
+    ) -> Result<Vec<MerchantOrderResponse>, MercadoPagoRequestError> {
+        collect_all(stream_pages(self.0, mp_client), max_items).await
+    }
+}
+
+search_query!(MerchantOrderSearchOptions);
+
+impl SearchPage for MerchantOrderSearchResponse {
+    type Item = MerchantOrderResponse;
+
+    fn into_results(self) -> (Vec<Self::Item>, usize) {
+        (self.elements, self.total)
+    }
+}
+
+/// Streams every result matching `options`, going through all the pages.
+fn stream_pages<'a, C>(
+    options: MerchantOrderSearchOptions,
+    mp_client: C,
+) -> SearchStream<'a, MerchantOrderResponse>
+where
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    paginate::<MerchantOrderSearchResponse, _, _>("/merchant_orders/search", options, mp_client)
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::{MerchantOrderSearchBuilder, MerchantOrderSearchOptions};
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn search_orders() {
+        let mp_client = create_test_client();
+
+        let orders = MerchantOrderSearchBuilder(MerchantOrderSearchOptions {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .collect_all(&mp_client, Some(3))
+        .await
+        .unwrap();
+
+        assert!(orders.len() <= 3);
+    }
+}
@@ -0,0 +1,197 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    common::{ExternalReference, MerchantOrderLimits},
+    ids::{MerchantOrderId, PaymentId, PreferenceId, UserId},
+    payments::types::{CurrencyId, PaymentStatus, PaymentStatusDetail},
+};
+
+/// # MerchantOrderSearchOptions
+/// Struct to use in [`MerchantOrderSearchBuilder`](crate::merchant_orders::MerchantOrderSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/merchant_orders/_merchant_orders_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct MerchantOrderSearchOptions {
+    /// Quantity of orders returned.
+    pub limit: Option<usize>,
+    /// Quantity of orders to skip.
+    pub offset: Option<usize>,
+    pub status: Option<MerchantOrderStatus>,
+    /// Preference the orders were created from.
+    pub preference_id: Option<PreferenceId>,
+    /// External reference sent when the preference was created.
+    pub external_reference: Option<ExternalReference<MerchantOrderLimits>>,
+    pub payer_id: Option<u64>,
+    /// ID of the sponsor account, used by integrators.
+    pub sponsor_id: Option<u64>,
+    /// Start of the creation date interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created_from: Option<String>,
+    /// End of the creation date interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created_to: Option<String>,
+}
+
+/// # MerchantOrderSearchResponse
+/// Used in pagination of [`MerchantOrderSearchBuilder`](crate::merchant_orders::MerchantOrderSearchBuilder)
+///
+/// Response from `/merchant_orders/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerchantOrderSearchResponse {
+    pub elements: Vec<MerchantOrderResponse>,
+    /// Offset of the next page.
+    pub next_offset: usize,
+    /// Total number of orders matching the search.
+    pub total: usize,
+}
+
+/// # MerchantOrderResponse
+/// Order grouping the payments and shipments of a purchase.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/merchant_orders/_merchant_orders_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerchantOrderResponse {
+    pub id: MerchantOrderId,
+    pub status: MerchantOrderStatus,
+    /// Payment situation of the order.
+    pub order_status: Option<MerchantOrderPaymentStatus>,
+    pub preference_id: Option<PreferenceId>,
+    pub external_reference: Option<String>,
+    pub site_id: Option<String>,
+    pub collector: Option<MerchantOrderUser>,
+    pub payer: Option<MerchantOrderUser>,
+    pub items: Vec<MerchantOrderItem>,
+    pub payments: Vec<MerchantOrderPayment>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub paid_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub refunded_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub shipping_cost: Option<Decimal>,
+    pub cancelled: Option<bool>,
+    pub notification_url: Option<String>,
+    pub marketplace: Option<String>,
+    /// Order create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: String,
+    /// Date when order was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_updated: Option<String>,
+}
+
+/// Status of a merchant order
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantOrderStatus {
+    /// The order accepts new payments.
+    Opened,
+    /// The order was paid or cancelled.
+    Closed,
+    /// The order expired before being paid.
+    Expired,
+    /// For untracked order status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Payment situation of a merchant order
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantOrderPaymentStatus {
+    PaymentRequired,
+    PaymentInProcess,
+    PartiallyPaid,
+    Paid,
+    PartiallyReverted,
+    Reverted,
+    Expired,
+    /// For untracked payment situation
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Collector or payer of a merchant order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerchantOrderUser {
+    pub id: UserId,
+    pub email: Option<String>,
+    pub nickname: Option<String>,
+}
+
+/// Item of a merchant order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerchantOrderItem {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub quantity: u32,
+    pub currency_id: Option<CurrencyId>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unit_price: Decimal,
+}
+
+/// Payment made for a merchant order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerchantOrderPayment {
+    pub id: PaymentId,
+    pub status: PaymentStatus,
+    pub status_detail: Option<PaymentStatusDetail>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub transaction_amount: Decimal,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_paid_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub amount_refunded: Option<Decimal>,
+    /// Date when payment was approved. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_approved: Option<String>,
+    /// Payment create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+}
+
+impl MerchantOrderResponse {
+    /// Sum of the `transaction_amount` of the approved payments of the order.
+    ///
+    /// Rejected and pending attempts are left out, so it is the amount to reconcile against `total_amount`.
+    pub fn approved_amount(&self) -> Decimal {
+        self.payments
+            .iter()
+            .filter(|payment| payment.status == PaymentStatus::Approved)
+            .map(|payment| payment.transaction_amount)
+            .sum()
+    }
+
+    /// Whether the approved payments cover `total_amount`. `false` when the order has no `total_amount`.
+    pub fn is_fully_paid(&self) -> bool {
+        self.total_amount
+            .is_some_and(|total| self.approved_amount() >= total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{MerchantOrderResponse, MerchantOrderStatus};
+    use crate::fixtures::MERCHANT_ORDER_PAID;
+
+    #[test]
+    fn approved_amount_skips_rejected_attempts() {
+        let order: MerchantOrderResponse = serde_json::from_str(MERCHANT_ORDER_PAID).unwrap();
+
+        assert_eq!(order.status, MerchantOrderStatus::Closed);
+        assert_eq!(order.payments.len(), 2);
+        assert_eq!(order.approved_amount(), Decimal::new(100, 0));
+        assert!(order.is_fully_paid());
+    }
+
+    #[test]
+    fn partially_paid_order() {
+        let mut order: MerchantOrderResponse = serde_json::from_str(MERCHANT_ORDER_PAID).unwrap();
+        order.total_amount = Some(Decimal::new(15000, 2));
+        assert!(!order.is_fully_paid());
+
+        order.total_amount = None;
+        assert!(!order.is_fully_paid());
+    }
+}
@@ -1,9 +1,18 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use futures_util::{future::BoxFuture, lock::Mutex, FutureExt};
+use reqwest::Method;
+
 use crate::{
-    common::{resolve_json, MercadoPagoRequestError},
-    API_BASE_URL,
+    client::{AccessTokenProvider, MercadoPagoClient, MercadoPagoClientBuilder, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
 };
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "grant_type")]
 pub enum OAuthRequestBody {
@@ -31,7 +40,7 @@ pub enum OAuthRequestBody {
 }
 
 /// Response body from OAuth routes
-#[derive(serde::Deserialize, serde::Serialize, Debug)]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
 pub struct OAuthResponseBody {
     /// Security code that identifies the user, their privileges, and an application used in various public origin requests to access protected resources.
     pub access_token: String,
@@ -46,7 +55,7 @@ pub struct OAuthResponseBody {
     /// Scopes are used in the process of authorization and consent for APIs, allowing the application to specify what access it is requesting and for the user to grant access to specific resources.
     pub scope: String,
     /// Identification number (Mercado Pago ID) automatically generated when an account is created on Mercado Pago. It is a unique number that identifies the Mercado Pago seller and is the owner of the application.
-    pub user_id: u64,
+    pub user_id: UserId,
     /// Code for temporal grants used to obtain access tokens so that authorization and access to resources remain valid before the expiration of the access token's validity period.
     ///
     /// Only usable once.
@@ -56,6 +65,68 @@ pub struct OAuthResponseBody {
     /// Indicates whether the application is in production mode or test mode.
     pub live_mode: bool,
 }
+
+/// Builder for the OAuth token route, creating or refreshing an access token
+///
+/// The audit hook and the size limit of `mp_client` apply, like in the other builders. The access token of `mp_client` is not sent, the route is authenticated by `client_secret`.
+///
+/// # Example
+/// ```
+/// use mpago::oauth::{OAuthRequestBody, OAuthTokenBuilder};
+///
+/// let tokens = OAuthTokenBuilder(OAuthRequestBody::RefreshToken {
+///     client_secret: "RcHGkCg2VTL6cxrxzBSDQydT".to_string(),
+///     client_id: "8971239781".to_string(),
+///     refresh_token: "TG-78293722-241983636".to_string(),
+/// })
+/// .send(&mp_client)
+/// .await?;
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/oauth/_oauth_token/post>
+#[derive(Debug, Clone)]
+pub struct OAuthTokenBuilder(pub OAuthRequestBody);
+
+impl OAuthTokenBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<OAuthResponseBody, MercadoPagoRequestError> {
+        let res = mp_client
+            .json_body(
+                mp_client.start_request_without_token(Method::POST, "/oauth/token"),
+                &self.0,
+            )?
+            .send()
+            .await?;
+
+        mp_client.resolve_json::<OAuthResponseBody>(res).await
+    }
+}
+
+impl SendRequest for OAuthTokenBuilder {
+    type Output = OAuthResponseBody;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OAuthTokenBuilder::send(self, mp_client)
+    }
+}
+
+/// Client for the token route of the free functions, which don't receive one.
+fn token_client(base_url: Option<String>) -> MercadoPagoClient {
+    let builder = MercadoPagoClientBuilder::builder("");
+
+    match base_url {
+        Some(url) => builder.with_base_url(url),
+        None => builder,
+    }
+    .build()
+}
 /// Create an access token integrating an account to an application
 ///
 /// # Arguments
@@ -91,23 +162,14 @@ pub async fn create_access(
     redirect_uri: impl ToString,
     base_url: Option<String>,
 ) -> Result<OAuthResponseBody, MercadoPagoRequestError> {
-    let client_http = reqwest::Client::new();
-
-    let authorization_response = client_http
-        .post(format!(
-            "{}/oauth/token",
-            base_url.unwrap_or_else(|| API_BASE_URL.to_string())
-        ))
-        .json(&OAuthRequestBody::AuthorizationCode {
-            client_secret: client_secret.to_string(),
-            client_id: client_id.to_string(),
-            code: code.to_string(),
-            redirect_uri: redirect_uri.to_string(),
-        })
-        .send()
-        .await?;
-
-    resolve_json::<OAuthResponseBody>(authorization_response).await
+    OAuthTokenBuilder(OAuthRequestBody::AuthorizationCode {
+        client_secret: client_secret.to_string(),
+        client_id: client_id.to_string(),
+        code: code.to_string(),
+        redirect_uri: redirect_uri.to_string(),
+    })
+    .send(&token_client(base_url))
+    .await
 }
 
 /// Refresh an access token made by an integration
@@ -139,22 +201,297 @@ pub async fn refresh_access(
     refresh_token: impl ToString,
     base_url: Option<String>,
 ) -> Result<OAuthResponseBody, MercadoPagoRequestError> {
-    let client_http = reqwest::Client::new();
-
-    let authorization_response = client_http
-        .post(format!(
-            "{}/oauth/token",
-            base_url.unwrap_or_else(|| API_BASE_URL.to_string())
-        ))
-        .json(&OAuthRequestBody::RefreshToken {
-            client_secret: client_secret.to_string(),
+    OAuthTokenBuilder(OAuthRequestBody::RefreshToken {
+        client_secret: client_secret.to_string(),
+        client_id: client_id.to_string(),
+        refresh_token: refresh_token.to_string(),
+    })
+    .send(&token_client(base_url))
+    .await
+}
+
+/// [`AccessTokenProvider`] for the tokens of an OAuth integration, refreshing them with [`OAuthTokenBuilder`]
+///
+/// The token is refreshed a minute before `expires_in`, and when the API rejects it with `401 Unauthorized`. A refresh token is only usable once, so concurrent requests wait for the same refresh. Store the new refresh token with [`on_token_refreshed`](Self::on_token_refreshed), or the user has to link the account again after a restart.
+///
+/// # Example
+/// ```
+/// use mpago::{client::MercadoPagoClientBuilder, oauth::OAuthTokenProvider};
+///
+/// let client = MercadoPagoClientBuilder::builder_with_token_provider(
+///     OAuthTokenProvider::from_refresh_token(client_id, client_secret, stored_refresh_token),
+/// )
+/// .build();
+/// ```
+pub struct OAuthTokenProvider {
+    client_id: String,
+    client_secret: String,
+    client: MercadoPagoClient,
+    tokens: Mutex<Tokens>,
+    on_token_refreshed:
+        Option<Box<dyn Fn(OAuthResponseBody) -> BoxFuture<'static, ()> + Send + Sync>>,
+}
+
+struct Tokens {
+    access_token: Option<String>,
+    refresh_token: String,
+    refresh_at: Option<Instant>,
+}
+
+impl OAuthTokenProvider {
+    /// Time before `expires_in` when the token is refreshed.
+    const EXPIRATION_MARGIN: Duration = Duration::from_secs(60);
+
+    /// Provider starting with `tokens`, as returned by [`create_access`] or [`refresh_access`].
+    pub fn new(
+        client_id: impl ToString,
+        client_secret: impl ToString,
+        tokens: OAuthResponseBody,
+    ) -> Self {
+        Self::with_tokens(
+            client_id,
+            client_secret,
+            Tokens {
+                refresh_at: Some(Self::refresh_at(tokens.expires_in)),
+                access_token: Some(tokens.access_token),
+                refresh_token: tokens.refresh_token,
+            },
+        )
+    }
+
+    /// Provider starting with a stored refresh token, refreshed on the first request.
+    pub fn from_refresh_token(
+        client_id: impl ToString,
+        client_secret: impl ToString,
+        refresh_token: impl ToString,
+    ) -> Self {
+        Self::with_tokens(
+            client_id,
+            client_secret,
+            Tokens {
+                access_token: None,
+                refresh_token: refresh_token.to_string(),
+                refresh_at: None,
+            },
+        )
+    }
+
+    /// Refresh the tokens with a custom base url.
+    pub fn with_base_url(mut self, url: impl ToString) -> Self {
+        self.client = token_client(Some(url.to_string()));
+
+        self
+    }
+
+    /// Refresh the tokens through `mp_client`, so the refresh requests reach its audit hook.
+    ///
+    /// Only the configuration of `mp_client` is used, not its token. Don't pass the client built with this provider, they would keep each other alive.
+    pub fn with_client(mut self, mp_client: MercadoPagoClient) -> Self {
+        self.client = mp_client;
+
+        self
+    }
+
+    /// Call `callback` with the new tokens after every refresh, to persist the refresh token.
+    ///
+    /// Requests wait for `callback` before using the new token, so a crash after a request never loses the refresh token it was sent with.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::oauth::OAuthTokenProvider;
+    ///
+    /// let provider = OAuthTokenProvider::new(client_id, client_secret, tokens).on_token_refreshed(
+    ///     move |tokens| {
+    ///         let db = db.clone();
+    ///         async move {
+    ///             db.save_refresh_token(tokens.user_id, &tokens.refresh_token).await;
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn on_token_refreshed<F, Fut>(mut self, callback: F) -> Self
+    where
+        F: Fn(OAuthResponseBody) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_token_refreshed = Some(Box::new(move |tokens| callback(tokens).boxed()));
+
+        self
+    }
+
+    fn with_tokens(client_id: impl ToString, client_secret: impl ToString, tokens: Tokens) -> Self {
+        OAuthTokenProvider {
             client_id: client_id.to_string(),
-            refresh_token: refresh_token.to_string(),
+            client_secret: client_secret.to_string(),
+            client: token_client(None),
+            tokens: Mutex::new(tokens),
+            on_token_refreshed: None,
+        }
+    }
+
+    fn refresh_at(expires_in: u64) -> Instant {
+        Instant::now() + Duration::from_secs(expires_in).saturating_sub(Self::EXPIRATION_MARGIN)
+    }
+
+    async fn refresh_tokens(&self, tokens: &mut Tokens) -> Result<String, MercadoPagoRequestError> {
+        let refreshed = OAuthTokenBuilder(OAuthRequestBody::RefreshToken {
+            client_secret: self.client_secret.clone(),
+            client_id: self.client_id.clone(),
+            refresh_token: tokens.refresh_token.clone(),
         })
-        .send()
+        .send(&self.client)
         .await?;
 
-    resolve_json::<OAuthResponseBody>(authorization_response).await
+        let access_token = refreshed.access_token.clone();
+
+        tokens.refresh_at = Some(Self::refresh_at(refreshed.expires_in));
+        tokens.refresh_token = refreshed.refresh_token.clone();
+        tokens.access_token = Some(access_token.clone());
+
+        if let Some(callback) = &self.on_token_refreshed {
+            callback(refreshed).await;
+        }
+
+        Ok(access_token)
+    }
+}
+
+impl AccessTokenProvider for OAuthTokenProvider {
+    fn access_token(&self) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+        async move {
+            let mut tokens = self.tokens.lock().await;
+
+            match (&tokens.access_token, tokens.refresh_at) {
+                (Some(access_token), Some(refresh_at)) if Instant::now() < refresh_at => {
+                    Ok(access_token.clone())
+                }
+                _ => self.refresh_tokens(&mut tokens).await,
+            }
+        }
+        .boxed()
+    }
+
+    fn refresh(&self, rejected: &str) -> BoxFuture<'_, Result<String, MercadoPagoRequestError>> {
+        let rejected = rejected.to_string();
+
+        async move {
+            let mut tokens = self.tokens.lock().await;
+
+            match &tokens.access_token {
+                Some(access_token) if *access_token != rejected => Ok(access_token.clone()),
+                _ => self.refresh_tokens(&mut tokens).await,
+            }
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod provider_tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    use futures_util::future::join;
+
+    use super::OAuthTokenProvider;
+    use crate::{
+        audit::AuditEvent,
+        client::{AccessTokenProvider, MercadoPagoClientBuilder},
+        test_support::{serve, MockResponse},
+    };
+
+    /// Answers every refresh with `ACCESS-n` and `REFRESH-n`, counting them.
+    async fn serve_tokens(refreshes: Arc<AtomicUsize>) -> String {
+        serve(move |_| {
+            let n = refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+
+            MockResponse::json(
+                "200 OK",
+                serde_json::json!({
+                    "access_token": format!("ACCESS-{n}"),
+                    "token_type": "bearer",
+                    "expires_in": 15552000,
+                    "scope": "offline_access payments read write",
+                    "user_id": 241983636,
+                    "refresh_token": format!("REFRESH-{n}"),
+                    "public_key": "APP_USR-0123",
+                    "live_mode": true
+                }),
+            )
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn refreshes_once_for_concurrent_requests() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let url = serve_tokens(refreshes.clone()).await;
+        let provider = OAuthTokenProvider::from_refresh_token("8971239781", "secret", "REFRESH-0")
+            .with_base_url(url);
+
+        let (first, second) = join(provider.access_token(), provider.access_token()).await;
+        assert_eq!(first.unwrap(), "ACCESS-1");
+        assert_eq!(second.unwrap(), "ACCESS-1");
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+        // Already replaced, no refresh
+        assert_eq!(provider.refresh("ACCESS-0").await.unwrap(), "ACCESS-1");
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+        assert_eq!(provider.refresh("ACCESS-1").await.unwrap(), "ACCESS-2");
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn refresh_reaches_audit_hook_redacted() {
+        let url = serve_tokens(Arc::new(AtomicUsize::new(0))).await;
+        let audited = Arc::new(Mutex::new(Vec::new()));
+        let hook_audited = audited.clone();
+        let client = MercadoPagoClientBuilder::builder("")
+            .with_base_url(url)
+            .with_audit_hook(move |event: AuditEvent<'_>| {
+                if let AuditEvent::Request { url, body, .. } = event {
+                    hook_audited
+                        .lock()
+                        .unwrap()
+                        .push(format!("{url} {}", String::from_utf8_lossy(body)));
+                }
+            })
+            .build();
+
+        let provider = OAuthTokenProvider::from_refresh_token("8971239781", "secret", "REFRESH-0")
+            .with_client(client);
+        provider.access_token().await.unwrap();
+
+        let audited = audited.lock().unwrap();
+        assert_eq!(audited.len(), 1);
+        assert!(audited[0].ends_with(
+            r#"/oauth/token {"client_id":"8971239781","client_secret":"[REDACTED]","grant_type":"refresh_token","refresh_token":"[REDACTED]"}"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn persists_refreshed_tokens() {
+        let url = serve_tokens(Arc::new(AtomicUsize::new(0))).await;
+        let stored = Arc::new(Mutex::new(Vec::new()));
+
+        let provider = OAuthTokenProvider::from_refresh_token("8971239781", "secret", "REFRESH-0")
+            .with_base_url(url)
+            .on_token_refreshed({
+                let stored = stored.clone();
+                move |tokens| {
+                    stored.lock().unwrap().push(tokens.refresh_token);
+                    async {}
+                }
+            });
+
+        provider.access_token().await.unwrap();
+        provider.refresh("ACCESS-1").await.unwrap();
+
+        assert_eq!(*stored.lock().unwrap(), ["REFRESH-1", "REFRESH-2"]);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,14 @@
+pub use self::cancel_builder::OrderCancelBuilder;
+pub use self::capture_builder::OrderCaptureBuilder;
+pub use self::create_builder::OrderCreateBuilder;
+pub use self::get_builder::OrderGetBuilder;
+pub use self::process_builder::OrderProcessBuilder;
+pub use self::refund_builder::OrderRefundBuilder;
+
+mod cancel_builder;
+mod capture_builder;
+mod create_builder;
+mod get_builder;
+mod process_builder;
+mod refund_builder;
+pub mod types;
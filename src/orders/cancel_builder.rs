@@ -0,0 +1,88 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::OrderId,
+};
+
+use super::types::Order;
+
+/// Builder for cancelling an order
+///
+/// Only orders that are not paid or captured yet can be cancelled, see [`Order::is_cancellable`](super::types::Order::is_cancellable). Paid orders must be refunded.
+///
+/// # Arguments
+///
+/// * `id` - Id of the order.
+/// * `idempotency_key` - Required by the Orders API. A unique value per operation, so a retried request isn't applied twice.
+///
+/// # Example
+/// ```
+/// use mpago::orders::OrderCancelBuilder;
+///
+/// OrderCancelBuilder {
+///     id: "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3".into(),
+///     idempotency_key: "cancel-order-4711".to_string(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/cancel/post>
+#[derive(Debug, Clone)]
+pub struct OrderCancelBuilder {
+    pub id: OrderId,
+    pub idempotency_key: String,
+}
+
+impl OrderCancelBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Order, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::POST, format!("/v1/orders/{}/cancel", self.id))
+                    .header("X-Idempotency-Key", self.idempotency_key),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Order>(res).await
+    }
+}
+
+impl SendRequest for OrderCancelBuilder {
+    type Output = Order;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OrderCancelBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::OrderCancelBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_with_unknown_order() {
+        let mp_client = create_test_client();
+
+        let res = OrderCancelBuilder {
+            id: "ORD00000000000000000000000000".into(),
+            idempotency_key: format!("mpago-test-{}", std::process::id()),
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::OrderId,
+};
+
+use super::types::Order;
+
+/// Builder for capturing the authorized payments of an order
+///
+/// Only for orders created with the `manual` capture mode. Authorizations not captured in 7 days are cancelled.
+///
+/// # Arguments
+///
+/// * `id` - Id of the order.
+/// * `idempotency_key` - Required by the Orders API. A unique value per operation, so a retried request isn't applied twice.
+///
+/// # Example
+/// ```
+/// use mpago::orders::OrderCaptureBuilder;
+///
+/// OrderCaptureBuilder {
+///     id: "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3".into(),
+///     idempotency_key: "capture-order-4711".to_string(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/capture/post>
+#[derive(Debug, Clone)]
+pub struct OrderCaptureBuilder {
+    pub id: OrderId,
+    pub idempotency_key: String,
+}
+
+impl OrderCaptureBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Order, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::POST, format!("/v1/orders/{}/capture", self.id))
+                    .header("X-Idempotency-Key", self.idempotency_key),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Order>(res).await
+    }
+}
+
+impl SendRequest for OrderCaptureBuilder {
+    type Output = Order;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OrderCaptureBuilder::send(self, mp_client)
+    }
+}
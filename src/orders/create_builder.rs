@@ -0,0 +1,104 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{Order, OrderCreateOptions};
+
+/// Builder for creating an order of the Orders API, with its payments
+///
+/// With the `automatic` processing mode the payments are processed right away and the response has their result.
+///
+/// # Arguments
+///
+/// * `options` - Payer, items and payments of the order.
+/// * `idempotency_key` - Required by the Orders API. A unique value per order, so a retried request doesn't create a second one.
+///
+/// # Example
+/// ```
+/// use mpago::orders::{types::{OrderCreateOptions, OrderPayer, OrderPaymentRequest}, OrderCreateBuilder};
+///
+/// OrderCreateBuilder {
+///     options: OrderCreateOptions::online(
+///         ExternalReference::new("order-4711")?,
+///         OrderPayer::new("test_user@testuser.com"),
+///         vec![OrderPaymentRequest::card(card_token, PaymentMethodId::MasterCard, Decimal::new(200, 0), 1)],
+///     ),
+///     idempotency_key: "order-4711".to_string(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/create/post>
+#[derive(Debug, Clone)]
+pub struct OrderCreateBuilder {
+    pub options: OrderCreateOptions,
+    pub idempotency_key: String,
+}
+
+impl OrderCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Order, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .json_body(
+                        mp_client.start_request(Method::POST, "/v1/orders"),
+                        &self.options,
+                    )?
+                    .header("X-Idempotency-Key", self.idempotency_key),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Order>(res).await
+    }
+}
+
+impl SendRequest for OrderCreateBuilder {
+    type Output = Order;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OrderCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::OrderCreateBuilder;
+    use crate::{
+        common::{create_test_client, ExternalReference},
+        orders::types::{OrderCreateOptions, OrderPayer, OrderPaymentRequest},
+    };
+
+    #[tokio::test]
+    async fn create_pix_order() {
+        let mp_client = create_test_client();
+
+        let order = OrderCreateBuilder {
+            options: OrderCreateOptions::online(
+                ExternalReference::new("mpago-test").unwrap(),
+                OrderPayer::new("test_user_123@testuser.com"),
+                vec![OrderPaymentRequest::pix(Decimal::new(10, 0))],
+            ),
+            idempotency_key: format!("mpago-test-{}", std::process::id()),
+        }
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        assert_eq!(order.transactions.payments.len(), 1);
+    }
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::OrderId,
+};
+
+use super::types::Order;
+
+/// Builder for getting an order, with the current status of its transactions
+///
+/// # Arguments
+///
+/// * `id` - Id of the order.
+///
+/// # Example
+/// ```
+/// use mpago::orders::OrderGetBuilder;
+///
+/// OrderGetBuilder("ORD01JQ4S4KY8HWQ6NA5PXB65B3D3".into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/get-order/get>
+#[derive(Debug, Clone)]
+pub struct OrderGetBuilder(pub OrderId);
+
+impl OrderGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Order, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/v1/orders/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<Order>(res).await
+    }
+}
+
+impl<T: Into<OrderId>> From<T> for OrderGetBuilder {
+    fn from(id: T) -> Self {
+        OrderGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for OrderGetBuilder {
+    type Output = Order;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OrderGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::OrderGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_with_unknown_order() {
+        let mp_client = create_test_client();
+
+        let res = OrderGetBuilder("ORD00000000000000000000000000".into())
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::OrderId,
+};
+
+use super::types::{Order, OrderRefundOptions};
+
+/// Builder for refunding an order, fully or per transaction
+///
+/// # Arguments
+///
+/// * `id` - Id of the order.
+/// * `options` - Payments and amounts to refund. The default refunds every payment fully.
+/// * `idempotency_key` - Required by the Orders API. A unique value per refund, so a retried request doesn't refund twice.
+///
+/// # Example
+/// ```
+/// use mpago::orders::{types::{OrderRefundOptions, OrderRefundTransaction}, OrderRefundBuilder};
+///
+/// OrderRefundBuilder {
+///     id: "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3".into(),
+///     options: OrderRefundOptions {
+///         transactions: Some(vec![OrderRefundTransaction {
+///             id: "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3".into(),
+///             amount: Decimal::new(2550, 2), // 25.50
+///         }]),
+///     },
+///     idempotency_key: "refund-order-4711-1".to_string(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/refund/post>
+#[derive(Debug, Clone)]
+pub struct OrderRefundBuilder {
+    pub id: OrderId,
+    pub options: OrderRefundOptions,
+    pub idempotency_key: String,
+}
+
+impl OrderRefundBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Order, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .json_body(
+                        mp_client
+                            .start_request(Method::POST, format!("/v1/orders/{}/refund", self.id)),
+                        &self.options,
+                    )?
+                    .header("X-Idempotency-Key", self.idempotency_key),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Order>(res).await
+    }
+}
+
+impl SendRequest for OrderRefundBuilder {
+    type Output = Order;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        OrderRefundBuilder::send(self, mp_client)
+    }
+}
@@ -0,0 +1,534 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    common::ExternalReference,
+    ids::{CustomerId, OrderId, OrderTransactionId},
+    payer::PayerIdentification,
+    payments::types::{PaymentMethodId, PaymentTypeId},
+};
+
+/// # OrderCreateOptions
+/// Struct to use in [`OrderCreateBuilder`](crate::orders::OrderCreateBuilder)
+///
+/// Amounts of the Orders API are sent as strings, like `"200.00"`.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/create/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderCreateOptions {
+    pub r#type: OrderType,
+    pub processing_mode: ProcessingMode,
+    pub capture_mode: Option<CaptureMode>,
+    /// Sum of the amounts of the payments.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_amount: Decimal,
+    pub external_reference: ExternalReference,
+    pub description: Option<String>,
+    pub payer: OrderPayer,
+    pub transactions: OrderTransactionsRequest,
+    pub items: Option<Vec<OrderItem>>,
+}
+
+impl OrderCreateOptions {
+    /// Online order processed right away, with `total_amount` as the sum of the `payments`.
+    pub fn online(
+        external_reference: ExternalReference,
+        payer: OrderPayer,
+        payments: Vec<OrderPaymentRequest>,
+    ) -> Self {
+        OrderCreateOptions {
+            r#type: OrderType::Online,
+            processing_mode: ProcessingMode::Automatic,
+            capture_mode: None,
+            total_amount: payments.iter().map(|payment| payment.amount).sum(),
+            external_reference,
+            description: None,
+            payer,
+            transactions: OrderTransactionsRequest { payments },
+            items: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn with_description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets `capture_mode`. With [`CaptureMode::Manual`] card payments are only authorized, and must be captured later.
+    pub fn with_capture_mode(mut self, capture_mode: CaptureMode) -> Self {
+        self.capture_mode = Some(capture_mode);
+
+        self
+    }
+
+    /// Sets `items`.
+    pub fn with_items(mut self, items: Vec<OrderItem>) -> Self {
+        self.items = Some(items);
+
+        self
+    }
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// Payments of Checkout API, online.
+    Online,
+    /// For untracked order types
+    #[serde(other)]
+    Unknown(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingMode {
+    /// Payments are processed when the order is created.
+    Automatic,
+    /// Payments are processed when the order is processed.
+    Manual,
+    /// For untracked processing modes
+    #[serde(other)]
+    Unknown(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMode {
+    /// Payments are captured when authorized.
+    Automatic,
+    /// Payments are captured asynchronously after being authorized.
+    AutomaticAsync,
+    /// Payments are only authorized, and must be captured.
+    Manual,
+    /// For untracked capture modes
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Payer of an order.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderPayer {
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub identification: Option<PayerIdentification>,
+    /// Id of the customer, when paying with a saved card.
+    pub customer_id: Option<CustomerId>,
+}
+
+impl OrderPayer {
+    /// Payer identified only by `email`.
+    pub fn new(email: impl ToString) -> Self {
+        OrderPayer {
+            email: email.to_string(),
+            first_name: None,
+            last_name: None,
+            identification: None,
+            customer_id: None,
+        }
+    }
+}
+
+/// Transactions to create with the order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderTransactionsRequest {
+    pub payments: Vec<OrderPaymentRequest>,
+}
+
+/// Payment to create with the order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderPaymentRequest {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+    pub payment_method: OrderPaymentMethodRequest,
+}
+
+impl OrderPaymentRequest {
+    /// Credit card payment of `amount` with the card `token`.
+    pub fn card(
+        token: impl ToString,
+        payment_method_id: PaymentMethodId,
+        amount: Decimal,
+        installments: u32,
+    ) -> Self {
+        OrderPaymentRequest {
+            amount,
+            payment_method: OrderPaymentMethodRequest {
+                id: payment_method_id,
+                r#type: PaymentTypeId::CreditCard,
+                token: Some(token.to_string()),
+                installments: Some(installments),
+                statement_descriptor: None,
+            },
+        }
+    }
+
+    /// Pix payment of `amount`. The QR to pay it is in the payment method of the response.
+    pub fn pix(amount: Decimal) -> Self {
+        OrderPaymentRequest {
+            amount,
+            payment_method: OrderPaymentMethodRequest {
+                id: PaymentMethodId::Pix,
+                r#type: PaymentTypeId::BankTransfer,
+                token: None,
+                installments: None,
+                statement_descriptor: None,
+            },
+        }
+    }
+}
+
+/// Payment method of a payment to create.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderPaymentMethodRequest {
+    pub id: PaymentMethodId,
+    pub r#type: PaymentTypeId,
+    /// Card token, see [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder).
+    pub token: Option<String>,
+    pub installments: Option<u32>,
+    /// Text on the card statement of the payer.
+    pub statement_descriptor: Option<String>,
+}
+
+/// Item of an order.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderItem {
+    pub title: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub unit_price: Decimal,
+    pub quantity: u32,
+    pub description: Option<String>,
+    /// Code of the item in your system, like the SKU.
+    pub external_code: Option<String>,
+    pub category_id: Option<String>,
+    pub picture_url: Option<String>,
+}
+
+impl OrderItem {
+    pub fn new(title: impl ToString, unit_price: Decimal, quantity: u32) -> Self {
+        OrderItem {
+            title: title.to_string(),
+            unit_price,
+            quantity,
+            description: None,
+            external_code: None,
+            category_id: None,
+            picture_url: None,
+        }
+    }
+}
+
+/// # Order
+/// Response from `/v1/orders`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/create/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Order {
+    pub id: OrderId,
+    pub r#type: OrderType,
+    pub processing_mode: ProcessingMode,
+    pub capture_mode: Option<CaptureMode>,
+    pub external_reference: Option<String>,
+    pub description: Option<String>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_amount: Decimal,
+    pub status: OrderStatus,
+    pub status_detail: Option<String>,
+    /// Country of the account, like `"BRA"`.
+    pub country_code: Option<String>,
+    pub user_id: Option<String>,
+    /// Order create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub created_date: Option<String>,
+    /// Order last update date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_updated_date: Option<String>,
+    pub transactions: OrderTransactions,
+    #[serde(default)]
+    pub items: Vec<OrderItem>,
+}
+
+/// Status of an order
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// Created with the `manual` processing mode, waiting to be processed.
+    Created,
+    /// Payments are being processed.
+    Processing,
+    /// The payer must act, like paying a Pix or a boleto, or a payment was only authorized.
+    ActionRequired,
+    /// Every payment was approved.
+    Processed,
+    /// A payment was rejected.
+    Failed,
+    Canceled,
+    Refunded,
+    /// The payer didn't pay in time.
+    Expired,
+    /// For untracked statuses
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Status of a transaction of an order
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderTransactionStatus {
+    Created,
+    Processing,
+    /// Waiting for the payer, or authorized and waiting to be captured.
+    ActionRequired,
+    Processed,
+    Failed,
+    Canceled,
+    Refunded,
+    Expired,
+    /// For untracked statuses
+    #[serde(other)]
+    Unknown(String),
+}
+
+impl Order {
+    /// Whether the order can still be cancelled, before its payments are captured or paid.
+    pub fn is_cancellable(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatus::Created | OrderStatus::ActionRequired
+        )
+    }
+}
+
+/// Transactions of an order.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct OrderTransactions {
+    #[serde(default)]
+    pub payments: Vec<OrderPayment>,
+    #[serde(default)]
+    pub refunds: Vec<OrderRefund>,
+}
+
+/// Refund of a payment of an order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderRefund {
+    pub id: OrderTransactionId,
+    /// Id of the refunded payment.
+    pub transaction_id: OrderTransactionId,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+    pub status: OrderTransactionStatus,
+    /// Id of the refund in the payments API.
+    pub reference_id: Option<String>,
+}
+
+/// # OrderRefundOptions
+/// Struct to use in [`OrderRefundBuilder`](crate::orders::OrderRefundBuilder)
+///
+/// Without `transactions` every payment is fully refunded.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/orders/online-payments/refund/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct OrderRefundOptions {
+    pub transactions: Option<Vec<OrderRefundTransaction>>,
+}
+
+/// Partial refund of a payment of an order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderRefundTransaction {
+    /// Id of the payment.
+    pub id: OrderTransactionId,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+}
+
+/// Payment of an order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderPayment {
+    pub id: OrderTransactionId,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+    pub status: OrderTransactionStatus,
+    pub status_detail: Option<String>,
+    /// Id of the payment in the payments API.
+    pub reference_id: Option<String>,
+    pub payment_method: OrderPaymentMethod,
+}
+
+/// Payment method of a payment of an order.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderPaymentMethod {
+    pub id: PaymentMethodId,
+    pub r#type: PaymentTypeId,
+    pub installments: Option<u32>,
+    pub statement_descriptor: Option<String>,
+    /// Pix "copia e cola" code.
+    pub qr_code: Option<String>,
+    /// PNG of the Pix QR, in base64.
+    pub qr_code_base64: Option<String>,
+    /// Page with the instructions to pay, for Pix and boleto.
+    pub ticket_url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{
+        Order, OrderCreateOptions, OrderItem, OrderPayer, OrderPaymentRequest, OrderRefundOptions,
+        OrderRefundTransaction, OrderStatus, OrderTransactionStatus,
+    };
+    use crate::{common::ExternalReference, payments::types::PaymentMethodId};
+
+    #[test]
+    fn create_body() {
+        let options = OrderCreateOptions::online(
+            ExternalReference::new("order-4711").unwrap(),
+            OrderPayer::new("test_user@testuser.com"),
+            vec![
+                OrderPaymentRequest::card(
+                    "ff8080814c11e237014c1ff593b57b4d",
+                    PaymentMethodId::MasterCard,
+                    Decimal::new(15000, 2),
+                    3,
+                ),
+                OrderPaymentRequest::pix(Decimal::new(5000, 2)),
+            ],
+        )
+        .with_items(vec![OrderItem::new("Café", Decimal::new(10000, 2), 2)]);
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "type": "online",
+                "processing_mode": "automatic",
+                "total_amount": "200.00",
+                "external_reference": "order-4711",
+                "payer": {"email": "test_user@testuser.com"},
+                "transactions": {
+                    "payments": [
+                        {
+                            "amount": "150.00",
+                            "payment_method": {"id": "master", "type": "credit_card", "token": "ff8080814c11e237014c1ff593b57b4d", "installments": 3}
+                        },
+                        {
+                            "amount": "50.00",
+                            "payment_method": {"id": "pix", "type": "bank_transfer"}
+                        }
+                    ]
+                },
+                "items": [{"title": "Café", "unit_price": "100.00", "quantity": 2}]
+            })
+        );
+    }
+
+    #[test]
+    fn parse_order() {
+        let order = serde_json::from_str::<Order>(
+            r#"{
+                "id": "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                "type": "online",
+                "processing_mode": "automatic",
+                "external_reference": "order-4711",
+                "total_amount": "200.00",
+                "country_code": "BRA",
+                "user_id": "1245621468",
+                "status": "processed",
+                "status_detail": "accredited",
+                "capture_mode": "automatic",
+                "created_date": "2025-03-25T14:52:44.523Z",
+                "last_updated_date": "2025-03-25T14:52:46.033Z",
+                "integration_data": {"application_id": "4599991948843755"},
+                "transactions": {
+                    "payments": [{
+                        "id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                        "amount": "200.00",
+                        "reference_id": "0001hyqxn3",
+                        "status": "processed",
+                        "status_detail": "accredited",
+                        "payment_method": {"id": "master", "type": "credit_card", "token": "ff8080814c11e237014c1ff593b57b4d", "installments": 1}
+                    }]
+                },
+                "items": [{"title": "Café", "unit_price": "100.00", "quantity": 2}]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(order.id, "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3");
+        assert_eq!(order.status, OrderStatus::Processed);
+        assert!(!order.is_cancellable());
+        assert_eq!(
+            order.transactions.payments[0].status,
+            OrderTransactionStatus::Processed
+        );
+        assert_eq!(order.total_amount, Decimal::new(200, 0));
+        assert_eq!(
+            order.transactions.payments[0].payment_method.id,
+            PaymentMethodId::MasterCard
+        );
+    }
+
+    #[test]
+    fn refund_body() {
+        assert_eq!(
+            serde_json::to_value(OrderRefundOptions::default()).unwrap(),
+            serde_json::json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(OrderRefundOptions {
+                transactions: Some(vec![OrderRefundTransaction {
+                    id: "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3".into(),
+                    amount: Decimal::new(2550, 2),
+                }]),
+            })
+            .unwrap(),
+            serde_json::json!({"transactions": [{"id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3", "amount": "25.50"}]})
+        );
+    }
+
+    #[test]
+    fn parse_refunded_order() {
+        let order = serde_json::from_str::<Order>(
+            r#"{
+                "id": "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                "type": "online",
+                "processing_mode": "automatic",
+                "total_amount": "200.00",
+                "status": "refunded",
+                "status_detail": "refunded",
+                "transactions": {
+                    "payments": [{
+                        "id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                        "amount": "200.00",
+                        "status": "refunded",
+                        "payment_method": {"id": "pix", "type": "bank_transfer"}
+                    }],
+                    "refunds": [{
+                        "id": "REF01JQ4SBX0RV7TAEZCHWEWKWZPN",
+                        "transaction_id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                        "reference_id": "12345678",
+                        "amount": "200.00",
+                        "status": "processing"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(order.status, OrderStatus::Refunded);
+        assert_eq!(
+            order.transactions.refunds[0].status,
+            OrderTransactionStatus::Processing
+        );
+        assert_eq!(
+            serde_json::from_str::<OrderStatus>(r#""at_terminal""#).unwrap(),
+            OrderStatus::Unknown("at_terminal".to_string())
+        );
+    }
+}
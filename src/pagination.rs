@@ -0,0 +1,265 @@
+//! Offset pagination shared by the search builders.
+
+use std::{borrow::Borrow, pin::Pin};
+
+use async_stream::stream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{client::MercadoPagoClient, common::MercadoPagoRequestError};
+
+/// Page size used when the options have no `limit`.
+const DEFAULT_PAGE_LIMIT: usize = 30;
+
+/// Stream of the results of a search, going through all the pages.
+pub(crate) type SearchStream<'a, T> =
+    Pin<Box<dyn Stream<Item = Result<T, MercadoPagoRequestError>> + Send + 'a>>;
+
+/// Query of a search paginated with `offset` and `limit`.
+pub(crate) trait SearchQuery: Serialize + Clone + Send + Sync {
+    fn offset(&self) -> Option<usize>;
+
+    fn limit(&self) -> Option<usize>;
+
+    /// The same query, for the page at `offset`.
+    fn page(&self, offset: usize, limit: usize) -> Self;
+}
+
+/// Implements [`SearchQuery`] for options with `offset` and `limit` fields.
+macro_rules! search_query {
+    ($name:ty) => {
+        impl $crate::pagination::SearchQuery for $name {
+            fn offset(&self) -> Option<usize> {
+                self.offset
+            }
+
+            fn limit(&self) -> Option<usize> {
+                self.limit
+            }
+
+            fn page(&self, offset: usize, limit: usize) -> Self {
+                Self {
+                    offset: Some(offset),
+                    limit: Some(limit),
+                    ..self.clone()
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use search_query;
+
+/// Response of a page of a search.
+pub(crate) trait SearchPage: DeserializeOwned + Send {
+    type Item: Send;
+
+    /// Results of the page and the total of results of the search.
+    fn into_results(self) -> (Vec<Self::Item>, usize);
+}
+
+/// Streams every result of the search at `path`, fetching the pages as the stream is consumed.
+///
+/// The stream ends after yielding an error, so a failing request is never retried in a loop.
+pub(crate) fn paginate<'a, P, Q, C>(
+    path: &'static str,
+    query: Q,
+    mp_client: C,
+) -> SearchStream<'a, P::Item>
+where
+    P: SearchPage + 'a,
+    Q: SearchQuery + 'a,
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    Box::pin(stream! {
+        let limit = query.limit().unwrap_or(DEFAULT_PAGE_LIMIT);
+        let mut offset = query.offset().unwrap_or_default();
+        loop {
+            let request = mp_client
+                .borrow()
+                .start_request(Method::GET, path)
+                .query(&query.page(offset, limit));
+            let page = match mp_client.borrow().execute(request).await {
+                Ok(res) => mp_client.borrow().resolve_json::<P>(res).await,
+                Err(err) => Err(err),
+            };
+            let (results, total) = match page {
+                Ok(page) => page.into_results(),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            let is_empty = results.is_empty();
+            for result in results {
+                yield Ok(result)
+            }
+
+            offset += limit;
+            if is_empty || offset >= total {
+                return
+            }
+        }
+    })
+}
+
+/// Collects the results of `stream` into a [`Vec`], stopping at the first error or once `max_items` results were collected.
+///
+/// Pages after the one reaching `max_items` are never requested.
+pub(crate) async fn collect_all<T>(
+    mut stream: SearchStream<'_, T>,
+    max_items: Option<usize>,
+) -> Result<Vec<T>, MercadoPagoRequestError> {
+    let max_items = max_items.unwrap_or(usize::MAX);
+    let mut results = Vec::new();
+
+    while results.len() < max_items {
+        match stream.next().await {
+            Some(result) => results.push(result?),
+            None => break,
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    use futures_util::StreamExt;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::{collect_all, paginate, SearchPage};
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        common::MercadoPagoRequestError,
+        test_support::{serve, MockResponse},
+    };
+
+    #[derive(Serialize, Clone, Default)]
+    struct Query {
+        status: Option<String>,
+        offset: Option<usize>,
+        limit: Option<usize>,
+    }
+
+    search_query!(Query);
+
+    #[derive(Deserialize)]
+    struct Page {
+        results: Vec<u64>,
+        total: usize,
+    }
+
+    impl SearchPage for Page {
+        type Item = u64;
+
+        fn into_results(self) -> (Vec<u64>, usize) {
+            (self.results, self.total)
+        }
+    }
+
+    #[tokio::test]
+    async fn goes_through_all_pages() {
+        let url = serve(|request| {
+            let results = if request.head.contains("offset=0") {
+                json!([1, 2])
+            } else {
+                json!([3])
+            };
+            MockResponse::json("200 OK", json!({ "results": results, "total": 3 }))
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        let query = Query {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let results = paginate::<Page, _, _>("/search", query, &mp_client)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(results, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn ends_after_an_error() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let url = serve(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            MockResponse::json("400 Bad Request", json!({ "message": "invalid filter", "error": "bad_request", "status": 400, "cause": [] }))
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        let results = paginate::<Page, _, _>("/search", Query::default(), &mp_client)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(matches!(
+            results.as_slice(),
+            [Err(MercadoPagoRequestError::MercadoPago(_))]
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn collects_pages_up_to_max_items() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let seen = requests.clone();
+        let url = serve(move |request| {
+            let offset = ["offset=0", "offset=2", "offset=4"]
+                .iter()
+                .position(|offset| request.head.contains(offset))
+                .unwrap();
+            seen.lock().unwrap().push(offset);
+            let first = offset as u64 * 2 + 1;
+            MockResponse::json(
+                "200 OK",
+                json!({ "results": [first, first + 1], "total": 6 }),
+            )
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+        let query = Query {
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let all = collect_all(
+            paginate::<Page, _, _>("/search", query.clone(), &mp_client),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(all, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(*requests.lock().unwrap(), [0, 1, 2]);
+
+        requests.lock().unwrap().clear();
+        let first = collect_all(
+            paginate::<Page, _, _>("/search", query, &mp_client),
+            Some(3),
+        )
+        .await
+        .unwrap();
+        assert_eq!(first, [1, 2, 3]);
+        assert_eq!(*requests.lock().unwrap(), [0, 1]);
+    }
+}
@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-use crate::payments::types::{IdentificationType, PhoneNumber};
+use crate::payments::types::{IdentificationType, PhoneCountry, PhoneNumber, PhoneNumberError};
 
 /// Payer's information - ID (identification number), email, identification (type and document number).
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct Payer {
     /// Type of entity of the payer only for bank transfers.
     pub entity_type: Option<EntityType>,
@@ -23,7 +24,7 @@ pub struct Payer {
     pub last_name: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct AdditionalInfoPayer {
     pub first_name: Option<String>,
     pub last_name: Option<String>,
@@ -34,7 +35,20 @@ pub struct AdditionalInfoPayer {
     pub registration_date: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl AdditionalInfoPayer {
+    /// Sets `phone` from a number typed by the payer, see [`PhoneNumber::parse`].
+    pub fn with_phone(
+        mut self,
+        phone: &str,
+        country: PhoneCountry,
+    ) -> Result<Self, PhoneNumberError> {
+        self.phone = Some(PhoneNumber::parse(phone, country)?);
+
+        Ok(self)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PayerAddress {
     /// Payer's postal code (ZIP code).
     pub zip_code: String,
@@ -47,7 +61,7 @@ pub struct PayerAddress {
 /// Personal identification of the user.
 ///
 /// For example, in Brazil, we have CPF for individuals and CNPJ for companies. Other possible identification codes include CURP (Mexico) and CUIL (Argentina). This object will only return a response when `status` is `"approved"`, `"refunded"` or `"charged_back"`.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PayerIdentification {
     /// It refers to the type of identification. It can be one of the following types.
     pub r#type: Option<IdentificationType>,
@@ -55,7 +69,153 @@ pub struct PayerIdentification {
     pub number: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl PayerIdentification {
+    /// Validates a brazilian CPF, with or without punctuation, like `"123.456.789-09"`.
+    pub fn cpf(number: impl AsRef<str>) -> Result<Self, IdentificationError> {
+        let digits = digits(number.as_ref(), IdentificationType::CPF, 11)?;
+
+        if digits.iter().all(|d| *d == digits[0])
+            || mod11_check_digit(&digits[..9], &[10, 9, 8, 7, 6, 5, 4, 3, 2]) != digits[9]
+            || mod11_check_digit(&digits[..10], &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2]) != digits[10]
+        {
+            return Err(IdentificationError::InvalidCheckDigit(
+                IdentificationType::CPF,
+            ));
+        }
+
+        Ok(Self::from_digits(IdentificationType::CPF, &digits))
+    }
+
+    /// Validates a brazilian CNPJ, with or without punctuation, like `"11.222.333/0001-81"`.
+    pub fn cnpj(number: impl AsRef<str>) -> Result<Self, IdentificationError> {
+        let digits = digits(number.as_ref(), IdentificationType::CNPJ, 14)?;
+
+        if digits.iter().all(|d| *d == digits[0])
+            || mod11_check_digit(&digits[..12], &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]) != digits[12]
+            || mod11_check_digit(&digits[..13], &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2])
+                != digits[13]
+        {
+            return Err(IdentificationError::InvalidCheckDigit(
+                IdentificationType::CNPJ,
+            ));
+        }
+
+        Ok(Self::from_digits(IdentificationType::CNPJ, &digits))
+    }
+
+    /// Validates an argentinian DNI, with 7 or 8 digits and optional dots, like `"12.345.678"`.
+    ///
+    /// DNI has no check digit, only the length is validated.
+    pub fn dni(number: impl AsRef<str>) -> Result<Self, IdentificationError> {
+        let digits = match digits(number.as_ref(), IdentificationType::DNI, 8) {
+            Err(IdentificationError::InvalidLength { len: 7, .. }) => {
+                digits(number.as_ref(), IdentificationType::DNI, 7)?
+            }
+            digits => digits?,
+        };
+
+        Ok(Self::from_digits(IdentificationType::DNI, &digits))
+    }
+
+    /// Validates a mexican CURP, like `"GOMC800101HDFNRR09"`. Lowercase letters are accepted.
+    pub fn curp(number: impl AsRef<str>) -> Result<Self, IdentificationError> {
+        const ALPHABET: &str = "0123456789ABCDEFGHIJKLMNÑOPQRSTUVWXYZ";
+
+        let curp: Vec<char> = number.as_ref().trim().to_uppercase().chars().collect();
+        if curp.len() != 18 {
+            return Err(IdentificationError::InvalidLength {
+                kind: IdentificationType::CURP,
+                len: curp.len(),
+            });
+        }
+
+        let expected = |i: usize, c: char| match i {
+            0..=3 | 11..=15 => c.is_ascii_uppercase() || c == 'Ñ',
+            4..=9 | 17 => c.is_ascii_digit(),
+            10 => c == 'H' || c == 'M' || c == 'X',
+            _ => c.is_ascii_alphanumeric(),
+        };
+        if let Some((_, c)) = curp.iter().enumerate().find(|(i, c)| !expected(*i, **c)) {
+            return Err(IdentificationError::InvalidCharacter(*c));
+        }
+
+        let sum: u32 = curp[..17]
+            .iter()
+            .zip((2..=18).rev())
+            .map(|(c, weight)| {
+                ALPHABET.chars().position(|a| a == *c).unwrap_or_default() as u32 * weight
+            })
+            .sum();
+        if (10 - sum % 10) % 10 != curp[17].to_digit(10).unwrap_or_default() {
+            return Err(IdentificationError::InvalidCheckDigit(
+                IdentificationType::CURP,
+            ));
+        }
+
+        Ok(PayerIdentification {
+            r#type: Some(IdentificationType::CURP),
+            number: Some(curp.into_iter().collect()),
+        })
+    }
+
+    fn from_digits(r#type: IdentificationType, digits: &[u32]) -> Self {
+        PayerIdentification {
+            r#type: Some(r#type),
+            number: Some(digits.iter().map(|d| d.to_string()).collect()),
+        }
+    }
+}
+
+/// Digits of `number`, ignoring the usual punctuation (`.`, `-`, `/` and spaces).
+fn digits(
+    number: &str,
+    kind: IdentificationType,
+    len: usize,
+) -> Result<Vec<u32>, IdentificationError> {
+    let digits = number
+        .chars()
+        .filter(|c| !matches!(c, '.' | '-' | '/' | ' '))
+        .map(|c| {
+            c.to_digit(10)
+                .ok_or(IdentificationError::InvalidCharacter(c))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() != len {
+        return Err(IdentificationError::InvalidLength {
+            kind,
+            len: digits.len(),
+        });
+    }
+
+    Ok(digits)
+}
+
+/// Modulo 11 check digit used by CPF and CNPJ.
+fn mod11_check_digit(digits: &[u32], weights: &[u32]) -> u32 {
+    let sum: u32 = digits.iter().zip(weights).map(|(d, w)| d * w).sum();
+
+    match sum % 11 {
+        0 | 1 => 0,
+        rest => 11 - rest,
+    }
+}
+
+/// Error when validating a [`PayerIdentification`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum IdentificationError {
+    #[error("{kind} has an invalid length of {len}")]
+    InvalidLength {
+        kind: IdentificationType,
+        len: usize,
+    },
+    #[error("Identification has an invalid character: {0:?}")]
+    InvalidCharacter(char),
+    #[error("{0} check digit does not match")]
+    InvalidCheckDigit(IdentificationType),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum PayerType {
     /// Payer is a Customer and belongs to the collector.
@@ -64,9 +224,72 @@ pub enum PayerType {
     Guest,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum EntityType {
     Individual,
     Association,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{IdentificationError, PayerIdentification};
+    use crate::payments::types::IdentificationType;
+
+    #[test]
+    fn cpf() {
+        let cpf = PayerIdentification::cpf("529.982.247-25").unwrap();
+        assert_eq!(cpf.r#type, Some(IdentificationType::CPF));
+        assert_eq!(cpf.number.as_deref(), Some("52998224725"));
+
+        assert_eq!(
+            PayerIdentification::cpf("529.982.247-26").unwrap_err(),
+            IdentificationError::InvalidCheckDigit(IdentificationType::CPF)
+        );
+        assert!(PayerIdentification::cpf("111.111.111-11").is_err());
+        assert!(matches!(
+            PayerIdentification::cpf("5299822472"),
+            Err(IdentificationError::InvalidLength { len: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn cnpj() {
+        let cnpj = PayerIdentification::cnpj("11.222.333/0001-81").unwrap();
+        assert_eq!(cnpj.number.as_deref(), Some("11222333000181"));
+
+        assert!(PayerIdentification::cnpj("11.222.333/0001-82").is_err());
+        assert_eq!(
+            PayerIdentification::cnpj("11.222.333/0001-8a").unwrap_err(),
+            IdentificationError::InvalidCharacter('a')
+        );
+    }
+
+    #[test]
+    fn dni() {
+        assert_eq!(
+            PayerIdentification::dni("12.345.678")
+                .unwrap()
+                .number
+                .as_deref(),
+            Some("12345678")
+        );
+        assert!(PayerIdentification::dni("1234567").is_ok());
+        assert!(PayerIdentification::dni("123456").is_err());
+    }
+
+    #[test]
+    fn curp() {
+        let curp = PayerIdentification::curp("hegg560427mvzrrl04").unwrap();
+        assert_eq!(curp.number.as_deref(), Some("HEGG560427MVZRRL04"));
+
+        assert_eq!(
+            PayerIdentification::curp("HEGG560427MVZRRL05").unwrap_err(),
+            IdentificationError::InvalidCheckDigit(IdentificationType::CURP)
+        );
+        assert_eq!(
+            PayerIdentification::curp("HEGG5604270VZRRL04").unwrap_err(),
+            IdentificationError::InvalidCharacter('0')
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub use self::installments_builder::InstallmentsGetBuilder;
+pub use self::list_builder::PaymentMethodListBuilder;
+
+mod installments_builder;
+mod list_builder;
+pub mod types;
@@ -0,0 +1,91 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{InstallmentsOptions, InstallmentsResponse};
+
+/// Builder for get the installment options of a payment
+///
+/// Lets the seller show financing costs before creating the payment.
+///
+/// # Arguments
+///
+/// * `options` - Amount and card information.
+///
+/// # Example
+/// ```
+/// use mpago::{payment_methods::InstallmentsGetBuilder, Decimal};
+///
+/// InstallmentsGetBuilder(InstallmentsOptions {
+///     amount: Decimal::new(100, 0),
+///     bin: Some("503143".to_string()),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/payment_methods/_payment_methods_installments/get>
+#[derive(Debug, Clone)]
+pub struct InstallmentsGetBuilder(pub InstallmentsOptions);
+
+impl InstallmentsGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Vec<InstallmentsResponse>, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::GET, "/v1/payment_methods/installments")
+                    .query(&self.0),
+            )
+            .await?;
+
+        mp_client
+            .resolve_json::<Vec<InstallmentsResponse>>(res)
+            .await
+    }
+}
+
+impl SendRequest for InstallmentsGetBuilder {
+    type Output = Vec<InstallmentsResponse>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        InstallmentsGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::{common::create_test_client, payment_methods::types::InstallmentsOptions};
+
+    use super::InstallmentsGetBuilder;
+
+    #[tokio::test]
+    async fn get_installments() {
+        let mp_client = create_test_client();
+
+        let installments = InstallmentsGetBuilder(InstallmentsOptions {
+            amount: Decimal::new(100, 0),
+            bin: Some("503143".to_string()),
+            ..Default::default()
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        println!("{installments:?}");
+    }
+}
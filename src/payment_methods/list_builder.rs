@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::PaymentMethod;
+
+/// Builder for list the payment methods available for the account
+///
+/// The catalog depends on the site (country) of the account and rarely changes, so it can be fetched once and cached.
+///
+/// # Example
+/// ```
+/// use mpago::payment_methods::PaymentMethodListBuilder;
+///
+/// let catalog = PaymentMethodListBuilder.send(&mp_client).await?;
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/payment_methods/_payment_methods/get>
+#[derive(Debug, Clone)]
+pub struct PaymentMethodListBuilder;
+
+impl PaymentMethodListBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Vec<PaymentMethod>, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, "/v1/payment_methods"))
+            .await?;
+
+        mp_client.resolve_json::<Vec<PaymentMethod>>(res).await
+    }
+}
+
+impl SendRequest for PaymentMethodListBuilder {
+    type Output = Vec<PaymentMethod>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PaymentMethodListBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PaymentMethodListBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn list_payment_methods() {
+        let mp_client = create_test_client();
+
+        let catalog = PaymentMethodListBuilder.send(&mp_client).await.unwrap();
+
+        assert!(!catalog.is_empty());
+    }
+}
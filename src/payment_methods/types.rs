@@ -0,0 +1,114 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::payments::types::{PaymentMethodId, PaymentProcessingMode, PaymentTypeId};
+
+/// # InstallmentsOptions
+/// Struct to use in [`InstallmentsGetBuilder`](crate::payment_methods::InstallmentsGetBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/payment_methods/_payment_methods_installments/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct InstallmentsOptions {
+    /// Amount of the payment.
+    pub amount: Decimal,
+    /// BIN (Bank Identification Number) of the card, its first 6 or 8 digits.
+    pub bin: Option<String>,
+    /// Indicates the identifier of the payment method.
+    pub payment_method_id: Option<PaymentMethodId>,
+    /// It is the type of payment method (credit card, debit card, ...).
+    pub payment_type_id: Option<PaymentTypeId>,
+    /// It is the identifier of the card issuer.
+    pub issuer_id: Option<String>,
+    pub processing_mode: Option<PaymentProcessingMode>,
+    /// Language of `recommended_message`, like `"pt-BR"`.
+    pub locale: Option<String>,
+}
+
+/// Installment options of a payment method, returned by `/v1/payment_methods/installments`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InstallmentsResponse {
+    pub payment_method_id: PaymentMethodId,
+    pub payment_type_id: PaymentTypeId,
+    /// Card issuer the options apply to.
+    pub issuer: Option<Issuer>,
+    pub processing_mode: Option<PaymentProcessingMode>,
+    pub merchant_account_id: Option<String>,
+    /// Available installments and their costs.
+    pub payer_costs: Vec<PayerCost>,
+}
+
+/// Card issuer
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Issuer {
+    #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_string_from_number")]
+    pub id: String,
+    pub name: Option<String>,
+    pub thumbnail: Option<String>,
+    pub secure_thumbnail: Option<String>,
+}
+
+/// Cost of paying in a number of installments
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PayerCost {
+    /// Number of installments.
+    pub installments: u32,
+    /// Interest rate of the installments, in percentage.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub installment_rate: Decimal,
+    /// Discount rate, in percentage.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub discount_rate: Option<Decimal>,
+    /// Amount of each installment.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub installment_amount: Decimal,
+    /// Total amount paid by the payer, including interest.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_amount: Decimal,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub min_allowed_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub max_allowed_amount: Option<Decimal>,
+    /// Message to show to the payer, like `"3 parcelas de R$ 34,33 (R$ 103,00)"`.
+    pub recommended_message: String,
+    /// Labels with extra information, like `"CFT_0,00%|TEA_0,00%"`.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Payment method of the catalog returned by `/v1/payment_methods`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/payment_methods/_payment_methods/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PaymentMethod {
+    pub id: PaymentMethodId,
+    pub name: String,
+    pub payment_type_id: PaymentTypeId,
+    pub status: PaymentMethodStatus,
+    /// Minimum amount of a payment with this method.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub min_allowed_amount: Decimal,
+    /// Maximum amount of a payment with this method.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub max_allowed_amount: Decimal,
+    /// Minutes until the payment is credited.
+    pub accreditation_time: Option<u64>,
+    pub thumbnail: Option<String>,
+    pub secure_thumbnail: Option<String>,
+    #[serde(default)]
+    pub processing_modes: Vec<PaymentProcessingMode>,
+}
+
+/// Availability of a payment method
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodStatus {
+    Active,
+    Deactive,
+    TemporallyDeactive,
+    /// For untracked payment method status
+    #[serde(other)]
+    Unknown(String),
+}
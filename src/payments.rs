@@ -1,10 +1,60 @@
+#[cfg(feature = "batch")]
+pub use self::bulk_refund::{bulk_refund, BulkRefundOutcome, BulkRefundReport};
+#[cfg(feature = "tokio")]
+pub use self::challenge::{wait_for_challenge, ChallengeError, ChallengePolling};
 pub use self::create_builder::PaymentCreateBuilder;
 pub use self::get_builder::PaymentGetBuilder;
 pub use self::search_builder::PaymentSearchBuilder;
 pub use self::update_builder::PaymentUpdateBuilder;
 
+#[cfg(feature = "batch")]
+mod bulk_refund;
+#[cfg(feature = "tokio")]
+mod challenge;
 mod create_builder;
 mod get_builder;
 mod search_builder;
 pub mod types;
 mod update_builder;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        types::{PaymentSearchOptions, PaymentUpdateOptions},
+        PaymentCreateBuilder, PaymentGetBuilder, PaymentSearchBuilder, PaymentUpdateBuilder,
+    };
+    use crate::{
+        client::{MercadoPagoClientBuilder, SendRequest},
+        common::get_test_payment_options,
+    };
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    fn assert_static<T: 'static>(_: &T) {}
+
+    #[test]
+    fn builders_futures_are_send() {
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+
+        let create = PaymentCreateBuilder(get_test_payment_options(), None);
+        assert_send(&create.clone().send(&mp_client));
+
+        assert_send(&PaymentGetBuilder(1.into()).send(&mp_client));
+        assert_send(&SendRequest::send(PaymentGetBuilder(1.into()), &mp_client));
+
+        let update = PaymentUpdateBuilder {
+            id: 1.into(),
+            options: PaymentUpdateOptions::default(),
+            idempotency_key: None,
+        };
+        assert_send(&update.clone().send(&mp_client));
+        assert_send(&update.cancel_payment(&mp_client));
+
+        let search = PaymentSearchBuilder(PaymentSearchOptions::default());
+        assert_send(&search.clone().fetch_all_streamed(&mp_client));
+
+        let owned = search.fetch_all_streamed_owned(mp_client.clone());
+        assert_send(&owned);
+        assert_static(&owned);
+    }
+}
@@ -0,0 +1,316 @@
+use std::{future::Future, sync::Mutex, time::Duration};
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use rust_decimal::Decimal;
+use tokio::time::{sleep, sleep_until, Instant};
+
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
+    refunds::{types::RefundResponse, RefundCreateBuilder},
+};
+
+/// Refunds every payment of `refunds`, running up to `concurrency` refunds at the same time.
+///
+/// Meant for incident remediation, like refunding everyone affected by a pricing bug. Each item is a payment and the amount to refund, `None` refunds the full amount.
+///
+/// * The stream is only polled when a refund finishes, so it can be a lazy query over millions of payments.
+/// * Refunds are spaced by at least 100ms. A `429 Too Many Requests` pauses every refund for 2 seconds.
+/// * Each refund has an idempotency key derived from the payment and the amount, so timeouts and `5xx` errors are retried up to 3 attempts, and running the job again after a crash doesn't refund twice. For the same reason, two items with the same payment and amount are a single refund.
+///
+/// The outcomes are in the order the refunds finished, not in the order of the stream.
+///
+/// Requires a tokio runtime with the time driver enabled.
+///
+/// # Example
+/// ```
+/// use futures_util::stream;
+/// use mpago::payments::bulk_refund;
+///
+/// let affected = stream::iter(vec![
+///     (87891224.into(), None),
+///     (87891225.into(), Some(Decimal::new(1050, 2))),
+/// ]);
+///
+/// let report = bulk_refund(&mp_client, affected, 4).await;
+///
+/// for outcome in report.failed() {
+///     eprintln!("{}: {:?}", outcome.payment_id, outcome.result);
+/// }
+/// ```
+pub async fn bulk_refund(
+    mp_client: &MercadoPagoClient,
+    refunds: impl Stream<Item = (PaymentId, Option<Decimal>)>,
+    concurrency: usize,
+) -> BulkRefundReport {
+    run(refunds, concurrency, &Limits::DEFAULT, |refund| {
+        refund.send(mp_client)
+    })
+    .await
+}
+
+/// Result of [`bulk_refund`], one outcome per item of the stream.
+#[derive(Debug, Default)]
+pub struct BulkRefundReport {
+    pub outcomes: Vec<BulkRefundOutcome>,
+}
+
+impl BulkRefundReport {
+    /// Outcomes of the refunds that were made.
+    pub fn succeeded(&self) -> impl Iterator<Item = &BulkRefundOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.result.is_ok())
+    }
+
+    /// Outcomes of the refunds that failed, to be checked by hand or sent again.
+    pub fn failed(&self) -> impl Iterator<Item = &BulkRefundOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.result.is_err())
+    }
+
+    /// Sum of the amounts refunded.
+    pub fn total_refunded(&self) -> Decimal {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| outcome.result.as_ref().ok())
+            .map(|refund| refund.amount)
+            .sum()
+    }
+}
+
+/// Outcome of the refund of one payment.
+#[derive(Debug)]
+pub struct BulkRefundOutcome {
+    pub payment_id: PaymentId,
+    /// Amount requested, `None` for the full amount.
+    pub amount: Option<Decimal>,
+    /// Idempotency key sent with every attempt.
+    pub idempotency_key: String,
+    /// Number of requests sent, retries included.
+    pub attempts: u32,
+    /// Refund made, or the error of the last attempt.
+    pub result: Result<RefundResponse, MercadoPagoRequestError>,
+}
+
+/// Pacing of [`bulk_refund`].
+struct Limits {
+    /// Minimum time between two requests.
+    min_interval: Duration,
+    /// Pause of every request after a `429 Too Many Requests`.
+    rate_limited_pause: Duration,
+    /// Wait before retrying the same refund, multiplied by the attempt.
+    retry_delay: Duration,
+    max_attempts: u32,
+}
+
+impl Limits {
+    const DEFAULT: Limits = Limits {
+        min_interval: Duration::from_millis(100),
+        rate_limited_pause: Duration::from_secs(2),
+        retry_delay: Duration::from_millis(500),
+        max_attempts: 3,
+    };
+}
+
+async fn run<S, F, Fut>(
+    refunds: S,
+    concurrency: usize,
+    limits: &Limits,
+    send: F,
+) -> BulkRefundReport
+where
+    S: Stream<Item = (PaymentId, Option<Decimal>)>,
+    F: Fn(RefundCreateBuilder) -> Fut,
+    Fut: Future<Output = Result<RefundResponse, MercadoPagoRequestError>>,
+{
+    let pacer = Pacer::new(limits.min_interval);
+
+    let outcomes = refunds
+        .map(|(payment_id, amount)| {
+            let (pacer, send) = (&pacer, &send);
+
+            async move {
+                let idempotency_key = idempotency_key(payment_id, amount);
+                let mut attempts = 0;
+
+                let result = loop {
+                    pacer.wait().await;
+                    attempts += 1;
+
+                    let result = send(RefundCreateBuilder {
+                        payment_id,
+                        amount,
+                        idempotency_key: Some(idempotency_key.clone()),
+                    })
+                    .await;
+
+                    match result {
+                        Err(err) if err.is_retryable() && attempts < limits.max_attempts => {
+                            if is_rate_limited(&err) {
+                                pacer.pause(limits.rate_limited_pause);
+                            } else {
+                                sleep(limits.retry_delay * attempts).await;
+                            }
+                        }
+                        result => break result,
+                    }
+                };
+
+                BulkRefundOutcome {
+                    payment_id,
+                    amount,
+                    idempotency_key,
+                    attempts,
+                    result,
+                }
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    BulkRefundReport { outcomes }
+}
+
+/// Hands out start times spaced by `interval`, shared by every refund in flight.
+struct Pacer {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl Pacer {
+    fn new(interval: Duration) -> Self {
+        Pacer {
+            interval,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    async fn wait(&self) {
+        let start = {
+            let mut next = self.next.lock().unwrap_or_else(|err| err.into_inner());
+            let start = (*next).max(Instant::now());
+            *next = start + self.interval;
+            start
+        };
+
+        sleep_until(start).await;
+    }
+
+    /// Delays every start for at least `pause` from now.
+    fn pause(&self, pause: Duration) {
+        let mut next = self.next.lock().unwrap_or_else(|err| err.into_inner());
+        *next = (*next).max(Instant::now() + pause);
+    }
+}
+
+fn is_rate_limited(err: &MercadoPagoRequestError) -> bool {
+    matches!(err, MercadoPagoRequestError::MercadoPago(err) if err.status == 429)
+}
+
+/// Same payment and amount, same key, so `10` and `10.00` are normalized first.
+fn idempotency_key(payment_id: PaymentId, amount: Option<Decimal>) -> String {
+    match amount {
+        Some(amount) => format!("bulk-refund-{}-{}", payment_id, amount.normalize()),
+        None => format!("bulk-refund-{}-full", payment_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::Mutex,
+        time::{Duration, Instant},
+    };
+
+    use futures_util::stream;
+    use rust_decimal::Decimal;
+
+    use super::{run, Limits};
+    use crate::{refunds::types::RefundResponse, test_support::api_error};
+
+    const LIMITS: Limits = Limits {
+        min_interval: Duration::from_millis(5),
+        rate_limited_pause: Duration::from_millis(50),
+        retry_delay: Duration::from_millis(5),
+        max_attempts: 3,
+    };
+
+    fn refund_response(payment_id: u64, amount: Decimal) -> RefundResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": payment_id * 10,
+            "payment_id": payment_id,
+            "amount": amount,
+            "date_created": "2024-05-02T13:10:26.000-04:00",
+            "status": "approved"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limits_and_reports_each_item() {
+        let sent = Mutex::new(HashMap::<u64, Vec<String>>::new());
+        let start = Instant::now();
+
+        let report = run(
+            stream::iter(vec![
+                (1.into(), None),
+                (2.into(), Some(Decimal::new(1000, 2))),
+                (3.into(), None),
+            ]),
+            2,
+            &LIMITS,
+            |refund| {
+                let payment_id = u64::from(refund.payment_id);
+                let attempt = {
+                    let mut sent = sent.lock().unwrap();
+                    let keys = sent.entry(payment_id).or_default();
+                    keys.push(refund.idempotency_key.unwrap());
+                    keys.len()
+                };
+
+                async move {
+                    match (payment_id, attempt) {
+                        (1, 1) => Err(api_error(429)),
+                        (3, _) => Err(api_error(400)),
+                        _ => Ok(refund_response(payment_id, Decimal::new(10, 0))),
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert!(start.elapsed() >= LIMITS.rate_limited_pause);
+        assert_eq!(report.outcomes.len(), 3);
+        assert_eq!(report.succeeded().count(), 2);
+        assert_eq!(report.total_refunded(), Decimal::new(20, 0));
+
+        let failed = report.failed().collect::<Vec<_>>();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].payment_id, 3);
+        assert_eq!(failed[0].attempts, 1);
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[&1], ["bulk-refund-1-full", "bulk-refund-1-full"]);
+        assert_eq!(sent[&2], ["bulk-refund-2-10"]);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let report = run(
+            stream::iter(vec![(1.into(), None)]),
+            1,
+            &LIMITS,
+            |_| async { Err(api_error(503)) },
+        )
+        .await;
+
+        assert_eq!(report.outcomes[0].attempts, LIMITS.max_attempts);
+        assert!(report.outcomes[0].result.is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use std::{future::Future, time::Duration};
+
+use thiserror::Error;
+use tokio::time::{sleep, Instant};
+
+use crate::{client::MercadoPagoClient, common::MercadoPagoRequestError, ids::PaymentId};
+
+use super::{
+    types::{PaymentResponse, PaymentStatusDetail},
+    PaymentGetBuilder,
+};
+
+/// Waits for the outcome of a 3DS challenge, fetching the payment until `status_detail` leaves `pending_challenge`.
+///
+/// Call it after the buyer completes the challenge of [`PaymentResponse::three_ds_info`]. Retryable errors, like timeouts, are ignored until `polling.timeout`, which fails with [`ChallengeError::Timeout`].
+///
+/// # Example
+/// ```
+/// use mpago::payments::{wait_for_challenge, ChallengePolling};
+///
+/// let payment = wait_for_challenge(&mp_client, payment.id, &ChallengePolling::default()).await?;
+///
+/// if payment.status == PaymentStatus::Approved {
+///     // ...
+/// }
+/// ```
+pub async fn wait_for_challenge(
+    mp_client: &MercadoPagoClient,
+    payment_id: PaymentId,
+    polling: &ChallengePolling,
+) -> Result<PaymentResponse, ChallengeError> {
+    poll(polling, || PaymentGetBuilder(payment_id).send(mp_client)).await
+}
+
+/// Polling of [`wait_for_challenge`]
+#[derive(Debug, Clone)]
+pub struct ChallengePolling {
+    /// Time between two requests.
+    pub interval: Duration,
+    /// Time to wait for the challenge to be resolved.
+    pub timeout: Duration,
+}
+
+impl Default for ChallengePolling {
+    /// Every 2 seconds, for up to 5 minutes.
+    fn default() -> Self {
+        ChallengePolling {
+            interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+/// Error when waiting for a 3DS challenge with [`wait_for_challenge`]
+#[derive(Error, Debug)]
+pub enum ChallengeError {
+    /// The payment was still waiting for the challenge after [`ChallengePolling::timeout`].
+    #[error("Payment still pending challenge after {0:?}")]
+    Timeout(Duration),
+    #[error("{0}")]
+    Request(#[from] MercadoPagoRequestError),
+}
+
+async fn poll<F, Fut>(
+    polling: &ChallengePolling,
+    get_payment: F,
+) -> Result<PaymentResponse, ChallengeError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<PaymentResponse, MercadoPagoRequestError>>,
+{
+    let deadline = Instant::now() + polling.timeout;
+
+    loop {
+        match get_payment().await {
+            Ok(payment) if payment.status_detail != Some(PaymentStatusDetail::PendingChallenge) => {
+                return Ok(payment);
+            }
+            Err(err) if !err.is_retryable() => return Err(err.into()),
+            _ => {}
+        }
+
+        if Instant::now() + polling.interval > deadline {
+            return Err(ChallengeError::Timeout(polling.timeout));
+        }
+
+        sleep(polling.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use super::{poll, ChallengeError, ChallengePolling};
+    use crate::{
+        common::MercadoPagoRequestError,
+        fixtures,
+        payments::types::{PaymentResponse, PaymentStatusDetail},
+        test_support::api_error,
+    };
+
+    const POLLING: ChallengePolling = ChallengePolling {
+        interval: Duration::from_millis(5),
+        timeout: Duration::from_millis(50),
+    };
+
+    fn payment(status_detail: &str) -> PaymentResponse {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        body["status_detail"] = status_detail.into();
+
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn returns_payment_after_challenge() {
+        let requests = AtomicUsize::new(0);
+
+        let payment = poll(&POLLING, || {
+            let request = requests.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match request {
+                    0 => Ok(payment("pending_challenge")),
+                    1 => Err(api_error(503)),
+                    _ => Ok(payment("accredited")),
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(payment.status_detail, Some(PaymentStatusDetail::Accredited));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn fails_on_timeout_or_error() {
+        let res = poll(&POLLING, || async { Ok(payment("pending_challenge")) }).await;
+        assert!(matches!(res, Err(ChallengeError::Timeout(_))));
+
+        let res = poll(&POLLING, || async { Err(api_error(404)) }).await;
+        assert!(matches!(
+            res,
+            Err(ChallengeError::Request(
+                MercadoPagoRequestError::MercadoPago(_)
+            ))
+        ));
+    }
+}
@@ -1,14 +1,28 @@
+use std::{
+    future::Future,
+    time::{Duration, SystemTime},
+};
+
 use reqwest::Method;
 use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    client::MercadoPagoClient,
-    common::{resolve_json, MercadoPagoRequestError},
-    payer::Payer,
+    card_tokens::CardTokenCreateBuilder,
+    client::{MercadoPagoClient, SendRequest},
+    common::{format_system_time, trim_field, MercadoPagoRequestError, TrimWarning},
+    ids::{CardId, CustomerId},
+    payer::{Payer, PayerIdentification, PayerType},
+    payment_methods::{
+        types::{InstallmentsOptions, InstallmentsResponse, PaymentMethod, PaymentMethodStatus},
+        InstallmentsGetBuilder,
+    },
     payments::types::PaymentResponse,
 };
 
-use super::types::{AdditionalInfo, PaymentCreateOptions, PaymentMethodId, ProductItem};
+use super::types::{
+    AdditionalInfo, PaymentCreateOptions, PaymentMethodId, PaymentValidationError, ProductItem,
+};
 
 /// Builder for creating a payment
 ///
@@ -39,9 +53,13 @@ use super::types::{AdditionalInfo, PaymentCreateOptions, PaymentMethodId, Produc
 ///
 /// # Docs
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments/post>
-pub struct PaymentCreateBuilder(pub PaymentCreateOptions, pub Option<String>);
+#[derive(Debug, Clone)]
+pub struct PaymentCreateBuilder<M = serde_json::Value>(
+    pub PaymentCreateOptions<M>,
+    pub Option<String>,
+);
 
-impl PaymentCreateBuilder {
+impl<M> PaymentCreateBuilder<M> {
     /// Sets the items for `additional_info.items`
     ///
     /// # Arguments
@@ -134,24 +152,210 @@ impl PaymentCreateBuilder {
         self
     }
 
+    /// Pay with a card saved for a customer.
+    ///
+    /// Creates a card token from the saved card and its security code, sets it as the payment `token` and sets the payer as the customer (`payer.type = customer` and `payer.id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `mp_client` - Client used to create the card token.
+    /// * `customer_id` - Identifier of the customer who owns the card.
+    /// * `card_id` - Identifier of the saved card.
+    /// * `security_code` - Security code (CVV) of the card, typed by the payer.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{Decimal, payments::PaymentCreateBuilder};
+    ///
+    /// PaymentCreateBuilder(
+    ///     PaymentCreateOptions {
+    ///         transaction_amount: Decimal::new(25, 0),
+    ///         installments: 1,
+    ///         payment_method_id: PaymentMethodId::Visa,
+    ///         payer: Payer {
+    ///             email: "test_user@testmail.com".to_string(),
+    ///             ..Default::default()
+    ///         },
+    ///         ..Default::default()
+    ///     },
+    ///     None,
+    /// )
+    /// .with_saved_card(&mp_client, "123456789-jxOV430go9fx2e", "9176235123", "123")
+    /// .await?
+    /// .send(&mp_client)
+    /// .await?;
+    /// ```
+    ///
+    /// # Docs
+    /// <https://www.mercadopago.com.br/developers/pt/docs/checkout-api/customer-management>
+    pub async fn with_saved_card(
+        mut self,
+        mp_client: &MercadoPagoClient,
+        customer_id: impl Into<CustomerId>,
+        card_id: impl Into<CardId>,
+        security_code: impl ToString,
+    ) -> Result<Self, MercadoPagoRequestError> {
+        let card_token = CardTokenCreateBuilder::from_saved_card(card_id, security_code)
+            .send(mp_client)
+            .await?;
+
+        self.0.token = Some(card_token.id);
+        self.0.payer.r#type = Some(PayerType::Customer);
+        self.0.payer.id = Some(customer_id.into().0);
+
+        Ok(self)
+    }
+
+    /// Sets `date_of_expiration`
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{Duration, Utc};
+    ///
+    /// PaymentCreateBuilder::pix(Decimal::new(20, 0), payer)?
+    ///     .expires_at(Utc::now() + Duration::hours(2));
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn expires_at<Tz>(mut self, date: chrono::DateTime<Tz>) -> Self
+    where
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        self.0.date_of_expiration = Some(crate::common::format_date(&date));
+
+        self
+    }
+
+    /// Sets `date_of_expiration` to `duration` from now
+    ///
+    /// Fails with [`PaymentValidationError::ExpirationOutOfRange`] when the date would overflow.
+    ///
+    /// # Example
+    /// ```
+    /// PaymentCreateBuilder::pix(Decimal::new(20, 0), payer)?
+    ///     .expires_in(chrono::Duration::minutes(30))?;
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn expires_in(self, duration: chrono::Duration) -> Result<Self, PaymentValidationError> {
+        let date = chrono::Utc::now()
+            .checked_add_signed(duration)
+            .ok_or(PaymentValidationError::ExpirationOutOfRange)?;
+
+        Ok(self.expires_at(date))
+    }
+
+    /// Sets `date_of_expiration` to `duration` from now, without the `chrono` feature
+    ///
+    /// Pix payments expire in 24 hours by default, the allowed range is from 30 minutes to 30 days. Fails with [`PaymentValidationError::ExpirationOutOfRange`] when the date would overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// PaymentCreateBuilder::pix(Decimal::new(20, 0), payer)?
+    ///     .expires_after(Duration::from_secs(30 * 60))?;
+    /// ```
+    pub fn expires_after(mut self, duration: Duration) -> Result<Self, PaymentValidationError> {
+        let date = SystemTime::now()
+            .checked_add(duration)
+            .ok_or(PaymentValidationError::ExpirationOutOfRange)?;
+        self.0.date_of_expiration = Some(format_system_time(date));
+
+        Ok(self)
+    }
+
+    /// Shortens the free-text fields that are over the API limits, instead of having the payment rejected
+    ///
+    /// Trims `description`, `statement_descriptor` and the `title` and `description` of the items. Returns a [`TrimWarning`] for every trimmed field, so you can log them.
+    ///
+    /// # Example
+    /// ```
+    /// let (builder, warnings) = PaymentCreateBuilder::pix(amount, payer)?
+    ///     .set_items(catalog_items)
+    ///     .trim_free_text();
+    ///
+    /// for warning in warnings {
+    ///     log::warn!("{} trimmed from {} characters", warning.field, warning.original_len);
+    /// }
+    /// ```
+    pub fn trim_free_text(mut self) -> (Self, Vec<TrimWarning>) {
+        let mut warnings = Vec::new();
+        let options = &mut self.0;
+
+        if let Some(description) = &mut options.description {
+            trim_field(
+                &mut warnings,
+                || "description".to_string(),
+                description,
+                PaymentCreateBuilder::DESCRIPTION_MAX_LEN,
+            );
+        }
+        if let Some(statement_descriptor) = &mut options.statement_descriptor {
+            trim_field(
+                &mut warnings,
+                || "statement_descriptor".to_string(),
+                statement_descriptor,
+                PaymentCreateBuilder::STATEMENT_DESCRIPTOR_MAX_LEN,
+            );
+        }
+        for (i, item) in options.additional_info.items.iter_mut().enumerate() {
+            if let Some(title) = &mut item.title {
+                trim_field(
+                    &mut warnings,
+                    || format!("additional_info.items[{i}].title"),
+                    title,
+                    PaymentCreateBuilder::ITEM_TEXT_MAX_LEN,
+                );
+            }
+            if let Some(description) = &mut item.description {
+                trim_field(
+                    &mut warnings,
+                    || format!("additional_info.items[{i}].description"),
+                    description,
+                    PaymentCreateBuilder::ITEM_TEXT_MAX_LEN,
+                );
+            }
+        }
+
+        (self, warnings)
+    }
+
     /// Send the request
     pub async fn send(
         self,
         mp_client: &MercadoPagoClient,
-    ) -> Result<PaymentResponse, MercadoPagoRequestError> {
-        let mut req = mp_client
-            .start_request(Method::POST, "/v1/payments")
-            .json(&self.0);
+    ) -> Result<PaymentResponse<M>, MercadoPagoRequestError>
+    where
+        M: Serialize + DeserializeOwned,
+    {
+        let mut req = mp_client.json_body(
+            mp_client.start_request(Method::POST, "/v1/payments"),
+            &self.0,
+        )?;
 
         if let Some(idempotency_key) = self.1 {
             req = req.header("X-Idempotency-Key", idempotency_key);
         }
 
-        let res = req.send().await?;
+        let res = mp_client.execute(req).await?;
 
-        resolve_json::<PaymentResponse>(res).await
+        mp_client.resolve_json::<PaymentResponse<M>>(res).await
     }
 
+    /// Sets `metadata`, typed as `T`, see [`PaymentCreateOptions::with_metadata`]
+    pub fn with_metadata<T>(self, metadata: T) -> PaymentCreateBuilder<T> {
+        PaymentCreateBuilder(self.0.with_metadata(metadata), self.1)
+    }
+}
+
+impl PaymentCreateBuilder {
+    /// Maximum length of `description`, in characters.
+    pub const DESCRIPTION_MAX_LEN: usize = 600;
+    /// Maximum length of `statement_descriptor`, in characters, as printed in card statements.
+    pub const STATEMENT_DESCRIPTOR_MAX_LEN: usize = 22;
+    /// Maximum length of the item `title` and `description`, in characters.
+    pub const ITEM_TEXT_MAX_LEN: usize = 256;
+
     /// Returns a [`PaymentCreateBuilder`]
     ///
     /// # Arguments
@@ -204,6 +408,657 @@ impl PaymentCreateBuilder {
             idempotency_key,
         )
     }
+
+    /// Returns a [`PaymentCreateBuilder`] for a Pix payment
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_amount` - Amount of the payment, must be greater than zero.
+    /// * `payer` - Payer info, `email` is required.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::{Decimal, payments::PaymentCreateBuilder};
+    ///
+    /// PaymentCreateBuilder::pix(
+    ///     Decimal::new(20, 0),
+    ///     Payer {
+    ///         email: "someemail@testmail.com".to_string(),
+    ///         ..Default::default()
+    ///     },
+    /// )?;
+    /// ```
+    pub fn pix(transaction_amount: Decimal, payer: Payer) -> Result<Self, PaymentValidationError> {
+        validate_amount(transaction_amount)?;
+        validate_email(&payer)?;
+
+        Ok(Self::preset(
+            PaymentMethodId::Pix,
+            transaction_amount,
+            payer,
+        ))
+    }
+
+    /// Returns a [`PaymentCreateBuilder`] for a boleto payment
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_amount` - Amount of the payment, must be greater than zero.
+    /// * `payer` - Payer info, `email`, `first_name` and `last_name` are required.
+    /// * `identification` - Payer document, like a CPF or CNPJ. Type and number are required.
+    pub fn boleto(
+        transaction_amount: Decimal,
+        mut payer: Payer,
+        identification: PayerIdentification,
+    ) -> Result<Self, PaymentValidationError> {
+        validate_amount(transaction_amount)?;
+        validate_email(&payer)?;
+
+        let is_filled =
+            |field: &Option<String>| field.as_ref().is_some_and(|v| !v.trim().is_empty());
+
+        if !is_filled(&payer.first_name) || !is_filled(&payer.last_name) {
+            return Err(PaymentValidationError::MissingPayerName);
+        }
+
+        if identification.r#type.is_none() || !is_filled(&identification.number) {
+            return Err(PaymentValidationError::MissingIdentification);
+        }
+
+        payer.identification = Some(identification);
+
+        Ok(Self::preset(
+            PaymentMethodId::Boleto,
+            transaction_amount,
+            payer,
+        ))
+    }
+
+    /// Returns a [`PaymentCreateBuilder`] for a credit or debit card payment
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_amount` - Amount of the payment, must be greater than zero.
+    /// * `token` - Card token, created with the card data or a saved card.
+    /// * `payment_method_id` - Card brand, like [`PaymentMethodId::Visa`].
+    /// * `installments` - Number of installments, at least 1.
+    /// * `payer` - Payer info, `email` is required.
+    pub fn card(
+        transaction_amount: Decimal,
+        token: impl ToString,
+        payment_method_id: PaymentMethodId,
+        installments: u32,
+        payer: Payer,
+    ) -> Result<Self, PaymentValidationError> {
+        validate_amount(transaction_amount)?;
+        validate_email(&payer)?;
+
+        let token = token.to_string();
+        if token.trim().is_empty() {
+            return Err(PaymentValidationError::MissingCardToken);
+        }
+
+        if installments == 0 {
+            return Err(PaymentValidationError::InvalidInstallments(installments));
+        }
+
+        if matches!(
+            payment_method_id,
+            PaymentMethodId::Pix
+                | PaymentMethodId::Boleto
+                | PaymentMethodId::AccountMoney
+                | PaymentMethodId::Loterica
+        ) {
+            return Err(PaymentValidationError::NotACardMethod(payment_method_id));
+        }
+
+        let mut builder = Self::preset(payment_method_id, transaction_amount, payer);
+        builder.0.token = Some(token);
+        builder.0.installments = installments;
+
+        Ok(builder)
+    }
+
+    /// Returns a [`PaymentCreateBuilder`] for a payment with money in the payer's Mercado Pago account
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_amount` - Amount of the payment, must be greater than zero.
+    /// * `payer` - Payer info, `email` is required.
+    pub fn account_money(
+        transaction_amount: Decimal,
+        payer: Payer,
+    ) -> Result<Self, PaymentValidationError> {
+        validate_amount(transaction_amount)?;
+        validate_email(&payer)?;
+
+        Ok(Self::preset(
+            PaymentMethodId::AccountMoney,
+            transaction_amount,
+            payer,
+        ))
+    }
+
+    fn preset(
+        payment_method_id: PaymentMethodId,
+        transaction_amount: Decimal,
+        payer: Payer,
+    ) -> Self {
+        PaymentCreateBuilder(
+            PaymentCreateOptions {
+                description: None,
+                payer,
+                payment_method_id,
+                transaction_amount,
+                ..Default::default()
+            },
+            None,
+        )
+    }
+}
+
+impl<M> PaymentCreateBuilder<M> {
+    /// Checks `transaction_amount` against the limits of the payment method, before sending the request
+    ///
+    /// Avoids declines for amounts the method does not accept, like a boleto below its minimum.
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - Payment methods of the account, from [`PaymentMethodListBuilder`](crate::payment_methods::PaymentMethodListBuilder). The limits depend on the site, so use the catalog of the account that will receive the payment.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::payment_methods::PaymentMethodListBuilder;
+    ///
+    /// let catalog = PaymentMethodListBuilder.send(&mp_client).await?;
+    ///
+    /// builder.validate_amount_limits(&catalog)?;
+    /// ```
+    pub fn validate_amount_limits(
+        &self,
+        catalog: &[PaymentMethod],
+    ) -> Result<(), PaymentValidationError> {
+        let PaymentCreateOptions {
+            payment_method_id,
+            transaction_amount,
+            ..
+        } = &self.0;
+
+        validate_amount(*transaction_amount)?;
+
+        let method = catalog
+            .iter()
+            .find(|method| &method.id == payment_method_id)
+            .filter(|method| method.status == PaymentMethodStatus::Active)
+            .ok_or_else(|| PaymentValidationError::UnavailableMethod(payment_method_id.clone()))?;
+
+        if *transaction_amount < method.min_allowed_amount {
+            return Err(PaymentValidationError::AmountBelowMinimum {
+                method: method.id.clone(),
+                min: method.min_allowed_amount,
+            });
+        }
+
+        if *transaction_amount > method.max_allowed_amount {
+            return Err(PaymentValidationError::AmountAboveMaximum {
+                method: method.id.clone(),
+                max: method.max_allowed_amount,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the issuer offers the requested `installments` for this card and amount, before sending the request
+    ///
+    /// Avoids `cc_rejected_invalid_installments` round trips. If the count is not offered, returns [`PaymentValidationError::InstallmentsNotOffered`] with the allowed options.
+    ///
+    /// # Arguments
+    ///
+    /// * `mp_client` - Client used to fetch the installment options.
+    /// * `bin` - First 6 or 8 digits of the card.
+    ///
+    /// # Example
+    /// ```
+    /// PaymentCreateBuilder::card(Decimal::new(300, 0), token, PaymentMethodId::Visa, 12, payer)?
+    ///     .check_installments(&mp_client, "450995")
+    ///     .await?
+    ///     .send(&mp_client)
+    ///     .await?;
+    /// ```
+    pub async fn check_installments(
+        self,
+        mp_client: &MercadoPagoClient,
+        bin: impl ToString,
+    ) -> Result<Self, MercadoPagoRequestError> {
+        let options = InstallmentsGetBuilder(InstallmentsOptions {
+            amount: self.0.transaction_amount,
+            bin: Some(bin.to_string()),
+            payment_method_id: Some(self.0.payment_method_id.clone()),
+            issuer_id: self.0.issuer_id.clone(),
+            ..Default::default()
+        })
+        .send(mp_client)
+        .await?;
+
+        self.validate_installments(&options)?;
+
+        Ok(self)
+    }
+
+    /// Same as [`check_installments`](Self::check_installments), with installment options you already fetched.
+    pub fn validate_installments(
+        &self,
+        options: &[InstallmentsResponse],
+    ) -> Result<(), PaymentValidationError> {
+        let requested = self.0.installments;
+        let allowed: Vec<u32> = options
+            .iter()
+            .filter(|option| option.payment_method_id == self.0.payment_method_id)
+            .flat_map(|option| option.payer_costs.iter().map(|cost| cost.installments))
+            .collect();
+
+        if allowed.contains(&requested) {
+            return Ok(());
+        }
+
+        Err(PaymentValidationError::InstallmentsNotOffered { requested, allowed })
+    }
+}
+
+fn validate_amount(transaction_amount: Decimal) -> Result<(), PaymentValidationError> {
+    if transaction_amount <= Decimal::ZERO {
+        return Err(PaymentValidationError::NonPositiveAmount);
+    }
+
+    Ok(())
+}
+
+fn validate_email(payer: &Payer) -> Result<(), PaymentValidationError> {
+    if payer.email.trim().is_empty() {
+        return Err(PaymentValidationError::MissingPayerEmail);
+    }
+
+    Ok(())
+}
+
+impl<M> SendRequest for PaymentCreateBuilder<M>
+where
+    M: Serialize + DeserializeOwned + Send,
+{
+    type Output = PaymentResponse<M>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PaymentCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+mod preset_tests {
+    use std::sync::{Arc, Mutex};
+
+    use rust_decimal::Decimal;
+
+    use super::PaymentCreateBuilder;
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        common::{MercadoPagoRequestError, TrimWarning},
+        fixtures::PAYMENT_APPROVED_PIX,
+        payer::{Payer, PayerIdentification},
+        payment_methods::types::{InstallmentsResponse, PaymentMethod},
+        payments::types::{
+            IdentificationType, PaymentCreateOptions, PaymentMethodId, PaymentValidationError,
+            ProductItem,
+        },
+        test_support::{serve, MockResponse},
+    };
+
+    fn payer() -> Payer {
+        Payer {
+            email: "test@testmail.com".to_string(),
+            first_name: Some("Fulano".to_string()),
+            last_name: Some("de Tal".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn expiration_from_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        let builder = PaymentCreateBuilder::pix(Decimal::new(10, 0), payer())
+            .unwrap()
+            .expires_at(Utc.with_ymd_and_hms(2030, 1, 2, 3, 4, 5).unwrap());
+
+        assert_eq!(
+            builder.0.date_of_expiration.as_deref(),
+            Some("2030-01-02T03:04:05.000+00:00")
+        );
+
+        let builder = builder.expires_in(chrono::Duration::minutes(30)).unwrap();
+        assert!(builder.0.date_of_expiration.is_some());
+
+        assert_eq!(
+            builder.expires_in(chrono::Duration::MAX).err(),
+            Some(PaymentValidationError::ExpirationOutOfRange)
+        );
+    }
+
+    #[test]
+    fn expiration_after_duration() {
+        let builder = PaymentCreateBuilder::pix(Decimal::new(10, 0), payer())
+            .unwrap()
+            .expires_after(std::time::Duration::from_secs(30 * 60))
+            .unwrap();
+        assert!(builder.0.date_of_expiration.is_some());
+
+        assert_eq!(
+            builder.expires_after(std::time::Duration::MAX).err(),
+            Some(PaymentValidationError::ExpirationOutOfRange)
+        );
+    }
+
+    #[test]
+    fn pix_preset() {
+        let builder = PaymentCreateBuilder::pix(Decimal::new(10, 0), payer()).unwrap();
+        assert_eq!(builder.0.payment_method_id, PaymentMethodId::Pix);
+
+        assert_eq!(
+            PaymentCreateBuilder::pix(Decimal::ZERO, payer()).err(),
+            Some(PaymentValidationError::NonPositiveAmount)
+        );
+        assert_eq!(
+            PaymentCreateBuilder::pix(Decimal::new(10, 0), Payer::default()).err(),
+            Some(PaymentValidationError::MissingPayerEmail)
+        );
+    }
+
+    #[test]
+    fn boleto_preset() {
+        let identification = || PayerIdentification {
+            r#type: Some(IdentificationType::CPF),
+            number: Some("19119119100".to_string()),
+        };
+
+        let builder =
+            PaymentCreateBuilder::boleto(Decimal::new(10, 0), payer(), identification()).unwrap();
+        assert_eq!(builder.0.payment_method_id, PaymentMethodId::Boleto);
+        assert!(builder.0.payer.identification.is_some());
+
+        assert_eq!(
+            PaymentCreateBuilder::boleto(
+                Decimal::new(10, 0),
+                Payer {
+                    last_name: None,
+                    ..payer()
+                },
+                identification()
+            )
+            .err(),
+            Some(PaymentValidationError::MissingPayerName)
+        );
+        assert_eq!(
+            PaymentCreateBuilder::boleto(
+                Decimal::new(10, 0),
+                payer(),
+                PayerIdentification {
+                    number: None,
+                    ..identification()
+                }
+            )
+            .err(),
+            Some(PaymentValidationError::MissingIdentification)
+        );
+    }
+
+    #[test]
+    fn card_preset() {
+        let builder = PaymentCreateBuilder::card(
+            Decimal::new(10, 0),
+            "ff8080814c11e237014c1ff593b57b4d",
+            PaymentMethodId::Visa,
+            3,
+            payer(),
+        )
+        .unwrap();
+        assert_eq!(builder.0.installments, 3);
+        assert!(builder.0.token.is_some());
+
+        assert_eq!(
+            PaymentCreateBuilder::card(Decimal::new(10, 0), "", PaymentMethodId::Visa, 1, payer())
+                .err(),
+            Some(PaymentValidationError::MissingCardToken)
+        );
+        assert_eq!(
+            PaymentCreateBuilder::card(
+                Decimal::new(10, 0),
+                "token",
+                PaymentMethodId::Visa,
+                0,
+                payer()
+            )
+            .err(),
+            Some(PaymentValidationError::InvalidInstallments(0))
+        );
+        assert_eq!(
+            PaymentCreateBuilder::card(
+                Decimal::new(10, 0),
+                "token",
+                PaymentMethodId::Pix,
+                1,
+                payer()
+            )
+            .err(),
+            Some(PaymentValidationError::NotACardMethod(PaymentMethodId::Pix))
+        );
+    }
+
+    #[test]
+    fn amount_against_catalog() {
+        let catalog: Vec<PaymentMethod> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "bolbradesco",
+                "name": "Boleto",
+                "payment_type_id": "ticket",
+                "status": "active",
+                "min_allowed_amount": 3,
+                "max_allowed_amount": 100000
+            },
+            {
+                "id": "pec",
+                "name": "Pagamento na lotérica",
+                "payment_type_id": "ticket",
+                "status": "deactive",
+                "min_allowed_amount": 4,
+                "max_allowed_amount": 2000
+            }
+        ]))
+        .unwrap();
+
+        let boleto =
+            |amount| PaymentCreateBuilder::preset(PaymentMethodId::Boleto, amount, payer());
+
+        assert!(boleto(Decimal::new(10, 0))
+            .validate_amount_limits(&catalog)
+            .is_ok());
+        assert_eq!(
+            boleto(Decimal::new(299, 2)).validate_amount_limits(&catalog),
+            Err(PaymentValidationError::AmountBelowMinimum {
+                method: PaymentMethodId::Boleto,
+                min: Decimal::new(3, 0)
+            })
+        );
+        assert_eq!(
+            boleto(Decimal::new(100001, 0)).validate_amount_limits(&catalog),
+            Err(PaymentValidationError::AmountAboveMaximum {
+                method: PaymentMethodId::Boleto,
+                max: Decimal::new(100000, 0)
+            })
+        );
+        assert_eq!(
+            PaymentCreateBuilder::preset(PaymentMethodId::Loterica, Decimal::new(10, 0), payer())
+                .validate_amount_limits(&catalog),
+            Err(PaymentValidationError::UnavailableMethod(
+                PaymentMethodId::Loterica
+            ))
+        );
+        assert_eq!(
+            PaymentCreateBuilder::preset(PaymentMethodId::Pix, Decimal::new(10, 0), payer())
+                .validate_amount_limits(&catalog),
+            Err(PaymentValidationError::UnavailableMethod(
+                PaymentMethodId::Pix
+            ))
+        );
+    }
+
+    #[test]
+    fn installments_against_options() {
+        let payer_costs: Vec<_> = [1, 3, 6]
+            .map(|installments| {
+                serde_json::json!({
+                    "installments": installments,
+                    "installment_rate": 0,
+                    "installment_amount": 10,
+                    "total_amount": 30,
+                    "recommended_message": ""
+                })
+            })
+            .into();
+        let options: Vec<InstallmentsResponse> = serde_json::from_value(serde_json::json!([{
+            "payment_method_id": "visa",
+            "payment_type_id": "credit_card",
+            "payer_costs": payer_costs
+        }]))
+        .unwrap();
+
+        let card = |installments| {
+            PaymentCreateBuilder::card(
+                Decimal::new(30, 0),
+                "token",
+                PaymentMethodId::Visa,
+                installments,
+                payer(),
+            )
+            .unwrap()
+        };
+
+        assert!(card(3).validate_installments(&options).is_ok());
+        assert_eq!(
+            card(12).validate_installments(&options),
+            Err(PaymentValidationError::InstallmentsNotOffered {
+                requested: 12,
+                allowed: vec![1, 3, 6]
+            })
+        );
+    }
+
+    #[test]
+    fn trims_free_text() {
+        let mut builder = PaymentCreateBuilder::pix(Decimal::new(10, 0), payer()).unwrap();
+        builder.0.description = Some("ç".repeat(700));
+        builder.0.statement_descriptor = Some("LOJA".to_string());
+        builder = builder.add_items(
+            [ProductItem {
+                title: Some("a".repeat(300)),
+                ..Default::default()
+            }]
+            .into_iter(),
+        );
+
+        let (builder, warnings) = builder.trim_free_text();
+
+        assert_eq!(
+            warnings,
+            vec![
+                TrimWarning {
+                    field: "description".to_string(),
+                    original_len: 700,
+                    limit: 600
+                },
+                TrimWarning {
+                    field: "additional_info.items[0].title".to_string(),
+                    original_len: 300,
+                    limit: 256
+                }
+            ]
+        );
+        assert_eq!(builder.0.description.unwrap().chars().count(), 600);
+        assert_eq!(builder.0.statement_descriptor.as_deref(), Some("LOJA"));
+    }
+
+    #[test]
+    fn max_request_size() {
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_max_request_size(64)
+            .build();
+        let builder = PaymentCreateBuilder::pix(Decimal::new(10, 0), payer()).unwrap();
+
+        let request = mp_client.start_request(reqwest::Method::POST, "/v1/payments");
+        assert!(matches!(
+            mp_client.json_body(request, &builder.0),
+            Err(MercadoPagoRequestError::RequestTooLarge { limit: 64, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn pays_with_saved_card() {
+        let bodies = Arc::new(Mutex::new(vec![]));
+        let recorded = bodies.clone();
+        let url = serve(move |request| {
+            let path = request.head.split(' ').nth(1).unwrap().to_string();
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            recorded.lock().unwrap().push((path.clone(), body));
+
+            match path.as_str() {
+                "/v1/card_tokens" => MockResponse::json(
+                    "201 Created",
+                    serde_json::json!({ "id": "ff8080814c11e237014c1ff593b57b4d" }),
+                ),
+                "/v1/payments" => MockResponse::new(
+                    "201 Created",
+                    "application/json",
+                    PAYMENT_APPROVED_PIX.as_bytes(),
+                ),
+                path => panic!("unexpected request to {path}"),
+            }
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        PaymentCreateBuilder(
+            PaymentCreateOptions {
+                transaction_amount: Decimal::new(25, 0),
+                payment_method_id: PaymentMethodId::Visa,
+                payer: payer(),
+                ..Default::default()
+            },
+            None,
+        )
+        .with_saved_card(&mp_client, "123456789-jxOV430go9fx2e", "9176235123", "123")
+        .await
+        .unwrap()
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        let bodies = bodies.lock().unwrap();
+        let [(token_path, token), (payment_path, payment)] = bodies.as_slice() else {
+            panic!("expected 2 requests, got {bodies:?}");
+        };
+        assert_eq!(token_path, "/v1/card_tokens");
+        assert_eq!(token["card_id"], "9176235123");
+        assert_eq!(token["security_code"], "123");
+        assert_eq!(payment_path, "/v1/payments");
+        assert_eq!(payment["token"], "ff8080814c11e237014c1ff593b57b4d");
+        assert_eq!(payment["payer"]["type"], "customer");
+        assert_eq!(payment["payer"]["id"], "123456789-jxOV430go9fx2e");
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,12 @@
+use std::future::Future;
+
 use reqwest::Method;
+use serde::de::DeserializeOwned;
 
 use crate::{
-    client::MercadoPagoClient,
-    common::{resolve_json, MercadoPagoRequestError},
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
 };
 
 use super::types::PaymentResponse;
@@ -17,12 +21,13 @@ use super::types::PaymentResponse;
 /// ```
 /// use mpago::payments::PaymentGetBuilder;
 ///
-/// PaymentGetBuilder(87891224)
+/// PaymentGetBuilder(87891224.into())
 /// ```
 ///
 /// # Docs
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments_id/get>
-pub struct PaymentGetBuilder(pub u64);
+#[derive(Debug, Clone)]
+pub struct PaymentGetBuilder(pub PaymentId);
 
 impl PaymentGetBuilder {
     /// Send the request
@@ -30,12 +35,50 @@ impl PaymentGetBuilder {
         self,
         mp_client: &MercadoPagoClient,
     ) -> Result<PaymentResponse, MercadoPagoRequestError> {
+        self.send_with_metadata(mp_client).await
+    }
+
+    /// Send the request, reading `metadata` as `M`
+    ///
+    /// # Example
+    /// ```
+    /// #[derive(Deserialize)]
+    /// struct OrderMetadata {
+    ///     order_id: u64,
+    /// }
+    ///
+    /// let payment = PaymentGetBuilder(87891224.into())
+    ///     .send_with_metadata::<OrderMetadata>(&mp_client)
+    ///     .await?;
+    ///
+    /// println!("{}", payment.metadata.order_id);
+    /// ```
+    pub async fn send_with_metadata<M: DeserializeOwned>(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PaymentResponse<M>, MercadoPagoRequestError> {
         let res = mp_client
-            .start_request(Method::GET, format!("/v1/payments/{}", self.0))
-            .send()
+            .execute(mp_client.start_request(Method::GET, format!("/v1/payments/{}", self.0)))
             .await?;
 
-        resolve_json::<PaymentResponse>(res).await
+        mp_client.resolve_json::<PaymentResponse<M>>(res).await
+    }
+}
+
+impl<T: Into<PaymentId>> From<T> for PaymentGetBuilder {
+    fn from(id: T) -> Self {
+        PaymentGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for PaymentGetBuilder {
+    type Output = PaymentResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PaymentGetBuilder::send(self, mp_client)
     }
 }
 
@@ -71,7 +114,7 @@ mod tests {
     async fn fail_get_payment() {
         let mp_client = create_test_client();
 
-        let get_payment = PaymentGetBuilder(1234567890).send(&mp_client).await;
+        let get_payment = PaymentGetBuilder(1234567890.into()).send(&mp_client).await;
 
         assert!(get_payment.is_err());
     }
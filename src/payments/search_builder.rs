@@ -1,15 +1,18 @@
-use async_stream::stream;
-use reqwest::Method;
-use std::pin::Pin;
-use tokio_stream::Stream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use std::{borrow::Borrow, pin::Pin};
 
 use super::{
     get_builder::PaymentGetBuilder,
-    types::{PartialPaymentResult, PaymentResponse, PaymentSearchOptions, PaymentSearchResponse},
+    types::{
+        PartialPaymentResult, PaymentResponse, PaymentSearchOptions, PaymentSearchResponse,
+        PaymentSummary,
+    },
 };
 use crate::{
     client::MercadoPagoClient,
-    common::{resolve_json, MercadoPagoRequestError},
+    common::MercadoPagoRequestError,
+    pagination::{collect_all, paginate, search_query, SearchPage, SearchStream},
 };
 
 /// Builder for search payments
@@ -33,63 +36,150 @@ use crate::{
 ///
 /// # Docs
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments_search/get>
+#[derive(Debug, Clone)]
 pub struct PaymentSearchBuilder(pub PaymentSearchOptions);
 
 impl PaymentSearchBuilder {
     /// This function creates a stream of payments, it goes through all the pages.
     ///
     /// When you fetch a payment, it will check if you reached the end of a page, if you have, it will fetch another page and return the first payment on that page, other wise it gives you the next payment from the current page.
+    ///
+    /// The stream ends after yielding an error.
     pub async fn fetch_all_streamed<'a>(
         self,
         mp_client: &'a MercadoPagoClient,
-    ) -> Pin<Box<dyn Stream<Item = Result<PartialPaymentResult, MercadoPagoRequestError>> + 'a>>
+    ) -> Pin<
+        Box<dyn Stream<Item = Result<PartialPaymentResult, MercadoPagoRequestError>> + Send + 'a>,
+    > {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Same as [`fetch_all_streamed`](Self::fetch_all_streamed), but the stream owns the client.
+    ///
+    /// The returned stream is `'static`, so it can be moved into a `tokio::spawn` task. Since cloning a [`MercadoPagoClient`] is cheap, you can pass `mp_client.clone()`.
+    pub async fn fetch_all_streamed_owned(
+        self,
+        mp_client: MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<PartialPaymentResult, MercadoPagoRequestError>> + Send>>
     {
-        const DEFAULT_PAGE_LIMIT: usize = 30;
-        Box::pin(stream! {
-            let options = self.0;
-            let limit = options.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
-            let mut offset = options.offset.unwrap_or_default();
-            loop {
-                let res = match mp_client
-                    .start_request(Method::GET, "/v1/payments/search")
-                    .query(&PaymentSearchOptions {
-                        offset: Some(offset),
-                        limit: Some(limit),
-                        ..options.clone()
-                    })
-                    .send()
-                    .await {
-                        Ok(page) => page,
-                        // .next() retorna Some(Err(MercadoPagoRequestError))
-                        Err(err) => {
-                            yield Err(err.into());
-                            continue;
-                        }
-                    };
-                let page = match resolve_json::<PaymentSearchResponse>(res).await {
-                    Ok(page) => page,
-                    // .next() retorna Some(Err(MercadoPagoRequestError))
-                    Err(err) => {
-                        yield Err(err);
-                        continue;
-                    }
-                };
-
-                for payment in page.results {
-                    // .next() retorna Some(Ok(PartialPaymentResult))
-                    yield Ok(payment)
-                }
-
-                offset += limit;
-                if offset >= page.paging.total {
-                    // .next() retorna None
-                    return
-                }
-            }
-        })
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Fetches every page and collects the payments into a [`Vec`].
+    ///
+    /// Useful for small result sets, where a stream is overkill. Stops at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mp_client` - Client used to send the requests.
+    /// * `max_items` - If `Some`, stops fetching pages once this many payments were collected, protecting memory from huge result sets.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::payments::PaymentSearchBuilder;
+    ///
+    /// let payments = PaymentSearchBuilder(PaymentSearchOptions::default())
+    ///     .collect_all(&mp_client, Some(100))
+    ///     .await?;
+    /// ```
+    pub async fn collect_all(
+        self,
+        mp_client: &MercadoPagoClient,
+        max_items: Option<usize>,
+    ) -> Result<Vec<PartialPaymentResult>, MercadoPagoRequestError> {
+        collect_all(stream_pages(self.0, mp_client), max_items).await
+    }
+
+    /// Folds every payment into `init` with `f` while streaming the pages, without keeping the payments in memory.
+    ///
+    /// Stops at the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::payments::PaymentSearchBuilder;
+    ///
+    /// let refunded = PaymentSearchBuilder(PaymentSearchOptions::default())
+    ///     .aggregate(&mp_client, Decimal::ZERO, |total, payment| {
+    ///         if payment.status == PaymentStatus::Refunded {
+    ///             total + payment.transaction_amount
+    ///         } else {
+    ///             total
+    ///         }
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn aggregate<A>(
+        self,
+        mp_client: &MercadoPagoClient,
+        init: A,
+        mut f: impl FnMut(A, &PartialPaymentResult) -> A,
+    ) -> Result<A, MercadoPagoRequestError> {
+        let mut stream = stream_pages(self.0, mp_client);
+        let mut acc = init;
+
+        while let Some(payment) = stream.next().await {
+            acc = f(acc, &payment?);
+        }
+
+        Ok(acc)
+    }
+
+    /// Sums `transaction_amount` and counts the payments by day, status and payment method, see [`aggregate`](Self::aggregate).
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::payments::PaymentSearchBuilder;
+    ///
+    /// let summary = PaymentSearchBuilder(PaymentSearchOptions {
+    ///     range: Some(PaymentSearchRange::DateCreated),
+    ///     begin_date: Some(SearchDate::days_ago(7)),
+    ///     end_date: Some(SearchDate::NOW),
+    ///     ..Default::default()
+    /// })
+    /// .summarize(&mp_client)
+    /// .await?;
+    ///
+    /// for (key, totals) in summary.totals {
+    ///     println!("{} {} {}: {} payments, {}", key.date, key.status, key.payment_method_id, totals.count, totals.transaction_amount);
+    /// }
+    /// ```
+    pub async fn summarize(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PaymentSummary, MercadoPagoRequestError> {
+        self.aggregate(
+            mp_client,
+            PaymentSummary::default(),
+            |mut summary, payment| {
+                summary.add(payment);
+                summary
+            },
+        )
+        .await
+    }
+}
+
+search_query!(PaymentSearchOptions);
+
+impl SearchPage for PaymentSearchResponse {
+    type Item = PartialPaymentResult;
+
+    fn into_results(self) -> (Vec<Self::Item>, usize) {
+        (self.results, self.paging.total)
     }
 }
 
+/// Streams every result matching `options`, going through all the pages.
+fn stream_pages<'a, C>(
+    options: PaymentSearchOptions,
+    mp_client: C,
+) -> SearchStream<'a, PartialPaymentResult>
+where
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    paginate::<PaymentSearchResponse, _, _>("/v1/payments/search", options, mp_client)
+}
+
 impl PartialPaymentResult {
     /// Returns the full payment information
     pub async fn fetch_full_payment(
@@ -105,7 +195,7 @@ impl PartialPaymentResult {
 mod tests {
     use super::{PaymentSearchBuilder, PaymentSearchOptions};
     use crate::common::create_test_client;
-    use tokio_stream::StreamExt;
+    use futures_util::StreamExt;
 
     #[tokio::test]
     async fn search_payments() {
@@ -124,4 +214,37 @@ mod tests {
             panic!("Failed to fetch first item");
         }
     }
+
+    #[tokio::test]
+    async fn collect_payments() {
+        let mp_client = create_test_client();
+
+        let payments = PaymentSearchBuilder(PaymentSearchOptions {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .collect_all(&mp_client, Some(3))
+        .await
+        .unwrap();
+
+        assert!(payments.len() <= 3);
+    }
+
+    #[tokio::test]
+    async fn search_payments_in_spawned_task() {
+        let mp_client = create_test_client();
+
+        let mut response = PaymentSearchBuilder(PaymentSearchOptions {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .fetch_all_streamed_owned(mp_client)
+        .await;
+
+        let first = tokio::spawn(async move { response.next().await })
+            .await
+            .unwrap();
+
+        assert!(matches!(first, Some(Ok(_))));
+    }
 }
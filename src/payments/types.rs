@@ -1,19 +1,30 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+    time::SystemTime,
+};
 
 use iso_currency::Currency;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use serde_with::skip_serializing_none;
+use thiserror::Error;
 
-use crate::payer::{AdditionalInfoPayer, Payer};
+use crate::{
+    common::{format_system_time, ExternalReference, PaymentLimits},
+    ids::{MerchantOrderId, PaymentId, SubscriptionId, UserId},
+    payer::{AdditionalInfoPayer, Payer},
+    refunds::types::RefundResponse,
+};
 
 /// # PaymentUpdateOptions
 /// Struct to use in [`PaymentUpdateBuilder`](crate::payments::PaymentUpdateBuilder)
 ///
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments_id/put>
 #[skip_serializing_none]
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct PaymentUpdateOptions {
     /// It's a boolean field that exists in two-step payments (such as debit cards). In this type of payment, which is done asynchronously, first, the purchase amount is reserved (capture = false). This amount is captured and not immediately debited from the account. When the money is actually transferred to the collector (the recipient of the payment), the capture of the amount is performed (capture = true).
     pub capture: Option<bool>,
@@ -42,25 +53,155 @@ pub struct PaymentSearchOptions {
     /// It is an external reference for the payment.
     ///
     /// It can be, for example, a hash code from the Central Bank, serving as an origin identifier for the transaction.
-    pub external_reference: Option<String>,
+    pub external_reference: Option<ExternalReference<PaymentLimits>>,
     /// Defines which date field Mercado Pago should check when searching using `begin_date` and `end_date`
     ///
     /// What this means is that you can filter by update, created date or whatever date
     pub range: Option<PaymentSearchRange>,
     /// Sets the start of the search interval for payments.
     ///
-    /// Its format can be a relative date - `"NOW-XDAYS"`, `"NOW-XMONTHS"` - or an absolute date in [`ISO8601`](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
-    ///
     /// If not specified, it defaults to `"NOW-3MONTHS"`.
-    pub begin_date: Option<String>,
+    pub begin_date: Option<SearchDate>,
     /// Sets the end of the search interval for payments.
     ///
-    /// Its format can be a relative date - `"NOW-XDAYS"`, `"NOW-XMONTHS"` - or an absolute date in [`ISO8601`](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
-    ///
     /// If not specified, it defaults to `"NOW-3MONTHS"`.
-    pub end_date: Option<String>,
+    pub end_date: Option<SearchDate>,
+    /// Only payments charged by this subscription, see [`Subscription::fetch_payments`](crate::subscriptions::types::Subscription::fetch_payments).
+    pub preapproval_id: Option<SubscriptionId>,
+}
+
+/// Limit of the search interval for payments, see [`PaymentSearchOptions::begin_date`] and [`PaymentSearchOptions::end_date`]
+///
+/// Sent as a relative date - `"NOW"`, `"NOW-XDAYS"`, `"NOW-XMONTHS"` - or as an absolute date in [`ISO8601`](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+///
+/// # Example
+/// ```
+/// use std::time::SystemTime;
+///
+/// use mpago::payments::types::SearchDate;
+///
+/// let begin_date = SearchDate::Relative { days: 7, months: 0 };
+/// assert_eq!(begin_date.to_string(), "NOW-7DAYS");
+///
+/// let end_date = SearchDate::Absolute(SystemTime::now());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum SearchDate {
+    /// Days and months before now. Mercado Pago only accepts one unit, so the months are sent as days when both are set.
+    Relative { days: u32, months: u32 },
+    /// A fixed date, sent in UTC. With the `chrono` feature, it can also be created from a `DateTime`.
+    Absolute(SystemTime),
+}
+
+impl SearchDate {
+    /// Now, the default end of the interval.
+    pub const NOW: SearchDate = SearchDate::Relative { days: 0, months: 0 };
+
+    /// Days used for each month when a [`Relative`](Self::Relative) date has both units.
+    const DAYS_PER_MONTH: u32 = 30;
+
+    /// `days` before now.
+    pub fn days_ago(days: u32) -> Self {
+        SearchDate::Relative { days, months: 0 }
+    }
+
+    /// `months` before now.
+    pub fn months_ago(months: u32) -> Self {
+        SearchDate::Relative { days: 0, months }
+    }
+}
+
+impl Display for SearchDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SearchDate::Relative { days: 0, months: 0 } => f.write_str("NOW"),
+            SearchDate::Relative { days: 0, months } => write!(f, "NOW-{months}MONTHS"),
+            SearchDate::Relative { days, months } => write!(
+                f,
+                "NOW-{}DAYS",
+                days.saturating_add(months.saturating_mul(Self::DAYS_PER_MONTH))
+            ),
+            SearchDate::Absolute(time) => f.write_str(&format_system_time(time)),
+        }
+    }
+}
+
+impl FromStr for SearchDate {
+    type Err = SearchDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(relative) = s.strip_prefix("NOW") else {
+            return parse_absolute(s).ok_or_else(|| SearchDateError(s.to_string()));
+        };
+
+        let Some(relative) = relative.strip_prefix('-') else {
+            return match relative {
+                "" => Ok(SearchDate::NOW),
+                _ => Err(SearchDateError(s.to_string())),
+            };
+        };
+
+        let parse = |amount: &str| {
+            amount
+                .parse::<u32>()
+                .map_err(|_| SearchDateError(s.to_string()))
+        };
+        if let Some(days) = relative.strip_suffix("DAYS") {
+            Ok(SearchDate::days_ago(parse(days)?))
+        } else if let Some(months) = relative.strip_suffix("MONTHS") {
+            Ok(SearchDate::months_ago(parse(months)?))
+        } else {
+            Err(SearchDateError(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for SearchDate {
+    type Error = SearchDateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<SearchDate> for String {
+    fn from(value: SearchDate) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<SystemTime> for SearchDate {
+    fn from(value: SystemTime) -> Self {
+        SearchDate::Absolute(value)
+    }
+}
+
+/// Absolute dates are only parsed with the `chrono` feature.
+#[cfg(feature = "chrono")]
+fn parse_absolute(date: &str) -> Option<SearchDate> {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(|date| SearchDate::Absolute(date.into()))
+}
+
+#[cfg(not(feature = "chrono"))]
+fn parse_absolute(_date: &str) -> Option<SearchDate> {
+    None
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for SearchDate {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        SearchDate::Absolute(value.into())
+    }
 }
 
+/// Date that isn't `NOW`, `NOW-XDAYS`, `NOW-XMONTHS` or, with the `chrono` feature, ISO8601
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid search date: {0}")]
+pub struct SearchDateError(pub String);
+
 /// Parameter used to define the search interval for payments.
 ///
 /// It is related to `begin_date` and `end_date`
@@ -97,9 +238,9 @@ pub enum PaymentSearchSort {
 /// Essential information of Payment response.
 ///
 /// Used in [`PaymentSearchResponse`] to save memory.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PartialPaymentResult {
-    pub id: u64,
+    pub id: PaymentId,
     /// Payment create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
     pub date_created: String,
     /// Date when payment was approved. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
@@ -138,20 +279,60 @@ pub struct PartialPaymentResult {
     pub processing_mode: PaymentProcessingMode,
 }
 
+/// Totals of payments by day, status and payment method, computed by [`PaymentSearchBuilder::summarize`](crate::payments::PaymentSearchBuilder::summarize)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PaymentSummary {
+    pub totals: HashMap<PaymentSummaryKey, PaymentTotals>,
+}
+
+impl PaymentSummary {
+    /// Adds `payment` to the totals of its day, status and payment method.
+    pub fn add(&mut self, payment: &PartialPaymentResult) {
+        let totals = self
+            .totals
+            .entry(PaymentSummaryKey {
+                date: payment.date_created.chars().take(10).collect(),
+                status: payment.status.clone(),
+                payment_method_id: payment.payment_method_id.clone(),
+            })
+            .or_default();
+
+        totals.count += 1;
+        totals.transaction_amount += payment.transaction_amount;
+    }
+}
+
+/// Group of a [`PaymentSummary`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentSummaryKey {
+    /// Day the payment was created, as `YYYY-MM-DD` in the timezone of `date_created`.
+    pub date: String,
+    pub status: PaymentStatus,
+    pub payment_method_id: PaymentMethodId,
+}
+
+/// Totals of a group of a [`PaymentSummary`]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PaymentTotals {
+    pub count: usize,
+    /// Sum of `transaction_amount`.
+    pub transaction_amount: Decimal,
+}
+
 /// # PaymentSearchResponse
 /// Used in pagination of [`PaymentSearchBuilder`](crate::payments::PaymentSearchBuilder)
 ///
 /// Response from `/v1/payments/search`
 ///
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments_search/get>
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PaymentSearchResponse {
     pub paging: Paging,
     pub results: Vec<PartialPaymentResult>,
 }
 
 /// Pagination information for search results.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Paging {
     /// Total number of items in the charge.
     pub total: usize,
@@ -168,8 +349,8 @@ pub struct Paging {
 ///
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments/post>
 #[skip_serializing_none]
-#[derive(Deserialize, Serialize, Debug)]
-pub struct PaymentCreateOptions {
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PaymentCreateOptions<M = serde_json::Value> {
     /// At the Payments level, it's primarily data, and we forward this information to other APIs, such as Risco, for scoring and fraud prevention, and to Taxes to determine them for international payments.
     pub additional_info: AdditionalInfo,
     /// Commission (fee) that third parties (integrators) charge their clients, in this case, sellers, for using the marketplace platform and other services. This is a monetary amount determined by the integrator for the seller.
@@ -198,11 +379,10 @@ pub struct PaymentCreateOptions {
     /// Attribute that commonly contains an agreement on how much will be charged to the user (typically, this field is more relevant for Marketplace payments). Pricing and fees are calculated based on this identifier.
     pub differential_pricing_id: Option<u32>,
     /// It is an external reference for the payment. It can be, for example, a hash code from the Central Bank, serving as an origin identifier for the transaction.
-    pub external_reference: Option<String>,
+    pub external_reference: Option<ExternalReference<PaymentLimits>>,
     pub installments: u32,
     /// It is the identifier of the card issuer being used in a credit or debit card payment.
     pub issuer_id: Option<String>,
-    // pub metadata: T,
     /// Notification URL available to receive notifications of events related to the payment.
     pub notification_url: Option<String>,
     /// Payer info
@@ -211,11 +391,93 @@ pub struct PaymentCreateOptions {
     pub payment_method_id: PaymentMethodId,
     /// Description with which the payment will appear on the card statement (e.g., `MERCADOPAGO`).
     pub statement_descriptor: Option<String>,
+    /// Whether the card payment can go through a 3DS challenge, required by many issuers. When the issuer asks for it, the payment is created with `status_detail` `pending_challenge` and [`PaymentResponse::three_ds_info`] has the challenge.
+    pub three_d_secure_mode: Option<ThreeDSecureMode>,
     /// Card token identifier (required for credit cards). The card token is created from the card's own information, increasing security during the payment process. Additionally, once the token is used for a specific purchase, it is discarded, and a new token is required for future purchases.
     pub token: Option<String>,
     #[serde(with = "rust_decimal::serde::float")]
     pub transaction_amount: Decimal,
-    pub metadata: HashMap<String, serde_json::Value>,
+    /// Data of your own attached to the payment and returned in [`PaymentResponse::metadata`], typed as `M`. Mercado Pago converts camelCase keys to snake_case.
+    pub metadata: Option<M>,
+}
+
+/// Error when the fields required by a payment method are missing or invalid, found before sending the request.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PaymentValidationError {
+    #[error("Transaction amount must be greater than zero")]
+    NonPositiveAmount,
+    #[error("Payer email is required")]
+    MissingPayerEmail,
+    #[error("Payer first and last name are required")]
+    MissingPayerName,
+    #[error("Payer identification type and number are required")]
+    MissingIdentification,
+    #[error("Card token is required")]
+    MissingCardToken,
+    #[error("Installments must be at least 1, got {0}")]
+    InvalidInstallments(u32),
+    #[error("{0} is not a card payment method")]
+    NotACardMethod(PaymentMethodId),
+    #[error("{0} is not available for this account")]
+    UnavailableMethod(PaymentMethodId),
+    #[error("Minimum amount for {method} is {min}")]
+    AmountBelowMinimum {
+        method: PaymentMethodId,
+        min: Decimal,
+    },
+    #[error("Maximum amount for {method} is {max}")]
+    AmountAboveMaximum {
+        method: PaymentMethodId,
+        max: Decimal,
+    },
+    #[error("{requested} installments are not offered, allowed: {allowed:?}")]
+    InstallmentsNotOffered { requested: u32, allowed: Vec<u32> },
+    #[error("Expiration date is out of range")]
+    ExpirationOutOfRange,
+}
+
+impl<M> PaymentCreateOptions<M> {
+    /// Same options with `metadata`, typed as `T`
+    ///
+    /// # Example
+    /// ```
+    /// #[derive(Serialize, Deserialize)]
+    /// struct OrderMetadata {
+    ///     order_id: u64,
+    /// }
+    ///
+    /// let options = PaymentCreateOptions {
+    ///     transaction_amount: Decimal::new(25, 0),
+    ///     ..Default::default()
+    /// }
+    /// .with_metadata(OrderMetadata { order_id: 4711 });
+    /// ```
+    pub fn with_metadata<T>(self, metadata: T) -> PaymentCreateOptions<T> {
+        PaymentCreateOptions {
+            additional_info: self.additional_info,
+            application_fee: self.application_fee,
+            binary_mode: self.binary_mode,
+            callback_url: self.callback_url,
+            campaign_id: self.campaign_id,
+            capture: self.capture,
+            coupon_amount: self.coupon_amount,
+            coupon_code: self.coupon_code,
+            date_of_expiration: self.date_of_expiration,
+            description: self.description,
+            differential_pricing_id: self.differential_pricing_id,
+            external_reference: self.external_reference,
+            installments: self.installments,
+            issuer_id: self.issuer_id,
+            notification_url: self.notification_url,
+            payer: self.payer,
+            payment_method_id: self.payment_method_id,
+            statement_descriptor: self.statement_descriptor,
+            three_d_secure_mode: self.three_d_secure_mode,
+            token: self.token,
+            transaction_amount: self.transaction_amount,
+            metadata: Some(metadata),
+        }
+    }
 }
 
 impl Default for PaymentCreateOptions {
@@ -247,16 +509,17 @@ impl Default for PaymentCreateOptions {
             },
             payment_method_id: PaymentMethodId::Pix,
             statement_descriptor: None,
+            three_d_secure_mode: None,
             token: None,
             transaction_amount: Decimal::new(0, 1),
-            metadata: HashMap::new(),
+            metadata: None,
         }
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct PaymentResponse {
-    pub id: u64,
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PaymentResponse<M = serde_json::Value> {
+    pub id: PaymentId,
     /// Payment create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
     pub date_created: String,
     /// Date when payment was approved. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
@@ -303,13 +566,15 @@ pub struct PaymentResponse {
     /// Identifier of the store to which the cash register belongs.
     pub store_id: Option<String>,
     /// It is the user who receives the money. For example - A user (payer) purchases a cellphone through the marketplace. The identifier of the store/seller receiving the payment is the `collector_id`.
-    pub collector_id: u64,
+    pub collector_id: UserId,
     pub payer: Payer,
-    // pub metadata: T,
     /// At the Payments level, it's primarily data, and we forward this information to other APIs, such as Risco, for scoring and fraud prevention, and to Taxes to determine them for international payments.
     pub additional_info: AdditionalInfo,
     /// It is an external reference for the payment. It can be, for example, a hash code from the Central Bank, serving as an origin identifier for the transaction.
     pub external_reference: Option<String>,
+    /// Order the payment belongs to, an empty object when there is none. See [`PaymentResponse::merchant_order_id`].
+    #[serde(default)]
+    pub order: PaymentOrder,
     #[serde(with = "rust_decimal::serde::float")]
     pub transaction_amount: Decimal,
     #[serde(with = "rust_decimal::serde::float_option")]
@@ -325,6 +590,12 @@ pub struct PaymentResponse {
     pub deduction_schema: Option<String>,
     pub transaction_details: Option<PaymentTransactionDetails>,
     pub fee_details: Vec<FeeDetails>,
+    /// Refunds of the payment, total and partial.
+    #[serde(default)]
+    pub refunds: Vec<RefundResponse>,
+    /// Charges applied to the payment, like fees and taxes, with the accounts paying and receiving them.
+    #[serde(default)]
+    pub charges_details: Vec<ChargeDetail>,
     /// Indicates whether the payment amount has been captured or is pending capture.
     pub captured: bool,
     /// When set to `true`, payments can only be `"approved"` or `"rejected"`. Otherwise, they can also result in being `"in_process"`.
@@ -335,6 +606,8 @@ pub struct PaymentResponse {
     pub statement_descriptor: Option<String>,
     pub installments: u32,
     pub card: Option<PaymentCard>,
+    /// 3DS challenge to show to the buyer, when `status_detail` is `pending_challenge`.
+    pub three_ds_info: Option<ThreeDsInfo>,
     /// Notification URL available to receive notifications of events related to the payment.
     pub notification_url: Option<String>,
     pub processing_mode: PaymentProcessingMode,
@@ -345,11 +618,307 @@ pub struct PaymentResponse {
     pub mechant_number: Option<String>,
     /// Information about the application that processes the payment and receives regulatory data.
     pub point_of_interaction: PaymentPointOfInteraction,
-    pub metadata: HashMap<String, serde_json::Value>,
+    /// Data sent in [`PaymentCreateOptions::metadata`], an empty object when there is none. Read it typed with [`PaymentGetBuilder::send_with_metadata`](crate::payments::PaymentGetBuilder::send_with_metadata).
+    pub metadata: M,
+    /// Risk analysis of the payment, to join the fraud decisions of Mercado Pago with your own models. `None` when the payment didn't go through the risk analysis.
+    #[serde(flatten, deserialize_with = "deserialize_risk_info")]
+    pub risk_info: Option<RiskInfo>,
+}
+
+/// 3DS mode of a card payment, see [`PaymentCreateOptions::three_d_secure_mode`].
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDSecureMode {
+    /// The payment is never challenged. Default of the API.
+    NotSupported,
+    /// The issuer decides whether to challenge the payment.
+    Optional,
+    /// For untracked 3DS modes
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// 3DS challenge of a payment
+///
+/// Show `external_resource_url` in an iframe, posting `creq` to it. After the challenge, the payment leaves `pending_challenge`.
+///
+/// <https://www.mercadopago.com.br/developers/pt/docs/checkout-api/how-tos/integrate-3ds>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ThreeDsInfo {
+    /// URL of the challenge page of the issuer.
+    pub external_resource_url: String,
+    /// Challenge request, sent as the `creq` field of the form posted to `external_resource_url`.
+    pub creq: String,
+}
+
+/// Order of a payment, see [`PaymentResponse::order`].
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaymentOrder {
+    /// Identifier of the order, sent as a string by the API.
+    #[serde(
+        default,
+        deserialize_with = "serde_aux::field_attributes::deserialize_option_number_from_string"
+    )]
+    pub id: Option<u64>,
+    /// Who created the order: `mercadopago` for merchant orders, `mercadolibre` for Mercado Libre orders.
+    pub r#type: Option<String>,
+}
+
+/// Risk analysis of a payment by the fraud prevention of Mercado Pago, see [`PaymentResponse::risk_info`].
+///
+/// Mercado Pago sends these fields at the top level of the payment and doesn't document them, so unexpected values are dropped instead of failing the payment.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RiskInfo {
+    /// Identifier of the risk analysis execution that evaluated the payment, `None` when it isn't a number.
+    pub risk_execution_id: Option<u64>,
+    /// The other `risk_*` fields of the payment, like internal scoring tags, as sent by the API.
+    #[serde(flatten)]
+    pub scoring: HashMap<String, serde_json::Value>,
+}
+
+/// Collects the `risk_*` fields left over by [`PaymentResponse`], `None` when there is none.
+fn deserialize_risk_info<'de, D>(deserializer: D) -> Result<Option<RiskInfo>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct RiskInfoVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for RiskInfoVisitor {
+        type Value = Option<RiskInfo>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("the fields of a payment")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut risk_info = RiskInfo::default();
+            while let Some(key) = map.next_key::<String>()? {
+                if !key.starts_with("risk_") {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                    continue;
+                }
+
+                let value = map.next_value::<serde_json::Value>()?;
+                if key == "risk_execution_id" {
+                    risk_info.risk_execution_id = match &value {
+                        serde_json::Value::String(id) => id.trim().parse().ok(),
+                        value => value.as_u64(),
+                    };
+                } else if !value.is_null() {
+                    risk_info.scoring.insert(key, value);
+                }
+            }
+
+            Ok((risk_info != RiskInfo::default()).then_some(risk_info))
+        }
+    }
+
+    deserializer.deserialize_map(RiskInfoVisitor)
+}
+
+/// Action that can be taken on an existing payment.
+///
+/// See [`PaymentResponse::available_actions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaymentAction {
+    /// Capture the reserved amount of an authorized payment.
+    Capture,
+    /// Cancel a payment that was not approved yet.
+    Cancel,
+    /// Refund the remaining amount of the payment.
+    Refund,
+    /// Refund only part of the remaining amount.
+    PartialRefund,
+}
+
+/// Amounts of a payment, split the same way for receipts and ledgers.
+///
+/// See [`PaymentResponse::amount_breakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PaymentAmountBreakdown {
+    /// Amount paid by the payer, financing included.
+    pub gross: Decimal,
+    /// Commission of Mercado Pago (`mercadopago_fee`).
+    pub mercadopago_fee: Decimal,
+    /// Commission of the marketplace (`application_fee`).
+    pub application_fee: Decimal,
+    /// Cost of the installments (`financing_fee`).
+    pub financing_fee: Decimal,
+    /// Other fees, like `coupon_fee`, `discount_fee` and `shipping_fee`.
+    pub other_fees: Decimal,
+    /// Shipping cost included in the amount.
+    pub shipping: Decimal,
+    /// Taxes included in the amount.
+    pub taxes: Decimal,
+    /// `gross` minus every fee, what the collector receives once the payment is approved.
+    pub net: Decimal,
+}
+
+impl<M> PaymentResponse<M> {
+    /// Days an authorized payment can be captured.
+    const CAPTURE_WINDOW_DAYS: i64 = 7;
+    /// Days an approved payment can be refunded.
+    const REFUND_WINDOW_DAYS: i64 = 180;
+
+    /// Actions the API should accept for this payment, so UIs can enable only the buttons that will work.
+    ///
+    /// It is derived from `status`, `captured`, `payment_type_id` and the refunded amount. With the `chrono` feature, the capture and refund windows are also checked against the payment dates.
+    ///
+    /// It is a best effort check, the API still has the final word.
+    pub fn available_actions(&self) -> HashSet<PaymentAction> {
+        self.available_actions_with(older_than)
+    }
+
+    /// Merchant order of the payment, for payments created from a preference.
+    ///
+    /// `None` when the payment has no order or when the order is a Mercado Libre one, which is not a merchant order.
+    pub fn merchant_order_id(&self) -> Option<MerchantOrderId> {
+        match self.order.r#type.as_deref() {
+            Some("mercadopago") => self.order.id.map(MerchantOrderId),
+            _ => None,
+        }
+    }
+
+    /// Pix "copia e cola" code, also the content of the QR, for pending Pix payments.
+    pub fn pix_qr_code(&self) -> Option<&str> {
+        self.transaction_data()?.qr_code.as_deref()
+    }
+
+    /// PNG of the Pix QR, decoded from `qr_code_base64`, to serve it as an image.
+    pub fn pix_qr_code_png_bytes(&self) -> Option<Result<Vec<u8>, base64::DecodeError>> {
+        self.transaction_data()?.qr_code_png_bytes()
+    }
+
+    /// Page of Mercado Pago with the Pix QR and the instructions to pay.
+    pub fn pix_ticket_url(&self) -> Option<&str> {
+        self.transaction_data()?.ticket_url.as_deref()
+    }
+
+    /// Page to print the boleto, for ticket payments.
+    pub fn boleto_url(&self) -> Option<&str> {
+        self.ticket_details()?.external_resource_url.as_deref()
+    }
+
+    /// Number of the boleto barcode, for ticket payments.
+    pub fn boleto_barcode(&self) -> Option<&str> {
+        Some(&self.ticket_details()?.barcode.as_ref()?.content)
+    }
+
+    /// "Linha digitável" of the boleto, for ticket payments.
+    pub fn boleto_digitable_line(&self) -> Option<&str> {
+        self.ticket_details()?.digitable_line.as_deref()
+    }
+
+    fn ticket_details(&self) -> Option<&PaymentTransactionDetails> {
+        match self.payment_type_id {
+            PaymentTypeId::Ticket => self.transaction_details.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn transaction_data(&self) -> Option<&TransactionData> {
+        self.point_of_interaction.transaction_data.as_ref()
+    }
+
+    /// Splits the amount of the payment in fees, shipping, taxes and net, from `fee_details`, `shipping_amount`, `taxes_amount` and `transaction_details`.
+    ///
+    /// `gross` is `total_paid_amount`, or `transaction_amount` plus the fees paid by the payer when there are no `transaction_details`. `net` is always `gross` minus the fees, so pending payments, where `net_received_amount` is still zero, show what will be received.
+    pub fn amount_breakdown(&self) -> PaymentAmountBreakdown {
+        let mut breakdown = PaymentAmountBreakdown {
+            shipping: self.shipping_amount,
+            taxes: self.taxes_amount,
+            ..Default::default()
+        };
+
+        let mut payer_fees = Decimal::ZERO;
+        for fee in &self.fee_details {
+            let total = match fee.r#type {
+                FeeDetailsType::MercadopagoFee => &mut breakdown.mercadopago_fee,
+                FeeDetailsType::ApplicationFee => &mut breakdown.application_fee,
+                FeeDetailsType::FinancingFee => &mut breakdown.financing_fee,
+                _ => &mut breakdown.other_fees,
+            };
+            *total += fee.amount;
+
+            if fee.fee_payer == FeePayer::Payer {
+                payer_fees += fee.amount;
+            }
+        }
+
+        breakdown.gross = match &self.transaction_details {
+            Some(details) => details.total_paid_amount,
+            None => self.transaction_amount + payer_fees,
+        };
+        breakdown.net = breakdown.gross
+            - breakdown.mercadopago_fee
+            - breakdown.application_fee
+            - breakdown.financing_fee
+            - breakdown.other_fees;
+
+        breakdown
+    }
+
+    fn available_actions_with(
+        &self,
+        older_than: impl Fn(&str, i64) -> bool,
+    ) -> HashSet<PaymentAction> {
+        let mut actions = HashSet::new();
+
+        match self.status {
+            PaymentStatus::Pending | PaymentStatus::InProcess => {
+                actions.insert(PaymentAction::Cancel);
+            }
+            PaymentStatus::Authorized => {
+                actions.insert(PaymentAction::Cancel);
+                if !self.captured && !older_than(&self.date_created, Self::CAPTURE_WINDOW_DAYS) {
+                    actions.insert(PaymentAction::Capture);
+                }
+            }
+            PaymentStatus::Approved => {
+                let refunded = self.transaction_amount_refunded.unwrap_or_default();
+                let approved_at = self.date_approved.as_deref().unwrap_or(&self.date_created);
+
+                if refunded < self.transaction_amount
+                    && !older_than(approved_at, Self::REFUND_WINDOW_DAYS)
+                {
+                    actions.insert(PaymentAction::Refund);
+                    if !matches!(
+                        self.payment_type_id,
+                        PaymentTypeId::Ticket | PaymentTypeId::Atm
+                    ) {
+                        actions.insert(PaymentAction::PartialRefund);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        actions
+    }
+}
+
+/// Checks if an [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) date is more than `days` in the past.
+///
+/// Dates that can't be parsed are never considered old, the API will reject them if they are.
+#[cfg(feature = "chrono")]
+fn older_than(date: &str, days: i64) -> bool {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .map(|date| chrono::Utc::now() - chrono::Duration::days(days) > date)
+        .unwrap_or(false)
+}
+
+#[cfg(not(feature = "chrono"))]
+fn older_than(_date: &str, _days: i64) -> bool {
+    false
 }
 
 /// Information about the application that processes the payment and receives regulatory data.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PaymentPointOfInteraction {
     pub r#type: PaymentTypeId,
     pub sub_type: Option<String>,
@@ -360,7 +929,7 @@ pub struct PaymentPointOfInteraction {
 }
 
 /// Information about the pending payment that was generated.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TransactionData {
     /// Base64 representation of the QR code image to be scanned for payment completion.
     pub qr_code_base64: Option<String>,
@@ -370,13 +939,24 @@ pub struct TransactionData {
     pub ticket_url: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl TransactionData {
+    /// Decodes `qr_code_base64` into the bytes of the PNG.
+    pub fn qr_code_png_bytes(&self) -> Option<Result<Vec<u8>, base64::DecodeError>> {
+        use base64::Engine;
+
+        self.qr_code_base64
+            .as_deref()
+            .map(|qr_code| base64::engine::general_purpose::STANDARD.decode(qr_code))
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ApplicationData {
     pub name: Option<String>,
     pub version: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentProcessingMode {
     /// The merchant will use the Mercado Pago merchant codes and will take advantage of the financial advantages that Mercado Pago offers.
@@ -385,7 +965,7 @@ pub enum PaymentProcessingMode {
     Gateway,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PaymentCard {
     pub id: Option<String>,
     /// BIN (Bank Identification Number) of the card. It's the initial set of digits in a credit card number that identifies the issuing bank or financial institution.
@@ -400,20 +980,20 @@ pub struct PaymentCard {
     pub cardholder: Option<Cardholder>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Cardholder {
     pub name: Option<String>,
     pub identification: Option<IdentificationType>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CardHolderIdentification {
     /// The number refers to the identifier of the user in question. If it's a CPF, for example, it will have 11 digits.
     pub number: Option<String>,
     pub r#type: Option<IdentificationType>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct FeeDetails {
     /// Commission detail.
     pub r#type: FeeDetailsType,
@@ -423,14 +1003,62 @@ pub struct FeeDetails {
     pub fee_payer: FeePayer,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+/// Charge applied to a payment, see [`PaymentResponse::charges_details`].
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ChargeDetail {
+    pub id: Option<String>,
+    /// Name of the charge, like `"mercadopago_fee"`.
+    pub name: Option<String>,
+    pub r#type: ChargeType,
+    pub accounts: Option<ChargeAccounts>,
+    pub amounts: ChargeAmounts,
+    /// [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) date
+    pub date_created: Option<String>,
+    /// [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) date
+    pub last_updated: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Kind of a [`ChargeDetail`].
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChargeType {
+    Fee,
+    Tax,
+    Coupon,
+    Discount,
+    Shipping,
+    /// For untracked charge types
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Accounts of a [`ChargeDetail`], like `"collector"` paying `"mp"`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ChargeAccounts {
+    pub from: String,
+    pub to: String,
+}
+
+/// Amounts of a [`ChargeDetail`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct ChargeAmounts {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub original: Decimal,
+    /// Part of the charge returned by refunds.
+    #[serde(default, with = "rust_decimal::serde::float")]
+    pub refunded: Decimal,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FeePayer {
     Collector,
     Payer,
 }
 
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FeeDetailsType {
     MercadopagoFee,
@@ -443,7 +1071,7 @@ pub enum FeeDetailsType {
     Unknown(String),
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PaymentTransactionDetails {
     /// Unique identifier for the payment method.
     pub payment_method_reference_id: Option<String>,
@@ -453,15 +1081,28 @@ pub struct PaymentTransactionDetails {
     pub total_paid_amount: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub overpaid_amount: Decimal,
+    /// Page to print the boleto, or of the instructions to pay other tickets.
     pub external_resource_url: Option<String>,
     #[serde(with = "rust_decimal::serde::float")]
     pub installment_amount: Decimal,
     pub financial_institution: Option<String>,
     pub payable_deferral_period: Option<String>,
     pub acquirer_reference: Option<String>,
+    /// Barcode of the boleto.
+    pub barcode: Option<Barcode>,
+    /// "Linha digitável" of the boleto, the barcode number typed by the payer in the bank app.
+    pub digitable_line: Option<String>,
+    pub verification_code: Option<String>,
+}
+
+/// Barcode of a ticket payment
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Barcode {
+    /// Number encoded in the barcode.
+    pub content: String,
 }
 
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 pub enum CurrencyId {
     ARS,
     BRL,
@@ -523,13 +1164,15 @@ impl TryFrom<CurrencyId> for Currency {
 }
 
 /// Detail of the outcome of the collection.
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentStatusDetail {
     Accredited,
     PendingContingency,
     PendingWaitingTransfer,
     PendingReviewManual,
+    /// Waiting for the buyer to complete the 3DS challenge, see [`PaymentResponse::three_ds_info`].
+    PendingChallenge,
     CcRejectedBadFilledDate,
     CcRejectedBadFilledOther,
     CcRejectedBadFilledSecurityCode,
@@ -547,9 +1190,8 @@ pub enum PaymentStatusDetail {
     Unknown(String),
 }
 
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
-
 pub enum PaymentStatus {
     /// The user has not completed the payment process (for example, for generating a payment via boleto, it will be considered completed when the user makes the corresponding payment).
     Pending,
@@ -575,7 +1217,7 @@ pub enum PaymentStatus {
 }
 
 /// It is the type of payment method (credit card, bank transfer, boleto, ATM, etc.).
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentTypeId {
     /// Money in the Mercado Pago account.
@@ -606,7 +1248,7 @@ pub enum PaymentTypeId {
 }
 
 /// Is the operation type
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum OperationType {
     /// When money is put into an investment, such as CDB, in the Mercado Pago application.
@@ -635,7 +1277,7 @@ pub enum OperationType {
 /// Payment method ID. Indicates the ID of the selected payment method for making the payment.
 ///
 /// Check <https://www.mercadopago.com.br/developers/pt/reference/payment_methods/_payment_methods/get>
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Default, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Default, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum PaymentMethodId {
     #[default]
@@ -664,7 +1306,7 @@ pub enum PaymentMethodId {
     Unknown(String),
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct AdditionalInfo {
     /// Internal protocol (IP) originating from the request (only for bank transfers).
     pub ip_address: Option<String>,
@@ -677,7 +1319,7 @@ pub struct AdditionalInfo {
     pub shipments: Option<Shipments>,
 }
 
-#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, PartialEq, Eq)]
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
 pub enum IdentificationType {
     CPF,
     CNPJ,
@@ -710,7 +1352,7 @@ pub struct ProductItem {
     pub unit_price: Option<Decimal>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ReceiverAddress {
     /// Payer's postal code (ZIP code).
     pub zip_code: String,
@@ -726,7 +1368,7 @@ pub struct ReceiverAddress {
     pub apartment: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PhoneNumber {
     /// Area code where the payer resides.
     pub area_code: String,
@@ -734,12 +1376,727 @@ pub struct PhoneNumber {
     pub number: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl PhoneNumber {
+    /// Parses a phone number typed by the payer, splitting the area code from the number
+    ///
+    /// Formatting (spaces, `-`, `.`, parentheses), the country calling code and the trunk prefix `0` are removed, then the length is checked with the rules of `country`.
+    ///
+    /// # Example
+    /// ```
+    /// use mpago::payments::types::{PhoneCountry, PhoneNumber};
+    ///
+    /// let phone = PhoneNumber::parse("+55 (11) 98765-4321", PhoneCountry::Brazil)?;
+    ///
+    /// assert_eq!(phone.area_code, "11");
+    /// assert_eq!(phone.number, "987654321");
+    /// ```
+    pub fn parse(phone: &str, country: PhoneCountry) -> Result<Self, PhoneNumberError> {
+        if let Some(c) = phone
+            .chars()
+            .find(|c| !(c.is_ascii_digit() || matches!(c, ' ' | '-' | '.' | '(' | ')' | '+')))
+        {
+            return Err(PhoneNumberError::InvalidCharacter(c));
+        }
+
+        let digits: String = phone.chars().filter(char::is_ascii_digit).collect();
+        let national = country.national_number(&digits);
+
+        if !country.national_lengths().contains(&national.len()) {
+            return Err(PhoneNumberError::InvalidLength {
+                country,
+                len: national.len(),
+            });
+        }
+
+        let (area_code, number) = national.split_at(country.area_code_len(national));
+
+        Ok(PhoneNumber {
+            area_code: area_code.to_string(),
+            number: number.to_string(),
+        })
+    }
+}
+
+/// Country rules used by [`PhoneNumber::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneCountry {
+    Argentina,
+    Brazil,
+    Chile,
+    Colombia,
+    Mexico,
+}
+
+impl PhoneCountry {
+    fn calling_code(self) -> &'static str {
+        match self {
+            PhoneCountry::Argentina => "54",
+            PhoneCountry::Brazil => "55",
+            PhoneCountry::Chile => "56",
+            PhoneCountry::Colombia => "57",
+            PhoneCountry::Mexico => "52",
+        }
+    }
+
+    /// Accepted lengths of the area code plus the number.
+    fn national_lengths(self) -> &'static [usize] {
+        match self {
+            PhoneCountry::Brazil => &[10, 11],
+            PhoneCountry::Chile => &[9],
+            PhoneCountry::Argentina | PhoneCountry::Colombia | PhoneCountry::Mexico => &[10],
+        }
+    }
+
+    /// Removes the calling code and the trunk prefix from `digits`.
+    fn national_number(self, digits: &str) -> &str {
+        let lengths = self.national_lengths();
+
+        if let Some(rest) = digits.strip_prefix(self.calling_code()) {
+            // Argentinian mobiles have a `9` and old mexican mobiles a `1` after the calling code.
+            let rest = match (self, rest.len()) {
+                (PhoneCountry::Argentina, 11) => rest.strip_prefix('9').unwrap_or(rest),
+                (PhoneCountry::Mexico, 11) => rest.strip_prefix('1').unwrap_or(rest),
+                _ => rest,
+            };
+            if lengths.contains(&rest.len()) {
+                return rest;
+            }
+        }
+
+        match digits.strip_prefix('0') {
+            Some(rest) if lengths.contains(&rest.len()) => rest,
+            _ => digits,
+        }
+    }
+
+    /// Length of the area code at the start of `national`.
+    fn area_code_len(self, national: &str) -> usize {
+        match self {
+            PhoneCountry::Brazil => 2,
+            PhoneCountry::Colombia => 3,
+            // Buenos Aires has a 2 digits area code, most other cities 3.
+            PhoneCountry::Argentina if national.starts_with("11") => 2,
+            PhoneCountry::Argentina => 3,
+            // Mexico City, Guadalajara and Monterrey have 2 digits area codes.
+            PhoneCountry::Mexico if ["55", "33", "81"].iter().any(|a| national.starts_with(a)) => 2,
+            PhoneCountry::Mexico => 3,
+            // Santiago and mobiles have a 1 digit area code.
+            PhoneCountry::Chile if national.starts_with(['2', '9']) => 1,
+            PhoneCountry::Chile => 2,
+        }
+    }
+}
+
+/// Error when parsing a [`PhoneNumber`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PhoneNumberError {
+    #[error("Phone number has an invalid character: {0:?}")]
+    InvalidCharacter(char),
+    #[error("Phone number has {len} digits, invalid for {country:?}")]
+    InvalidLength { country: PhoneCountry, len: usize },
+}
+
+/// Shipping of the purchase, shared by payments ([`AdditionalInfo::shipments`]) and preferences.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct Shipments {
+    /// Who handles the shipping.
+    pub mode: Option<ShipmentMode>,
+    /// Shipping cost, charged together with the items.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost: Option<Decimal>,
+    pub free_shipping: Option<bool>,
+    /// The buyer picks up the purchase at the store.
+    pub local_pickup: Option<bool>,
+    /// Package dimensions as `"{height}x{width}x{length},{weight}"`, in centimeters and grams, like `"30x30x30,500"`. Required by [`ShipmentMode::Me2`].
+    pub dimensions: Option<String>,
     /// Object that comprises the shipping address of the purchase recipient.
-    pub receiver_address: ReceiverAddress,
-    /// Barcode width.
-    pub width: u32,
-    /// Barcode height.
-    pub height: u32,
+    pub receiver_address: Option<ReceiverAddress>,
+}
+
+/// Who handles the shipping of a purchase.
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShipmentMode {
+    /// Mercado Envios, the shipping is calculated and handled by Mercado Pago.
+    Me2,
+    NotSpecified,
+    /// Shipping handled by the seller, with the `cost` informed.
+    Custom,
+    /// For untracked shipment modes
+    #[serde(other)]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{
+        ChargeType, FeeDetails, FeeDetailsType, FeePayer, OperationType, PartialPaymentResult,
+        PaymentAction, PaymentCreateOptions, PaymentMethodId, PaymentResponse,
+        PaymentSearchOptions, PaymentStatus, PaymentStatusDetail, PaymentSummary,
+        PaymentSummaryKey, PaymentTotals, PaymentTypeId, PhoneCountry, PhoneNumber,
+        PhoneNumberError, SearchDate, ShipmentMode, Shipments,
+    };
+    use crate::fixtures;
+    use rust_decimal::{prelude::ToPrimitive, Decimal};
+    use std::collections::HashSet;
+
+    #[test]
+    fn parse_refunds_and_charges() {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        body["refunds"] = serde_json::json!([{
+            "id": 1258904837,
+            "payment_id": 87891224,
+            "amount": 40.0,
+            "date_created": "2024-05-03T09:00:00.000-04:00",
+            "status": "approved",
+            "source": { "id": "241983636", "name": "Loja", "type": "collector" },
+            "refund_mode": "standard",
+            "unique_sequence_number": null
+        }]);
+        body["charges_details"] = serde_json::json!([{
+            "id": "87891224-001",
+            "name": "mercadopago_fee",
+            "type": "fee",
+            "accounts": { "from": "collector", "to": "mp" },
+            "client_id": 0,
+            "date_created": "2024-05-02T13:10:26.000-04:00",
+            "last_updated": "2024-05-02T13:10:26.000-04:00",
+            "amounts": { "original": 0.99, "refunded": 0.4 },
+            "metadata": {},
+            "refund_charges": [],
+            "reserve_id": null
+        }]);
+
+        let payment = serde_json::from_value::<PaymentResponse>(body).unwrap();
+
+        assert_eq!(payment.refunds[0].amount, Decimal::new(40, 0));
+        assert_eq!(payment.charges_details[0].r#type, ChargeType::Fee);
+        assert_eq!(
+            payment.charges_details[0].amounts.refunded,
+            Decimal::new(4, 1)
+        );
+
+        let payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        assert!(payment.refunds.is_empty());
+    }
+
+    #[test]
+    fn pix_helpers() {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        body["point_of_interaction"]["transaction_data"]["qr_code_base64"] = "iVBORw0KGgo=".into();
+        let payment = serde_json::from_value::<PaymentResponse>(body).unwrap();
+
+        assert!(payment.pix_qr_code().unwrap().starts_with("000201"));
+        assert!(payment.pix_ticket_url().is_some());
+        assert_eq!(
+            &payment.pix_qr_code_png_bytes().unwrap().unwrap()[..4],
+            b"\x89PNG"
+        );
+    }
+
+    #[test]
+    fn search_dates() {
+        let options = PaymentSearchOptions {
+            begin_date: Some(SearchDate::days_ago(7)),
+            end_date: Some(SearchDate::NOW),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["begin_date"], "NOW-7DAYS");
+        assert_eq!(json["end_date"], "NOW");
+
+        assert_eq!(SearchDate::months_ago(3).to_string(), "NOW-3MONTHS");
+        assert_eq!(
+            SearchDate::Relative { days: 5, months: 1 }.to_string(),
+            "NOW-35DAYS"
+        );
+
+        let absolute = SearchDate::Absolute(
+            std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_668_692_272),
+        );
+        assert_eq!(absolute.to_string(), "2022-11-17T13:37:52.000+00:00");
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            "2022-11-17T09:37:52.000-04:00".parse::<SearchDate>(),
+            Ok(absolute)
+        );
+        assert_eq!(
+            "NOW-3MONTHS".parse::<SearchDate>(),
+            Ok(SearchDate::months_ago(3))
+        );
+
+        assert!("NOW-3WEEKS".parse::<SearchDate>().is_err());
+        assert!("17/11/2022".parse::<SearchDate>().is_err());
+        assert!("2022-11-17Té0000".parse::<SearchDate>().is_err());
+        assert!("NOW-é".parse::<SearchDate>().is_err());
+    }
+
+    #[test]
+    fn risk_info_only_when_analyzed() {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+
+        let payment = serde_json::from_value::<PaymentResponse>(body.clone()).unwrap();
+        assert_eq!(payment.risk_info, None);
+
+        body["risk_execution_id"] = serde_json::Value::Null;
+        let payment = serde_json::from_value::<PaymentResponse>(body.clone()).unwrap();
+        assert_eq!(payment.risk_info, None);
+
+        body["risk_execution_id"] = 8429341052_u64.into();
+        body["risk_tags"] = serde_json::json!(["new_device", "high_amount"]);
+        let payment = serde_json::from_value::<PaymentResponse>(body.clone()).unwrap();
+        let risk_info = payment.risk_info.unwrap();
+        assert_eq!(risk_info.risk_execution_id, Some(8429341052));
+        assert_eq!(
+            risk_info.scoring["risk_tags"],
+            serde_json::json!(["new_device", "high_amount"])
+        );
+
+        let serialized = serde_json::to_value(&risk_info).unwrap();
+        assert_eq!(serialized["risk_execution_id"], 8429341052_u64);
+        assert_eq!(serialized["risk_tags"], body["risk_tags"]);
+    }
+
+    #[test]
+    fn unexpected_risk_fields_do_not_fail_the_payment() {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+
+        body["risk_execution_id"] = " 8429341052 ".into();
+        let payment = serde_json::from_value::<PaymentResponse>(body.clone()).unwrap();
+        assert_eq!(
+            payment.risk_info.unwrap().risk_execution_id,
+            Some(8429341052)
+        );
+
+        body["risk_execution_id"] = "not a number".into();
+        body["risk_score"] = serde_json::json!({ "value": 0.12 });
+        let payment = serde_json::from_value::<PaymentResponse>(body).unwrap();
+        let risk_info = payment.risk_info.unwrap();
+        assert_eq!(risk_info.risk_execution_id, None);
+        assert_eq!(risk_info.scoring["risk_score"]["value"], 0.12);
+    }
+
+    #[test]
+    fn external_reference_is_not_restricted() {
+        let reference = format!("pedido 1234.5:{}", "x".repeat(100));
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        body["external_reference"] = reference.clone().into();
+
+        let payment = serde_json::from_value::<PaymentResponse>(body).unwrap();
+
+        assert_eq!(payment.external_reference, Some(reference));
+    }
+
+    #[test]
+    fn boleto_helpers() {
+        let payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_PENDING_BOLETO).unwrap();
+
+        assert!(payment
+            .boleto_url()
+            .unwrap()
+            .ends_with("caller_id=1822339428"));
+        assert_eq!(payment.boleto_barcode().unwrap().len(), 44);
+        assert_eq!(payment.boleto_digitable_line().unwrap().len(), 47);
+
+        let payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        assert!(payment.boleto_url().is_none());
+    }
+
+    #[test]
+    fn typed_metadata() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct OrderMetadata {
+            order_id: u64,
+        }
+
+        let options =
+            PaymentCreateOptions::default().with_metadata(OrderMetadata { order_id: 4711 });
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body["metadata"], serde_json::json!({ "order_id": 4711 }));
+
+        let body = serde_json::to_value(PaymentCreateOptions::default()).unwrap();
+        assert!(body.get("metadata").is_none());
+
+        let mut response =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        response["metadata"] = serde_json::json!({ "order_id": 4711 });
+        let payment = serde_json::from_value::<PaymentResponse<OrderMetadata>>(response).unwrap();
+        assert_eq!(payment.metadata, OrderMetadata { order_id: 4711 });
+    }
+
+    #[test]
+    fn parse_three_ds_challenge() {
+        let mut body =
+            serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        body["status"] = "pending".into();
+        body["status_detail"] = "pending_challenge".into();
+        body["three_ds_info"] = serde_json::json!({
+            "external_resource_url": "https://acs-public.tp.mastercard.com/api/v1/browser_challenges",
+            "creq": "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6ImJmYTVhZjI0In0"
+        });
+
+        let payment = serde_json::from_value::<PaymentResponse>(body).unwrap();
+
+        assert_eq!(
+            payment.status_detail,
+            Some(PaymentStatusDetail::PendingChallenge)
+        );
+        assert_eq!(
+            payment.three_ds_info.unwrap().creq,
+            "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6ImJmYTVhZjI0In0"
+        );
+    }
+
+    #[test]
+    fn amount_breakdown() {
+        let mut payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+
+        let breakdown = payment.amount_breakdown();
+        assert_eq!(breakdown.gross, Decimal::new(100, 0));
+        assert_eq!(breakdown.mercadopago_fee, Decimal::new(99, 2));
+        assert_eq!(breakdown.net, Decimal::new(9901, 2));
+        assert_eq!(
+            breakdown.net,
+            payment
+                .transaction_details
+                .as_ref()
+                .unwrap()
+                .net_received_amount
+        );
+
+        payment.transaction_details = None;
+        payment.fee_details.push(FeeDetails {
+            r#type: FeeDetailsType::FinancingFee,
+            amount: Decimal::new(1050, 2),
+            fee_payer: FeePayer::Payer,
+        });
+
+        let breakdown = payment.amount_breakdown();
+        assert_eq!(breakdown.gross, Decimal::new(11050, 2));
+        assert_eq!(breakdown.financing_fee, Decimal::new(1050, 2));
+        assert_eq!(breakdown.net, Decimal::new(9901, 2));
+    }
+
+    #[test]
+    fn summary_by_day_status_and_method() {
+        let mut payment =
+            serde_json::from_str::<PartialPaymentResult>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        let mut summary = PaymentSummary::default();
+
+        summary.add(&payment);
+        summary.add(&payment);
+        payment.status = PaymentStatus::Refunded;
+        summary.add(&payment);
+
+        let key = PaymentSummaryKey {
+            date: payment.date_created.chars().take(10).collect(),
+            status: PaymentStatus::Approved,
+            payment_method_id: payment.payment_method_id.clone(),
+        };
+        assert_eq!(summary.totals.len(), 2);
+        assert_eq!(
+            summary.totals[&key],
+            PaymentTotals {
+                count: 2,
+                transaction_amount: payment.transaction_amount * Decimal::TWO,
+            }
+        );
+    }
+
+    #[test]
+    fn shipments() {
+        let shipments: Shipments = serde_json::from_value(serde_json::json!({
+            "mode": "me2",
+            "cost": 19.9,
+            "free_shipping": false,
+            "dimensions": "30x30x30,500",
+        }))
+        .unwrap();
+
+        assert_eq!(shipments.mode, Some(ShipmentMode::Me2));
+        assert_eq!(shipments.cost, Some(Decimal::new(199, 1)));
+
+        let custom = Shipments {
+            mode: Some(ShipmentMode::Custom),
+            cost: Some(Decimal::new(10, 0)),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(custom).unwrap(),
+            serde_json::json!({ "mode": "custom", "cost": 10.0 })
+        );
+    }
+
+    #[test]
+    fn phone_numbers_are_normalized() {
+        let parse = |phone, country| {
+            PhoneNumber::parse(phone, country).map(|phone| (phone.area_code, phone.number))
+        };
+        let split = |area: &str, number: &str| Ok((area.to_string(), number.to_string()));
+
+        assert_eq!(
+            parse("+55 (11) 98765-4321", PhoneCountry::Brazil),
+            split("11", "987654321")
+        );
+        assert_eq!(
+            parse("(021) 3456-7890", PhoneCountry::Brazil),
+            split("21", "34567890")
+        );
+        assert_eq!(
+            parse("+54 9 11 2345-6789", PhoneCountry::Argentina),
+            split("11", "23456789")
+        );
+        assert_eq!(
+            parse("0351 423-4567", PhoneCountry::Argentina),
+            split("351", "4234567")
+        );
+        assert_eq!(
+            parse("+52 1 55 1234 5678", PhoneCountry::Mexico),
+            split("55", "12345678")
+        );
+        assert_eq!(
+            parse("+56 9 8765 4321", PhoneCountry::Chile),
+            split("9", "87654321")
+        );
+        assert_eq!(
+            parse("300.123.4567", PhoneCountry::Colombia),
+            split("300", "1234567")
+        );
+
+        assert_eq!(
+            parse("11 9876-543", PhoneCountry::Brazil),
+            Err(PhoneNumberError::InvalidLength {
+                country: PhoneCountry::Brazil,
+                len: 9
+            })
+        );
+        assert_eq!(
+            parse("11 98765-432l", PhoneCountry::Brazil),
+            Err(PhoneNumberError::InvalidCharacter('l'))
+        );
+    }
+
+    fn payment(status: &str, payment_type_id: &str, captured: bool) -> PaymentResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "date_created": "2024-01-01T10:00:00.000-04:00",
+            "date_approved": "2024-01-01T10:00:00.000-04:00",
+            "date_of_expiration": "2024-01-02T10:00:00.000-04:00",
+            "operation_type": "regular_payment",
+            "payment_method_id": "pix",
+            "payment_type_id": payment_type_id,
+            "status": status,
+            "live_mode": false,
+            "taxes_amount": 0,
+            "shipping_amount": 0,
+            "collector_id": 1,
+            "payer": { "email": "test@testmail.uk" },
+            "additional_info": {},
+            "transaction_amount": 100,
+            "transaction_amount_refunded": 0,
+            "coupon_amount": 0,
+            "fee_details": [],
+            "captured": captured,
+            "binary_mode": false,
+            "installments": 1,
+            "processing_mode": "aggregator",
+            "point_of_interaction": { "type": payment_type_id },
+            "metadata": {}
+        }))
+        .unwrap()
+    }
+
+    fn actions(payment: &PaymentResponse, expired: bool) -> HashSet<PaymentAction> {
+        payment.available_actions_with(|_, _| expired)
+    }
+
+    #[test]
+    fn available_actions_by_status() {
+        use PaymentAction::*;
+
+        assert_eq!(
+            actions(&payment("pending", "ticket", false), false),
+            HashSet::from([Cancel])
+        );
+        assert_eq!(
+            actions(&payment("authorized", "credit_card", false), false),
+            HashSet::from([Cancel, Capture])
+        );
+        assert_eq!(
+            actions(&payment("approved", "credit_card", true), false),
+            HashSet::from([Refund, PartialRefund])
+        );
+        assert_eq!(
+            actions(&payment("approved", "ticket", true), false),
+            HashSet::from([Refund])
+        );
+        assert!(actions(&payment("rejected", "credit_card", false), false).is_empty());
+        assert!(actions(&payment("refunded", "credit_card", true), false).is_empty());
+    }
+
+    #[test]
+    fn available_actions_respect_windows_and_refunds() {
+        use PaymentAction::*;
+
+        assert_eq!(
+            actions(&payment("authorized", "credit_card", false), true),
+            HashSet::from([Cancel])
+        );
+        assert!(actions(&payment("approved", "credit_card", true), true).is_empty());
+
+        let mut refunded = payment("approved", "credit_card", true);
+        refunded.transaction_amount_refunded = Some(Decimal::new(100, 0));
+        assert!(actions(&refunded, false).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn old_payments_are_not_refundable() {
+        assert!(payment("approved", "credit_card", true)
+            .available_actions()
+            .is_empty());
+    }
+
+    const KNOWN_STATUS: [&str; 9] = [
+        "pending",
+        "approved",
+        "authorized",
+        "in_process",
+        "in_mediation",
+        "rejected",
+        "cancelled",
+        "refunded",
+        "charged_back",
+    ];
+
+    #[test]
+    fn known_status_roundtrip() {
+        for status in KNOWN_STATUS {
+            let json = format!("{status:?}");
+            let parsed = serde_json::from_str::<PaymentStatus>(&json).unwrap();
+
+            assert!(!matches!(parsed, PaymentStatus::Unknown(_)));
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn untracked_status_falls_back_to_unknown(value in "[a-z_]{1,32}") {
+            prop_assume!(!KNOWN_STATUS.contains(&value.as_str()));
+
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed = serde_json::from_str::<PaymentStatus>(&json).unwrap();
+
+            prop_assert_eq!(&parsed, &PaymentStatus::Unknown(value));
+            prop_assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+
+        #[test]
+        fn enums_with_fallback_accept_any_string(value in "\\PC*") {
+            let json = serde_json::to_string(&value).unwrap();
+
+            prop_assert!(serde_json::from_str::<PaymentStatus>(&json).is_ok());
+            prop_assert!(serde_json::from_str::<PaymentStatusDetail>(&json).is_ok());
+            prop_assert!(serde_json::from_str::<PaymentTypeId>(&json).is_ok());
+            prop_assert!(serde_json::from_str::<PaymentMethodId>(&json).is_ok());
+            prop_assert!(serde_json::from_str::<OperationType>(&json).is_ok());
+        }
+
+        #[test]
+        fn amounts_from_any_number(amount in -1e12f64..1e12) {
+            let mut json =
+                serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+            for field in AMOUNT_FIELDS {
+                json[field] = amount.into();
+            }
+
+            let payment = serde_json::from_value::<PaymentResponse>(json).unwrap();
+            let amounts = [
+                Some(payment.transaction_amount),
+                Some(payment.taxes_amount),
+                Some(payment.shipping_amount),
+                payment.transaction_amount_refunded,
+                payment.coupon_amount,
+            ];
+            for parsed in amounts {
+                let parsed = parsed.unwrap().to_f64().unwrap();
+                prop_assert!((parsed - amount).abs() <= amount.abs().max(1.0) * 1e-9);
+            }
+        }
+
+        #[test]
+        fn amounts_never_panic(value in any_json()) {
+            let mut json =
+                serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+            for field in AMOUNT_FIELDS {
+                json[field] = value.clone();
+            }
+
+            let _ = serde_json::from_value::<PaymentResponse>(json);
+        }
+
+        #[test]
+        fn amount_fallbacks(value in any_json()) {
+            let mut json =
+                serde_json::from_str::<serde_json::Value>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+            json["transaction_amount_refunded"] = serde_json::Value::Null;
+            json["coupon_amount"] = serde_json::Value::Null;
+            json["transaction_amount"] = value.clone();
+
+            // A null optional amount is `None`, a required amount that isn't a number in the range of `Decimal` fails to decode instead of defaulting to zero.
+            match serde_json::from_value::<PaymentResponse>(json) {
+                Ok(payment) => {
+                    prop_assert!(value.is_number() || value.is_string());
+                    prop_assert_eq!(payment.transaction_amount_refunded, None);
+                    prop_assert_eq!(payment.coupon_amount, None);
+                }
+                Err(_) => {
+                    prop_assert!(!value.as_f64().is_some_and(|amount| amount.abs() <= 1e28))
+                }
+            }
+        }
+    }
+
+    /// Fields of [`PaymentResponse`] deserialized with `rust_decimal::serde::float(_option)`.
+    const AMOUNT_FIELDS: [&str; 5] = [
+        "transaction_amount",
+        "taxes_amount",
+        "shipping_amount",
+        "transaction_amount_refunded",
+        "coupon_amount",
+    ];
+
+    /// Any JSON value: numbers of every kind, numeric strings and junk.
+    fn any_json() -> impl Strategy<Value = serde_json::Value> {
+        let leaf = prop_oneof![
+            Just(serde_json::Value::Null),
+            any::<bool>().prop_map(serde_json::Value::from),
+            any::<u64>().prop_map(serde_json::Value::from),
+            any::<i64>().prop_map(serde_json::Value::from),
+            any::<f64>().prop_map(serde_json::Value::from),
+            "-?[0-9]{1,30}(\\.[0-9]{0,10})?".prop_map(serde_json::Value::from),
+            "\\PC*".prop_map(serde_json::Value::from),
+        ];
+
+        leaf.prop_recursive(2, 8, 4, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..4).prop_map(serde_json::Value::from),
+                prop::collection::hash_map("[a-z_]{1,8}", inner, 0..4)
+                    .prop_map(|map| serde_json::Value::Object(map.into_iter().collect())),
+            ]
+        })
+    }
 }
@@ -1,8 +1,12 @@
+use std::future::Future;
+
 use reqwest::Method;
+use rust_decimal::Decimal;
 
 use crate::{
-    client::MercadoPagoClient,
-    common::{resolve_json, MercadoPagoRequestError},
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
 };
 
 use super::types::{PartialPaymentResult, PaymentResponse, PaymentStatus, PaymentUpdateOptions};
@@ -13,93 +17,206 @@ use super::types::{PartialPaymentResult, PaymentResponse, PaymentStatus, Payment
 ///
 /// * `id` - Unique payment identifier, automatically generated by Mercado Pago.
 /// * `options` - Options to update payment.
+/// * `idempotency_key` - Idempotency key is a unique value that is used to prevent duplicate processing of requests, so retrying an update or a cancellation is safe.
 ///
 /// # Example
 /// ```
 /// use mpago::payments::PaymentUpdateBuilder;
 ///
 /// PaymentUpdateBuilder {
-///     id: 8972364,
+///     id: 8972364.into(),
 ///     options: PaymentUpdateOptions {
 ///         status: Ok(PaymentStatus::Cancelled),
 ///         ..Default::default()
-///     }
+///     },
+///     idempotency_key: Some("cancel-8972364".to_string()),
 /// }
 /// ```
 ///
 /// # Docs
 /// <https://www.mercadopago.com.br/developers/pt/reference/payments/_payments_id/put>
+#[derive(Debug, Clone)]
 pub struct PaymentUpdateBuilder {
-    pub id: u64,
+    pub id: PaymentId,
     pub options: PaymentUpdateOptions,
+    pub idempotency_key: Option<String>,
 }
 
 impl PaymentUpdateBuilder {
+    /// Sets `date_of_expiration`
+    #[cfg(feature = "chrono")]
+    pub fn expires_at<Tz>(mut self, date: chrono::DateTime<Tz>) -> Self
+    where
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        self.options.date_of_expiration = Some(crate::common::format_date(&date));
+
+        self
+    }
+
     /// Send the request
     pub async fn send(
         self,
         mp_client: &MercadoPagoClient,
     ) -> Result<PaymentResponse, MercadoPagoRequestError> {
-        let res = mp_client
-            .start_request(Method::PUT, format!("/v1/payments/{}", self.id))
-            .json(&self.options)
-            .send()
-            .await?;
+        let mut req = mp_client.json_body(
+            mp_client.start_request(Method::PUT, format!("/v1/payments/{}", self.id)),
+            &self.options,
+        )?;
 
-        resolve_json::<PaymentResponse>(res).await
+        if let Some(idempotency_key) = self.idempotency_key {
+            req = req.header("X-Idempotency-Key", idempotency_key);
+        }
+
+        let res = mp_client.execute(req).await?;
+
+        mp_client.resolve_json::<PaymentResponse>(res).await
     }
 
     /// Send a request to cancel the payment
+    ///
+    /// Only the status is sent, `options` is ignored. The `idempotency_key` of the builder is kept.
     pub async fn cancel_payment(
         self,
         mp_client: &MercadoPagoClient,
     ) -> Result<PaymentResponse, MercadoPagoRequestError> {
-        let res = mp_client
-            .start_request(Method::PUT, format!("/v1/payments/{}", self.id))
-            .json(&PaymentUpdateOptions {
+        Self::cancel(self.id, self.idempotency_key)
+            .send(mp_client)
+            .await
+    }
+
+    /// Builder that cancels the payment `id`.
+    fn cancel(id: PaymentId, idempotency_key: Option<String>) -> Self {
+        PaymentUpdateBuilder {
+            id,
+            options: PaymentUpdateOptions {
                 status: Some(PaymentStatus::Cancelled),
                 ..Default::default()
-            })
-            .send()
-            .await?;
+            },
+            idempotency_key,
+        }
+    }
 
-        resolve_json::<PaymentResponse>(res).await
+    /// Builder that captures `amount` of the authorized payment `id`, or all of it if `None`.
+    fn capture(id: PaymentId, amount: Option<Decimal>, idempotency_key: Option<String>) -> Self {
+        PaymentUpdateBuilder {
+            id,
+            options: PaymentUpdateOptions {
+                capture: Some(true),
+                transaction_amount: amount,
+                ..Default::default()
+            },
+            idempotency_key,
+        }
+    }
+}
+
+impl SendRequest for PaymentUpdateBuilder {
+    type Output = PaymentResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PaymentUpdateBuilder::send(self, mp_client)
     }
 }
 
 impl PaymentResponse {
     /// Send a request to cancel the payment
+    ///
+    /// # Arguments
+    ///
+    /// * `idempotency_key` - Same as in [`PaymentUpdateBuilder`], makes retrying the cancellation safe.
     pub async fn cancel_payment(
         self,
         mp_client: &MercadoPagoClient,
+        idempotency_key: Option<String>,
     ) -> Result<PaymentResponse, MercadoPagoRequestError> {
-        PaymentUpdateBuilder {
-            id: self.id,
-            options: PaymentUpdateOptions {
-                status: Some(PaymentStatus::Cancelled),
-                ..Default::default()
-            },
-        }
-        .send(mp_client)
-        .await
+        PaymentUpdateBuilder::cancel(self.id, idempotency_key)
+            .send(mp_client)
+            .await
+    }
+
+    /// Send a request to capture the full amount of a payment created with `capture: false`
+    ///
+    /// Second step of the two-step card flow. Authorizations not captured in 5 days are cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `idempotency_key` - Same as in [`PaymentUpdateBuilder`], makes retrying the capture safe.
+    ///
+    /// # Example
+    /// ```
+    /// let payment = authorized.capture(&mp_client, Some(format!("capture-{}", authorized.id))).await?;
+    /// ```
+    pub async fn capture(
+        self,
+        mp_client: &MercadoPagoClient,
+        idempotency_key: Option<String>,
+    ) -> Result<PaymentResponse, MercadoPagoRequestError> {
+        PaymentUpdateBuilder::capture(self.id, None, idempotency_key)
+            .send(mp_client)
+            .await
+    }
+
+    /// Send a request to capture only `amount` of a payment created with `capture: false`
+    ///
+    /// `amount` can't be greater than the authorized amount, the rest is released to the payer.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount to capture.
+    /// * `idempotency_key` - Same as in [`PaymentUpdateBuilder`], makes retrying the capture safe.
+    pub async fn capture_partial(
+        self,
+        mp_client: &MercadoPagoClient,
+        amount: Decimal,
+        idempotency_key: Option<String>,
+    ) -> Result<PaymentResponse, MercadoPagoRequestError> {
+        PaymentUpdateBuilder::capture(self.id, Some(amount), idempotency_key)
+            .send(mp_client)
+            .await
     }
 }
 
 impl PartialPaymentResult {
     /// Send a request to cancel the payment
+    ///
+    /// # Arguments
+    ///
+    /// * `idempotency_key` - Same as in [`PaymentUpdateBuilder`], makes retrying the cancellation safe.
     pub async fn cancel_payment(
         self,
         mp_client: &MercadoPagoClient,
+        idempotency_key: Option<String>,
     ) -> Result<PaymentResponse, MercadoPagoRequestError> {
-        PaymentUpdateBuilder {
-            id: self.id,
-            options: PaymentUpdateOptions {
-                status: Some(PaymentStatus::Cancelled),
-                ..Default::default()
-            },
-        }
-        .send(mp_client)
-        .await
+        PaymentUpdateBuilder::cancel(self.id, idempotency_key)
+            .send(mp_client)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod capture_tests {
+    use rust_decimal::Decimal;
+
+    use super::PaymentUpdateBuilder;
+
+    #[test]
+    fn capture_body() {
+        let full = PaymentUpdateBuilder::capture(1.into(), None, None);
+        assert_eq!(
+            serde_json::to_value(full.options).unwrap(),
+            serde_json::json!({ "capture": true })
+        );
+
+        let partial = PaymentUpdateBuilder::capture(1.into(), Some(Decimal::new(5050, 2)), None);
+        assert_eq!(
+            serde_json::to_value(partial.options).unwrap(),
+            serde_json::json!({ "capture": true, "transaction_amount": 50.5 })
+        );
     }
 }
 
@@ -120,7 +237,11 @@ mod tests {
             .await
             .unwrap();
 
-        let cancel_response = payment.cancel_payment(&mp_client).await.unwrap();
+        let idempotency_key = Some(format!("cancel-{}", payment.id));
+        let cancel_response = payment
+            .cancel_payment(&mp_client, idempotency_key)
+            .await
+            .unwrap();
 
         assert_eq!(cancel_response.status, PaymentStatus::Cancelled);
     }
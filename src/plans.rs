@@ -0,0 +1,10 @@
+pub use self::create_builder::PlanCreateBuilder;
+pub use self::get_builder::PlanGetBuilder;
+pub use self::search_builder::PlanSearchBuilder;
+pub use self::update_builder::PlanUpdateBuilder;
+
+mod create_builder;
+mod get_builder;
+mod search_builder;
+pub mod types;
+mod update_builder;
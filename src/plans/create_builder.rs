@@ -0,0 +1,99 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{PlanCreateOptions, PlanResponse};
+
+/// Builder for creating a subscription plan
+///
+/// # Arguments
+///
+/// * `options` - Options to create the plan.
+///
+/// # Example
+/// ```
+/// use mpago::{plans::PlanCreateBuilder, Decimal};
+///
+/// PlanCreateBuilder(PlanCreateOptions {
+///     reason: "Monthly plan".to_string(),
+///     auto_recurring: AutoRecurring {
+///         billing_day: Some(10),
+///         billing_day_proportional: Some(true),
+///         free_trial: Some(FreeTrial {
+///             frequency: 7,
+///             frequency_type: FrequencyType::Days,
+///         }),
+///         ..AutoRecurring::monthly(Decimal::new(50, 0), CurrencyId::BRL)
+///     },
+///     payment_methods_allowed: None,
+///     back_url: "https://example.com/subscribed".to_string(),
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan/post>
+#[derive(Debug, Clone)]
+pub struct PlanCreateBuilder(pub PlanCreateOptions);
+
+impl PlanCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PlanResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/preapproval_plan"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<PlanResponse>(res).await
+    }
+}
+
+impl SendRequest for PlanCreateBuilder {
+    type Output = PlanResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PlanCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::PlanCreateBuilder;
+    use crate::{
+        common::create_test_client,
+        payments::types::CurrencyId,
+        plans::types::{AutoRecurring, PlanCreateOptions},
+    };
+
+    #[tokio::test]
+    async fn create_plan() {
+        let mp_client = create_test_client();
+
+        let plan = PlanCreateBuilder(PlanCreateOptions {
+            reason: "Test".to_string(),
+            auto_recurring: AutoRecurring::monthly(Decimal::new(10, 0), CurrencyId::BRL),
+            payment_methods_allowed: None,
+            back_url: "https://www.mercadopago.com.br".to_string(),
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        assert_eq!(plan.reason, "Test");
+    }
+}
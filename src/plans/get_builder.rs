@@ -0,0 +1,60 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PlanId,
+};
+
+use super::types::PlanResponse;
+
+/// Builder for get a subscription plan
+///
+/// # Arguments
+///
+/// * `plan_id` - Unique plan identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::plans::PlanGetBuilder;
+///
+/// PlanGetBuilder("2c938084726fca480172750000000000".into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_id/get>
+#[derive(Debug, Clone)]
+pub struct PlanGetBuilder(pub PlanId);
+
+impl PlanGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PlanResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/preapproval_plan/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<PlanResponse>(res).await
+    }
+}
+
+impl<T: Into<PlanId>> From<T> for PlanGetBuilder {
+    fn from(id: T) -> Self {
+        PlanGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for PlanGetBuilder {
+    type Output = PlanResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PlanGetBuilder::send(self, mp_client)
+    }
+}
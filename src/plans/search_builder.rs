@@ -0,0 +1,104 @@
+use futures_core::Stream;
+use std::{borrow::Borrow, pin::Pin};
+
+use super::types::{PlanResponse, PlanSearchOptions, PlanSearchResponse};
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    pagination::{collect_all, paginate, search_query, SearchPage, SearchStream},
+};
+
+/// Builder for search subscription plans
+///
+/// # Arguments
+///
+/// * `options` - Options to search for plans
+///
+/// # Example
+/// ```
+/// use mpago::plans::PlanSearchBuilder;
+///
+/// PlanSearchBuilder(
+///     PlanSearchOptions {
+///         status: Some(PlanStatus::Active),
+///         ..Default::default()
+///     }
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_search/get>
+#[derive(Debug, Clone)]
+pub struct PlanSearchBuilder(pub PlanSearchOptions);
+
+impl PlanSearchBuilder {
+    /// This function creates a stream of plans, it goes through all the pages.
+    ///
+    /// Works like [`PaymentSearchBuilder::fetch_all_streamed`](crate::payments::PaymentSearchBuilder::fetch_all_streamed).
+    pub async fn fetch_all_streamed<'a>(
+        self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<PlanResponse, MercadoPagoRequestError>> + Send + 'a>>
+    {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Same as [`fetch_all_streamed`](Self::fetch_all_streamed), but the stream owns the client.
+    pub async fn fetch_all_streamed_owned(
+        self,
+        mp_client: MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<PlanResponse, MercadoPagoRequestError>> + Send>> {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Fetches every page and collects the plans into a [`Vec`].
+    ///
+    /// Stops at the first error, or once `max_items` plans were collected.
+    pub async fn collect_all(
+        self,
+        mp_client: &MercadoPagoClient,
+        max_items: Option<usize>,
+    ) -> Result<Vec<PlanResponse>, MercadoPagoRequestError> {
+        collect_all(stream_pages(self.0, mp_client), max_items).await
+    }
+}
+
+search_query!(PlanSearchOptions);
+
+impl SearchPage for PlanSearchResponse {
+    type Item = PlanResponse;
+
+    fn into_results(self) -> (Vec<Self::Item>, usize) {
+        (self.results, self.paging.total)
+    }
+}
+
+/// Streams every result matching `options`, going through all the pages.
+fn stream_pages<'a, C>(options: PlanSearchOptions, mp_client: C) -> SearchStream<'a, PlanResponse>
+where
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    paginate::<PlanSearchResponse, _, _>("/preapproval_plan/search", options, mp_client)
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::{PlanSearchBuilder, PlanSearchOptions};
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn search_plans() {
+        let mp_client = create_test_client();
+
+        let plans = PlanSearchBuilder(PlanSearchOptions {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .collect_all(&mp_client, Some(3))
+        .await
+        .unwrap();
+
+        assert!(plans.len() <= 3);
+    }
+}
@@ -0,0 +1,178 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::ids::{PlanId, UserId};
+use crate::payments::types::{CurrencyId, Paging, PaymentMethodId, PaymentTypeId};
+
+/// # PlanCreateOptions
+/// Used as the request body for creating subscription plans
+///
+/// See [`PlanCreateBuilder`](crate::plans::PlanCreateBuilder) if you want to create plans.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PlanCreateOptions {
+    /// Description of the plan, shown to the subscriber.
+    pub reason: String,
+    pub auto_recurring: AutoRecurring,
+    /// Payment methods subscribers can use.
+    pub payment_methods_allowed: Option<PaymentMethodsAllowed>,
+    /// URL the subscriber returns to after subscribing.
+    pub back_url: String,
+}
+
+/// # PlanUpdateOptions
+/// Struct to use in [`PlanUpdateBuilder`](crate::plans::PlanUpdateBuilder)
+///
+/// Only the fields that are `Some` are updated. Existing subscriptions keep the old `auto_recurring`.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_id/put>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PlanUpdateOptions {
+    pub reason: Option<String>,
+    pub auto_recurring: Option<AutoRecurring>,
+    pub payment_methods_allowed: Option<PaymentMethodsAllowed>,
+    pub back_url: Option<String>,
+    pub status: Option<PlanStatus>,
+}
+
+/// # PlanSearchOptions
+/// Struct to use in [`PlanSearchBuilder`](crate::plans::PlanSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PlanSearchOptions {
+    /// Quantity of plans returned.
+    pub limit: Option<usize>,
+    /// Quantity of plans to skip.
+    pub offset: Option<usize>,
+    pub status: Option<PlanStatus>,
+    /// Text to search in the plans, like part of the `reason`.
+    pub q: Option<String>,
+}
+
+/// # PlanSearchResponse
+/// Used in pagination of [`PlanSearchBuilder`](crate::plans::PlanSearchBuilder)
+///
+/// Response from `/preapproval_plan/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PlanSearchResponse {
+    pub paging: Paging,
+    pub results: Vec<PlanResponse>,
+}
+
+/// # PlanResponse
+/// Subscription plan returned by `/preapproval_plan`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PlanResponse {
+    pub id: PlanId,
+    pub application_id: Option<u64>,
+    pub collector_id: UserId,
+    pub reason: String,
+    pub auto_recurring: AutoRecurring,
+    pub payment_methods_allowed: Option<PaymentMethodsAllowed>,
+    pub back_url: Option<String>,
+    /// URL of the checkout where subscribers join the plan.
+    pub init_point: Option<String>,
+    pub status: PlanStatus,
+    /// Plan create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: String,
+    /// Date when plan was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_modified: Option<String>,
+}
+
+/// Status of a subscription plan
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    Active,
+    Inactive,
+    Cancelled,
+    /// For untracked plan status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// How and how much the subscribers are charged.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AutoRecurring {
+    /// Number of `frequency_type` units between charges, like `1` month.
+    pub frequency: u32,
+    pub frequency_type: FrequencyType,
+    /// Number of charges. If `None`, charges until the subscription is cancelled.
+    pub repetitions: Option<u32>,
+    /// Day of the month of the charges, from 1 to 28. Only for monthly plans.
+    pub billing_day: Option<u8>,
+    /// Charges a proportional amount when the subscription starts before `billing_day`.
+    pub billing_day_proportional: Option<bool>,
+    /// Period without charges at the start of the subscription.
+    pub free_trial: Option<FreeTrial>,
+    /// Amount of each charge.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub transaction_amount: Option<Decimal>,
+    pub currency_id: CurrencyId,
+}
+
+impl AutoRecurring {
+    /// Charges `transaction_amount` every month, until the subscription is cancelled
+    pub fn monthly(transaction_amount: Decimal, currency_id: CurrencyId) -> Self {
+        Self {
+            frequency: 1,
+            frequency_type: FrequencyType::Months,
+            repetitions: None,
+            billing_day: None,
+            billing_day_proportional: None,
+            free_trial: None,
+            transaction_amount: Some(transaction_amount),
+            currency_id,
+        }
+    }
+}
+
+/// Unit of the charges frequency.
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FrequencyType {
+    Days,
+    Months,
+    /// For untracked frequency type
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Period without charges at the start of a subscription.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FreeTrial {
+    /// Number of `frequency_type` units of the trial.
+    pub frequency: u32,
+    pub frequency_type: FrequencyType,
+}
+
+/// Payment methods subscribers can use.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PaymentMethodsAllowed {
+    #[serde(default)]
+    pub payment_types: Vec<AllowedPaymentType>,
+    #[serde(default)]
+    pub payment_methods: Vec<AllowedPaymentMethod>,
+}
+
+/// Payment type allowed in a plan.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AllowedPaymentType {
+    pub id: PaymentTypeId,
+}
+
+/// Payment method allowed in a plan.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AllowedPaymentMethod {
+    pub id: PaymentMethodId,
+}
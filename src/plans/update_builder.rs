@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PlanId,
+};
+
+use super::types::{PlanResponse, PlanUpdateOptions};
+
+/// Builder for update a subscription plan
+///
+/// # Arguments
+///
+/// * `id` - Unique plan identifier, automatically generated by Mercado Pago.
+/// * `options` - Options to update the plan.
+///
+/// # Example
+/// ```
+/// use mpago::plans::PlanUpdateBuilder;
+///
+/// PlanUpdateBuilder {
+///     id: "2c938084726fca480172750000000000".into(),
+///     options: PlanUpdateOptions {
+///         reason: Some("Yearly plan".to_string()),
+///         ..Default::default()
+///     }
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_plan_id/put>
+#[derive(Debug, Clone)]
+pub struct PlanUpdateBuilder {
+    pub id: PlanId,
+    pub options: PlanUpdateOptions,
+}
+
+impl PlanUpdateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PlanResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::PUT, format!("/preapproval_plan/{}", self.id)),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<PlanResponse>(res).await
+    }
+}
+
+impl SendRequest for PlanUpdateBuilder {
+    type Output = PlanResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PlanUpdateBuilder::send(self, mp_client)
+    }
+}
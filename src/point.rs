@@ -0,0 +1,8 @@
+pub use self::device_list_builder::DeviceListBuilder;
+pub use self::device_mode_builder::DeviceModeBuilder;
+pub use self::payment_intent_create_builder::PaymentIntentCreateBuilder;
+
+mod device_list_builder;
+mod device_mode_builder;
+mod payment_intent_create_builder;
+pub mod types;
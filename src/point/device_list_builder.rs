@@ -0,0 +1,76 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{DeviceListOptions, DeviceListResponse};
+
+/// Builder for listing the Point terminals of the account
+///
+/// # Arguments
+///
+/// * `options` - Filters by store or point of sale, and pagination.
+///
+/// # Example
+/// ```
+/// use mpago::point::{types::DeviceListOptions, DeviceListBuilder};
+///
+/// DeviceListBuilder(DeviceListOptions {
+///     store_id: Some("1234567".to_string()),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api/_point_integration-api_devices/get>
+#[derive(Debug, Clone, Default)]
+pub struct DeviceListBuilder(pub DeviceListOptions);
+
+impl DeviceListBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<DeviceListResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(Method::GET, "/point/integration-api/devices")
+                    .query(&self.0),
+            )
+            .await?;
+
+        mp_client.resolve_json::<DeviceListResponse>(res).await
+    }
+}
+
+impl SendRequest for DeviceListBuilder {
+    type Output = DeviceListResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        DeviceListBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::DeviceListBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn list_devices() {
+        let mp_client = create_test_client();
+
+        let res = DeviceListBuilder::default().send(&mp_client).await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,94 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{DeviceModeOptions, OperatingMode};
+
+/// Builder for changing the operating mode of a Point terminal
+///
+/// The terminal only receives payment intents in [`OperatingMode::Pdv`]. It applies the new mode after a restart.
+///
+/// # Arguments
+///
+/// * `device_id` - Identifier of the terminal, like `"PAX_A910__SMARTPOS1234345545"`.
+/// * `operating_mode` - New operating mode.
+///
+/// # Example
+/// ```
+/// use mpago::point::{types::OperatingMode, DeviceModeBuilder};
+///
+/// DeviceModeBuilder {
+///     device_id: "PAX_A910__SMARTPOS1234345545".to_string(),
+///     operating_mode: OperatingMode::Pdv,
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api/_point_integration-api_devices_device-id/patch>
+#[derive(Debug, Clone)]
+pub struct DeviceModeBuilder {
+    pub device_id: String,
+    pub operating_mode: OperatingMode,
+}
+
+impl DeviceModeBuilder {
+    /// Send the request, returning the operating mode set
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<OperatingMode, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::PATCH,
+                    format!("/point/integration-api/devices/{}", self.device_id),
+                ),
+                &DeviceModeOptions {
+                    operating_mode: self.operating_mode,
+                },
+            )?)
+            .await?;
+
+        Ok(mp_client
+            .resolve_json::<DeviceModeOptions>(res)
+            .await?
+            .operating_mode)
+    }
+}
+
+impl SendRequest for DeviceModeBuilder {
+    type Output = OperatingMode;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        DeviceModeBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::DeviceModeBuilder;
+    use crate::{common::create_test_client, point::types::OperatingMode};
+
+    #[tokio::test]
+    async fn fail_with_unknown_device() {
+        let mp_client = create_test_client();
+
+        let res = DeviceModeBuilder {
+            device_id: "UNKNOWN_DEVICE".to_string(),
+            operating_mode: OperatingMode::Pdv,
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{
+    PaymentIntentAdditionalInfo, PaymentIntentCreateOptions, PaymentIntentPayment,
+    PaymentIntentResponse, PointAmount,
+};
+
+/// Builder for sending a payment intent to a Point terminal, which then asks the payer for the card
+///
+/// # Arguments
+///
+/// * `device_id` - Identifier of the terminal, like `"PAX_A910__SMARTPOS1234345545"`.
+/// * `options` - Amount, payment and printing settings of the intent.
+///
+/// # Example
+/// ```
+/// use mpago::point::{types::{PaymentIntentAdditionalInfo, PointAmount}, PaymentIntentCreateBuilder};
+///
+/// PaymentIntentCreateBuilder::new("PAX_A910__SMARTPOS1234345545", PointAmount::cents(1500))
+///     .additional_info(PaymentIntentAdditionalInfo {
+///         external_reference: Some("order-4711".to_string()),
+///         print_on_terminal: Some(true),
+///     })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api_paymentintent_mlb/_point_integration-api_devices_deviceid_payment-intents/post>
+#[derive(Debug, Clone)]
+pub struct PaymentIntentCreateBuilder {
+    pub device_id: String,
+    pub options: PaymentIntentCreateOptions,
+}
+
+impl PaymentIntentCreateBuilder {
+    /// Payment intent of `amount` for the terminal `device_id`.
+    pub fn new(device_id: impl ToString, amount: PointAmount) -> Self {
+        PaymentIntentCreateBuilder {
+            device_id: device_id.to_string(),
+            options: PaymentIntentCreateOptions {
+                amount,
+                description: None,
+                additional_info: None,
+                payment: None,
+            },
+        }
+    }
+
+    /// Sets `description`.
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.options.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets `additional_info`, with the external reference and printing settings.
+    pub fn additional_info(mut self, additional_info: PaymentIntentAdditionalInfo) -> Self {
+        self.options.additional_info = Some(additional_info);
+
+        self
+    }
+
+    /// Sets `payment`, with the card type and installments.
+    pub fn payment(mut self, payment: PaymentIntentPayment) -> Self {
+        self.options.payment = Some(payment);
+
+        self
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PaymentIntentResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::POST,
+                    format!(
+                        "/point/integration-api/devices/{}/payment-intents",
+                        self.device_id
+                    ),
+                ),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<PaymentIntentResponse>(res).await
+    }
+}
+
+impl SendRequest for PaymentIntentCreateBuilder {
+    type Output = PaymentIntentResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PaymentIntentCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PaymentIntentCreateBuilder;
+    use crate::{common::create_test_client, point::types::PointAmount};
+
+    #[tokio::test]
+    async fn fail_with_unknown_device() {
+        let mp_client = create_test_client();
+
+        let res = PaymentIntentCreateBuilder::new("UNKNOWN_DEVICE", PointAmount::cents(1500))
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,389 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+use thiserror::Error;
+
+use crate::{
+    ids::{PaymentId, PosId, StoreId},
+    payments::types::Paging,
+};
+
+/// Amount of a Point payment intent, sent to the API as an integer number of cents.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct PointAmount(u64);
+
+impl PointAmount {
+    /// Amount of `cents`, like `1500` for 15.00.
+    pub fn cents(cents: u64) -> Self {
+        PointAmount(cents)
+    }
+
+    /// Amount in cents.
+    pub fn as_cents(&self) -> u64 {
+        self.0
+    }
+
+    /// Amount in currency units, like `15.00`.
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::new(self.0 as i64, 2)
+    }
+}
+
+impl TryFrom<Decimal> for PointAmount {
+    type Error = PointAmountError;
+
+    /// Converts an amount in currency units, failing when it is not positive or has fractions of a cent.
+    fn try_from(amount: Decimal) -> Result<Self, Self::Error> {
+        if amount <= Decimal::ZERO {
+            return Err(PointAmountError::NotPositive(amount));
+        }
+
+        let cents = amount * Decimal::ONE_HUNDRED;
+        if !cents.fract().is_zero() {
+            return Err(PointAmountError::FractionOfCent(amount));
+        }
+
+        u64::try_from(cents.trunc())
+            .map(PointAmount)
+            .map_err(|_| PointAmountError::TooLarge(amount))
+    }
+}
+
+/// Errors of converting a [`Decimal`] into a [`PointAmount`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PointAmountError {
+    #[error("Amount {0} must be greater than zero")]
+    NotPositive(Decimal),
+    #[error("Amount {0} has fractions of a cent")]
+    FractionOfCent(Decimal),
+    #[error("Amount {0} is too large")]
+    TooLarge(Decimal),
+}
+
+/// # PaymentIntentCreateOptions
+/// Struct to use in [`PaymentIntentCreateBuilder`](crate::point::PaymentIntentCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api_paymentintent_mlb/_point_integration-api_devices_deviceid_payment-intents/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PaymentIntentCreateOptions {
+    pub amount: PointAmount,
+    /// Description shown in the terminal.
+    pub description: Option<String>,
+    pub additional_info: Option<PaymentIntentAdditionalInfo>,
+    pub payment: Option<PaymentIntentPayment>,
+}
+
+/// Reference and printing settings of a payment intent.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PaymentIntentAdditionalInfo {
+    /// Identifier of the payment in your system, returned in the payment.
+    pub external_reference: Option<String>,
+    /// Print the receipt in the terminal.
+    pub print_on_terminal: Option<bool>,
+}
+
+/// Card type and installments of a payment intent. When missing, the payer chooses in the terminal.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PaymentIntentPayment {
+    #[serde(rename = "type")]
+    pub payment_type: Option<PointPaymentType>,
+    pub installments: Option<u32>,
+    pub installments_cost: Option<InstallmentsCost>,
+}
+
+/// Card type charged by the terminal
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PointPaymentType {
+    CreditCard,
+    DebitCard,
+    VoucherCard,
+    /// For untracked payment types
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Who pays the interest of the installments
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallmentsCost {
+    Seller,
+    Buyer,
+    /// For untracked installments costs
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// # PaymentIntentResponse
+/// Payment intent returned by `/point/integration-api/devices/{device_id}/payment-intents`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api_paymentintent_mlb/_point_integration-api_devices_deviceid_payment-intents/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PaymentIntentResponse {
+    pub id: String,
+    pub device_id: String,
+    pub amount: PointAmount,
+    pub description: Option<String>,
+    pub additional_info: Option<PaymentIntentAdditionalInfo>,
+    pub payment: Option<PaymentIntentPayment>,
+}
+
+/// # DeviceListOptions
+/// Struct to use in [`DeviceListBuilder`](crate::point::DeviceListBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api/_point_integration-api_devices/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct DeviceListOptions {
+    pub store_id: Option<StoreId>,
+    pub pos_id: Option<PosId>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// # DeviceListResponse
+/// Response from `/point/integration-api/devices`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeviceListResponse {
+    pub devices: Vec<Device>,
+    pub paging: Paging,
+}
+
+/// # Device
+/// Point terminal linked to the account
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/integrations_api/_point_integration-api_devices/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Device {
+    /// Identifier of the terminal, used to send payment intents.
+    pub id: String,
+    pub pos_id: Option<PosId>,
+    pub store_id: Option<StoreId>,
+    pub external_pos_id: Option<String>,
+    pub operating_mode: OperatingMode,
+}
+
+/// Operating mode of a Point terminal
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OperatingMode {
+    /// Integrated with a point of sale system, receiving payment intents from the API.
+    Pdv,
+    /// Used on its own, the amount is typed in the terminal.
+    Standalone,
+    /// For untracked operating modes
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Body of [`DeviceModeBuilder`](crate::point::DeviceModeBuilder), and its response
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeviceModeOptions {
+    pub operating_mode: OperatingMode,
+}
+
+/// State of a payment intent
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentIntentState {
+    /// Created, waiting for the terminal.
+    Open,
+    /// Shown in the terminal, waiting for the card.
+    OnTerminal,
+    Processing,
+    Processed,
+    /// The payment was created, check its status with `payment.id`.
+    Finished,
+    Canceled,
+    /// The terminal could not process the payment.
+    #[serde(rename = "ERROR")]
+    Failed,
+    Abandoned,
+    /// For untracked payment intent states
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// # PointNotification
+/// Body of the notifications of type `point_integration_wh`, sent when a payment intent changes state
+///
+/// <https://www.mercadopago.com.br/developers/pt/docs/mp-point/integration-configuration/integrate-with-pdv/notifications>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PointNotification {
+    /// Identifier of the payment intent.
+    pub id: String,
+    pub state: PaymentIntentState,
+    pub device_id: Option<String>,
+    pub amount: Option<PointAmount>,
+    pub additional_info: Option<PaymentIntentAdditionalInfo>,
+    /// Payment created by the terminal, once the intent is processed.
+    pub payment: Option<PointNotificationPayment>,
+    /// Payment intent create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub created_at: Option<String>,
+}
+
+impl PointNotification {
+    /// Parses the raw body of a notification.
+    pub fn from_body(body: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+}
+
+/// Payment of a [`PointNotification`]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PointNotificationPayment {
+    /// Identifier of the payment, to fetch with [`PaymentGetBuilder`](crate::payments::PaymentGetBuilder).
+    pub id: PaymentId,
+    #[serde(rename = "type")]
+    pub payment_type: Option<PointPaymentType>,
+    pub installments: Option<u32>,
+    pub installments_cost: Option<InstallmentsCost>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{
+        DeviceListResponse, InstallmentsCost, OperatingMode, PaymentIntentAdditionalInfo,
+        PaymentIntentPayment, PaymentIntentResponse, PaymentIntentState, PointAmount,
+        PointAmountError, PointNotification, PointPaymentType,
+    };
+    use crate::point::PaymentIntentCreateBuilder;
+
+    #[test]
+    fn amount_from_decimal() {
+        assert_eq!(
+            PointAmount::try_from(Decimal::new(1550, 2)),
+            Ok(PointAmount::cents(1550))
+        );
+        assert_eq!(
+            PointAmount::try_from(Decimal::new(15, 0)),
+            Ok(PointAmount::cents(1500))
+        );
+        assert_eq!(
+            PointAmount::try_from(Decimal::new(15505, 3)),
+            Err(PointAmountError::FractionOfCent(Decimal::new(15505, 3)))
+        );
+        assert_eq!(
+            PointAmount::try_from(Decimal::ZERO),
+            Err(PointAmountError::NotPositive(Decimal::ZERO))
+        );
+        assert_eq!(PointAmount::cents(1550).to_decimal(), Decimal::new(1550, 2));
+    }
+
+    #[test]
+    fn intent_body() {
+        let builder = PaymentIntentCreateBuilder::new(
+            "PAX_A910__SMARTPOS1234345545",
+            PointAmount::cents(1500),
+        )
+        .additional_info(PaymentIntentAdditionalInfo {
+            external_reference: Some("order-4711".to_string()),
+            print_on_terminal: Some(true),
+        })
+        .payment(PaymentIntentPayment {
+            payment_type: Some(PointPaymentType::CreditCard),
+            installments: Some(3),
+            installments_cost: Some(InstallmentsCost::Seller),
+        });
+
+        assert_eq!(
+            serde_json::to_value(&builder.options).unwrap(),
+            serde_json::json!({
+                "amount": 1500,
+                "additional_info": {
+                    "external_reference": "order-4711",
+                    "print_on_terminal": true
+                },
+                "payment": {
+                    "type": "credit_card",
+                    "installments": 3,
+                    "installments_cost": "seller"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parse_response() {
+        let response = serde_json::from_str::<PaymentIntentResponse>(
+            r#"{
+                "id": "7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+                "device_id": "PAX_A910__SMARTPOS1234345545",
+                "amount": 1500,
+                "description": "Pedido 4711",
+                "additional_info": {"external_reference": "order-4711", "print_on_terminal": true}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.amount.as_cents(), 1500);
+        assert!(response.payment.is_none());
+    }
+
+    #[test]
+    fn parse_devices() {
+        let response = serde_json::from_str::<DeviceListResponse>(
+            r#"{
+                "devices": [
+                    {
+                        "id": "PAX_A910__SMARTPOS1234345545",
+                        "pos_id": 47792476,
+                        "store_id": "47792478",
+                        "external_pos_id": "SUC0101POS",
+                        "operating_mode": "PDV"
+                    },
+                    {"id": "NEWLAND_N950__N950NCB801293324", "operating_mode": "STANDALONE"}
+                ],
+                "paging": {"total": 2, "offset": 0, "limit": 50}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.devices[0].operating_mode, OperatingMode::Pdv);
+        assert_eq!(
+            response.devices[1].operating_mode,
+            OperatingMode::Standalone
+        );
+        assert_eq!(response.paging.total, 2);
+        assert_eq!(
+            serde_json::to_value(OperatingMode::Standalone).unwrap(),
+            serde_json::json!("STANDALONE")
+        );
+    }
+
+    #[test]
+    fn parse_notification() {
+        let notification = PointNotification::from_body(
+            br#"{
+                "additional_info": {"external_reference": "order-4711", "print_on_terminal": true},
+                "amount": 1500,
+                "caller_id": 1234567890,
+                "client_id": 4321,
+                "created_at": "2024-03-12T10:15:00.000-03:00",
+                "device_id": "PAX_A910__SMARTPOS1234345545",
+                "id": "7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+                "payment": {"id": 73491648524, "installments": 1, "installments_cost": "seller", "type": "credit_card"},
+                "state": "FINISHED"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(notification.state, PaymentIntentState::Finished);
+        assert_eq!(notification.payment.unwrap().id, 73491648524);
+        assert_eq!(
+            serde_json::to_value(PaymentIntentState::OnTerminal).unwrap(),
+            serde_json::json!("ON_TERMINAL")
+        );
+        assert_eq!(
+            serde_json::from_str::<PaymentIntentState>(r#""ERROR""#).unwrap(),
+            PaymentIntentState::Failed
+        );
+    }
+}
@@ -0,0 +1,12 @@
+pub use self::create_builder::PosCreateBuilder;
+pub use self::delete_builder::PosDeleteBuilder;
+pub use self::get_builder::PosGetBuilder;
+pub use self::search_builder::PosSearchBuilder;
+pub use self::update_builder::PosUpdateBuilder;
+
+mod create_builder;
+mod delete_builder;
+mod get_builder;
+mod search_builder;
+pub mod types;
+mod update_builder;
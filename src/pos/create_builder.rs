@@ -0,0 +1,80 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{Pos, PosCreateOptions};
+
+/// Builder for creating a point of sale in a store
+///
+/// The response has the static QR of the point of sale.
+///
+/// # Arguments
+///
+/// * `options` - Name, store and external id of the point of sale.
+///
+/// # Example
+/// ```
+/// use mpago::pos::{types::{PosCategory, PosCreateOptions, PosExternalId}, PosCreateBuilder};
+///
+/// PosCreateBuilder(
+///     PosCreateOptions::new("Caixa 1", PosExternalId::new("SUC001POS001")?, "1234567".into())
+///         .with_category(PosCategory::Gastronomy),
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/post>
+#[derive(Debug, Clone)]
+pub struct PosCreateBuilder(pub PosCreateOptions);
+
+impl PosCreateBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<Pos, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(mp_client.start_request(Method::POST, "/pos"), &self.0)?)
+            .await?;
+
+        mp_client.resolve_json::<Pos>(res).await
+    }
+}
+
+impl SendRequest for PosCreateBuilder {
+    type Output = Pos;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PosCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PosCreateBuilder;
+    use crate::{
+        common::create_test_client,
+        pos::types::{PosCreateOptions, PosExternalId},
+    };
+
+    #[tokio::test]
+    async fn fail_with_unknown_store() {
+        let mp_client = create_test_client();
+
+        let res = PosCreateBuilder(PosCreateOptions::new(
+            "Caixa",
+            PosExternalId::new("UNKNOWN").unwrap(),
+            "0".into(),
+        ))
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
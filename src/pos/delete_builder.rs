@@ -0,0 +1,51 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PosId,
+};
+
+/// Builder for deleting a point of sale
+///
+/// Its static QR stops working.
+///
+/// # Arguments
+///
+/// * `id` - Id of the point of sale, generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::pos::PosDeleteBuilder;
+///
+/// PosDeleteBuilder(2711382.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos_id/delete>
+#[derive(Debug, Clone)]
+pub struct PosDeleteBuilder(pub PosId);
+
+impl PosDeleteBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<(), MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::DELETE, format!("/pos/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_empty(res).await
+    }
+}
+
+impl SendRequest for PosDeleteBuilder {
+    type Output = ();
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PosDeleteBuilder::send(self, mp_client)
+    }
+}
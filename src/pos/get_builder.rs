@@ -0,0 +1,73 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PosId,
+};
+
+use super::types::Pos;
+
+/// Builder for getting a point of sale
+///
+/// # Arguments
+///
+/// * `id` - Id of the point of sale, generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::pos::PosGetBuilder;
+///
+/// PosGetBuilder(2711382.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos_id/get>
+#[derive(Debug, Clone)]
+pub struct PosGetBuilder(pub PosId);
+
+impl PosGetBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<Pos, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/pos/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<Pos>(res).await
+    }
+}
+
+impl<T: Into<PosId>> From<T> for PosGetBuilder {
+    fn from(id: T) -> Self {
+        PosGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for PosGetBuilder {
+    type Output = Pos;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PosGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PosGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_with_unknown_pos() {
+        let mp_client = create_test_client();
+
+        let res = PosGetBuilder(0.into()).send(&mp_client).await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{PosSearchOptions, PosSearchResponse};
+
+/// Builder for search points of sale
+///
+/// # Arguments
+///
+/// * `options` - Options to search for points of sale.
+///
+/// # Example
+/// ```
+/// use mpago::pos::{types::PosSearchOptions, PosSearchBuilder};
+///
+/// PosSearchBuilder(PosSearchOptions {
+///     store_id: Some("1234567".into()),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/get>
+#[derive(Debug, Clone, Default)]
+pub struct PosSearchBuilder(pub PosSearchOptions);
+
+impl PosSearchBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PosSearchResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, "/pos").query(&self.0))
+            .await?;
+
+        mp_client.resolve_json::<PosSearchResponse>(res).await
+    }
+}
+
+impl SendRequest for PosSearchBuilder {
+    type Output = PosSearchResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PosSearchBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PosSearchBuilder;
+    use crate::{common::create_test_client, pos::types::PosSearchOptions};
+
+    #[tokio::test]
+    async fn search_pos() {
+        let mp_client = create_test_client();
+
+        let res = PosSearchBuilder(PosSearchOptions {
+            limit: Some(1),
+            ..Default::default()
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        assert!(res.results.len() <= 1);
+    }
+}
@@ -0,0 +1,346 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use thiserror::Error;
+
+use crate::{
+    ids::{PosId, StoreId},
+    payments::types::Paging,
+};
+
+/// # PosCreateOptions
+/// Struct to use in [`PosCreateBuilder`](crate::pos::PosCreateBuilder)
+///
+/// The point of sale belongs to the store of `store_id` or `external_store_id`, one of them is required.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PosCreateOptions {
+    pub name: String,
+    /// Whether the payer types the amount when paying with the static QR, instead of paying an order.
+    pub fixed_amount: bool,
+    pub store_id: Option<StoreId>,
+    pub external_store_id: Option<String>,
+    pub external_id: PosExternalId,
+    pub category: Option<PosCategory>,
+}
+
+impl PosCreateOptions {
+    /// Point of sale that only accepts orders, in the store of `store_id`.
+    pub fn new(name: impl ToString, external_id: PosExternalId, store_id: StoreId) -> Self {
+        PosCreateOptions {
+            name: name.to_string(),
+            fixed_amount: false,
+            store_id: Some(store_id),
+            external_store_id: None,
+            external_id,
+            category: None,
+        }
+    }
+
+    /// Sets `fixed_amount`.
+    pub fn with_fixed_amount(mut self, fixed_amount: bool) -> Self {
+        self.fixed_amount = fixed_amount;
+
+        self
+    }
+
+    /// Sets `category`.
+    pub fn with_category(mut self, category: PosCategory) -> Self {
+        self.category = Some(category);
+
+        self
+    }
+}
+
+/// # PosUpdateOptions
+/// Struct to use in [`PosUpdateBuilder`](crate::pos::PosUpdateBuilder)
+///
+/// Only the fields set are updated.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos_id/put>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PosUpdateOptions {
+    pub name: Option<String>,
+    pub fixed_amount: Option<bool>,
+    pub store_id: Option<StoreId>,
+    pub external_store_id: Option<String>,
+    pub category: Option<PosCategory>,
+}
+
+/// Id of the point of sale in your system, used in the URLs of the in-store orders.
+///
+/// Mercado Pago only accepts letters and numbers, up to 40 characters.
+///
+/// # Example
+/// ```
+/// use mpago::pos::types::PosExternalId;
+///
+/// let external_id = PosExternalId::new("SUC001POS001")?;
+///
+/// assert!(PosExternalId::new("SUC001-POS001").is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(try_from = "String", into = "String")]
+pub struct PosExternalId(String);
+
+impl PosExternalId {
+    /// Maximum number of characters accepted by Mercado Pago.
+    pub const MAX_LEN: usize = 40;
+
+    /// Validates and creates an external id
+    pub fn new(external_id: impl ToString) -> Result<Self, PosExternalIdError> {
+        let external_id = external_id.to_string();
+
+        if external_id.is_empty() {
+            return Err(PosExternalIdError::Empty);
+        }
+
+        if external_id.len() > Self::MAX_LEN {
+            return Err(PosExternalIdError::TooLong(external_id.len()));
+        }
+
+        if let Some(c) = external_id.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(PosExternalIdError::InvalidCharacter(c));
+        }
+
+        Ok(PosExternalId(external_id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for PosExternalId {
+    type Error = PosExternalIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for PosExternalId {
+    type Error = PosExternalIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl FromStr for PosExternalId {
+    type Err = PosExternalIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl From<PosExternalId> for String {
+    fn from(value: PosExternalId) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for PosExternalId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PosExternalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Error when validating a [`PosExternalId`]
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PosExternalIdError {
+    #[error("Point of sale external id is empty")]
+    Empty,
+    #[error("Point of sale external id has {0} characters, the maximum is {max}", max = PosExternalId::MAX_LEN)]
+    TooLong(usize),
+    #[error("Point of sale external id has an invalid character: {0:?}, only letters and numbers are accepted")]
+    InvalidCharacter(char),
+}
+
+/// Business category of a point of sale, as an MCC code.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(from = "u64", into = "u64")]
+pub enum PosCategory {
+    /// Restaurants, bars and food stores, `5611203`.
+    Gastronomy,
+    /// Gas stations, `473000`.
+    GasStation,
+    /// Any other business, `621102`.
+    General,
+    /// For untracked categories
+    Other(u64),
+}
+
+impl From<u64> for PosCategory {
+    fn from(code: u64) -> Self {
+        match code {
+            5611203 => PosCategory::Gastronomy,
+            473000 => PosCategory::GasStation,
+            621102 => PosCategory::General,
+            code => PosCategory::Other(code),
+        }
+    }
+}
+
+impl From<PosCategory> for u64 {
+    fn from(category: PosCategory) -> Self {
+        match category {
+            PosCategory::Gastronomy => 5611203,
+            PosCategory::GasStation => 473000,
+            PosCategory::General => 621102,
+            PosCategory::Other(code) => code,
+        }
+    }
+}
+
+/// # Pos
+/// Response from `/pos` and `/pos/{id}`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Pos {
+    pub id: PosId,
+    pub name: String,
+    #[serde(default)]
+    pub fixed_amount: bool,
+    pub category: Option<PosCategory>,
+    pub store_id: Option<StoreId>,
+    pub external_store_id: Option<String>,
+    /// Kept as received, points of sale created before the validation may not be a valid [`PosExternalId`].
+    pub external_id: Option<String>,
+    /// Static QR of the point of sale.
+    pub qr: Option<PosQr>,
+    /// EMVCo data of the static QR.
+    pub qr_code: Option<String>,
+    /// Creation date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// Last update date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_last_updated: Option<String>,
+}
+
+/// Images of the static QR of a point of sale.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PosQr {
+    /// URL of the QR code image.
+    pub image: String,
+    /// URL of a PDF with the QR and the Mercado Pago instructions.
+    pub template_document: Option<String>,
+    /// URL of an image with the QR and the Mercado Pago instructions.
+    pub template_image: Option<String>,
+}
+
+/// # PosSearchOptions
+/// Struct to use in [`PosSearchBuilder`](crate::pos::PosSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PosSearchOptions {
+    /// Quantity of points of sale returned.
+    pub limit: Option<usize>,
+    /// Quantity of points of sale to skip.
+    pub offset: Option<usize>,
+    pub external_id: Option<PosExternalId>,
+    pub store_id: Option<StoreId>,
+    pub external_store_id: Option<String>,
+}
+
+/// # PosSearchResponse
+/// Response from `/pos`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PosSearchResponse {
+    pub paging: Paging,
+    pub results: Vec<Pos>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Pos, PosCategory, PosCreateOptions, PosExternalId, PosExternalIdError, PosSearchOptions,
+    };
+
+    #[test]
+    fn external_id_validation() {
+        assert!(PosExternalId::new("SUC001POS001").is_ok());
+        assert_eq!(PosExternalId::new(""), Err(PosExternalIdError::Empty));
+        assert_eq!(
+            PosExternalId::new("a".repeat(41)),
+            Err(PosExternalIdError::TooLong(41))
+        );
+        assert_eq!(
+            PosExternalId::new("SUC001_POS001"),
+            Err(PosExternalIdError::InvalidCharacter('_'))
+        );
+        assert!(serde_json::from_str::<PosExternalId>(r#""SUC 001""#).is_err());
+    }
+
+    #[test]
+    fn create_body() {
+        let options = PosCreateOptions::new(
+            "Caixa 1",
+            PosExternalId::new("SUC001POS001").unwrap(),
+            "1234567".into(),
+        )
+        .with_fixed_amount(true)
+        .with_category(PosCategory::Gastronomy);
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "name": "Caixa 1",
+                "fixed_amount": true,
+                "store_id": "1234567",
+                "external_id": "SUC001POS001",
+                "category": 5611203
+            })
+        );
+
+        let search = PosSearchOptions {
+            external_id: Some(PosExternalId::new("SUC001POS001").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&search).unwrap(),
+            serde_json::json!({"external_id": "SUC001POS001"})
+        );
+    }
+
+    #[test]
+    fn parse_pos() {
+        let pos = serde_json::from_str::<Pos>(
+            r#"{
+                "id": 2711382,
+                "name": "Caixa 1",
+                "fixed_amount": false,
+                "category": 621102,
+                "store_id": "1234567",
+                "external_store_id": "SUC001",
+                "external_id": "SUC001POS001",
+                "qr": {
+                    "image": "https://www.mercadopago.com/instore/merchant/qr/2711382/0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.png",
+                    "template_document": "https://www.mercadopago.com/instore/merchant/qr/2711382/template_0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.pdf",
+                    "template_image": "https://www.mercadopago.com/instore/merchant/qr/2711382/template_0977011cd2a4489cb7eb1c2d8a3b7c61f5d3a76e.png"
+                },
+                "date_created": "2024-05-02T13:18:05.000Z",
+                "date_last_updated": "2024-05-02T13:18:05.000Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(pos.id, 2711382);
+        assert_eq!(pos.category, Some(PosCategory::General));
+        assert_eq!(
+            serde_json::from_str::<PosCategory>("123").unwrap(),
+            PosCategory::Other(123)
+        );
+    }
+}
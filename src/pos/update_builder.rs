@@ -0,0 +1,88 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PosId,
+};
+
+use super::types::{Pos, PosUpdateOptions};
+
+/// Builder for updating a point of sale
+///
+/// # Arguments
+///
+/// * `id` - Id of the point of sale, generated by Mercado Pago.
+/// * `options` - Fields to update.
+///
+/// # Example
+/// ```
+/// use mpago::pos::{types::PosUpdateOptions, PosUpdateBuilder};
+///
+/// PosUpdateBuilder {
+///     id: 2711382.into(),
+///     options: PosUpdateOptions {
+///         fixed_amount: Some(true),
+///         ..Default::default()
+///     },
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/pos/_pos_id/put>
+#[derive(Debug, Clone)]
+pub struct PosUpdateBuilder {
+    pub id: PosId,
+    pub options: PosUpdateOptions,
+}
+
+impl PosUpdateBuilder {
+    /// Send the request
+    pub async fn send(self, mp_client: &MercadoPagoClient) -> Result<Pos, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::PUT, format!("/pos/{}", self.id)),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<Pos>(res).await
+    }
+}
+
+impl SendRequest for PosUpdateBuilder {
+    type Output = Pos;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PosUpdateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::PosUpdateBuilder;
+    use crate::{common::create_test_client, pos::types::PosUpdateOptions};
+
+    #[tokio::test]
+    async fn fail_with_unknown_pos() {
+        let mp_client = create_test_client();
+
+        let res = PosUpdateBuilder {
+            id: 0.into(),
+            options: PosUpdateOptions {
+                name: Some("Caixa".to_string()),
+                ..Default::default()
+            },
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub use self::create_builder::PreferenceCreateBuilder;
+pub use self::get_builder::PreferenceGetBuilder;
+pub use self::search_builder::PreferenceSearchBuilder;
+
+mod create_builder;
+mod get_builder;
+mod search_builder;
+pub mod types;
@@ -0,0 +1,171 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::{trim_field, MercadoPagoRequestError, TrimWarning},
+    payments::PaymentCreateBuilder,
+};
+
+use super::types::{PreferenceCreateOptions, PreferenceResponse};
+
+/// Builder for creating a checkout preference
+///
+/// # Arguments
+///
+/// * `options` - Options to create the preference.
+///
+/// # Example
+/// ```
+/// use mpago::{preferences::PreferenceCreateBuilder, Decimal};
+///
+/// PreferenceCreateBuilder(PreferenceCreateOptions {
+///     items: vec![PreferenceItem {
+///         title: "Some product".to_string(),
+///         quantity: 1,
+///         unit_price: Decimal::new(25, 0),
+///         ..Default::default()
+///     }],
+///     back_urls: Some(BackUrls {
+///         success: Some("https://example.com/success".to_string()),
+///         ..Default::default()
+///     }),
+///     auto_return: Some(AutoReturn::Approved),
+///     ..Default::default()
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences/post>
+#[derive(Debug, Clone)]
+pub struct PreferenceCreateBuilder(pub PreferenceCreateOptions);
+
+impl PreferenceCreateBuilder {
+    /// Shortens the free-text fields that are over the API limits, instead of having the preference rejected
+    ///
+    /// Trims `statement_descriptor` and the `title` and `description` of the items, using the same limits as [`PaymentCreateBuilder::trim_free_text`]. Returns a [`TrimWarning`] for every trimmed field.
+    pub fn trim_free_text(mut self) -> (Self, Vec<TrimWarning>) {
+        let mut warnings = Vec::new();
+        let options = &mut self.0;
+
+        if let Some(statement_descriptor) = &mut options.statement_descriptor {
+            trim_field(
+                &mut warnings,
+                || "statement_descriptor".to_string(),
+                statement_descriptor,
+                PaymentCreateBuilder::STATEMENT_DESCRIPTOR_MAX_LEN,
+            );
+        }
+        for (i, item) in options.items.iter_mut().enumerate() {
+            trim_field(
+                &mut warnings,
+                || format!("items[{i}].title"),
+                &mut item.title,
+                PaymentCreateBuilder::ITEM_TEXT_MAX_LEN,
+            );
+            if let Some(description) = &mut item.description {
+                trim_field(
+                    &mut warnings,
+                    || format!("items[{i}].description"),
+                    description,
+                    PaymentCreateBuilder::ITEM_TEXT_MAX_LEN,
+                );
+            }
+        }
+
+        (self, warnings)
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PreferenceResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/checkout/preferences"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<PreferenceResponse>(res).await
+    }
+}
+
+impl SendRequest for PreferenceCreateBuilder {
+    type Output = PreferenceResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PreferenceCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::PreferenceCreateBuilder;
+    use crate::{
+        common::create_test_client,
+        preferences::{
+            types::{PreferenceCreateOptions, PreferenceItem},
+            PreferenceGetBuilder,
+        },
+    };
+
+    #[tokio::test]
+    async fn create_and_get_preference() {
+        let mp_client = create_test_client();
+
+        let preference = PreferenceCreateBuilder(PreferenceCreateOptions {
+            items: vec![PreferenceItem {
+                title: "Test".to_string(),
+                quantity: 1,
+                unit_price: Decimal::new(10, 0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        let fetched = PreferenceGetBuilder(preference.id.clone())
+            .send(&mp_client)
+            .await
+            .unwrap();
+
+        assert_eq!(fetched.id, preference.id);
+    }
+}
+
+#[cfg(test)]
+mod trim_tests {
+    use crate::preferences::types::{PreferenceCreateOptions, PreferenceItem};
+
+    use super::*;
+
+    #[test]
+    fn trims_item_titles() {
+        let (builder, warnings) = PreferenceCreateBuilder(PreferenceCreateOptions {
+            items: vec![PreferenceItem {
+                title: "Produto ".repeat(100),
+                quantity: 1,
+                ..Default::default()
+            }],
+            statement_descriptor: Some("UMA LOJA COM NOME MUITO GRANDE".to_string()),
+            ..Default::default()
+        })
+        .trim_free_text();
+
+        let fields: Vec<_> = warnings.iter().map(|w| w.field.as_str()).collect();
+        assert_eq!(fields, ["statement_descriptor", "items[0].title"]);
+        assert_eq!(builder.0.items[0].title.chars().count(), 256);
+        assert_eq!(builder.0.statement_descriptor.unwrap().len(), 22);
+    }
+}
@@ -0,0 +1,62 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PreferenceId,
+};
+
+use super::types::PreferenceResponse;
+
+/// Builder for get a checkout preference
+///
+/// # Arguments
+///
+/// * `preference_id` - Unique preference identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::preferences::PreferenceGetBuilder;
+///
+/// PreferenceGetBuilder("202809963-920c288b-4ebb-40be-966f-700250fa5370".into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences_id/get>
+#[derive(Debug, Clone)]
+pub struct PreferenceGetBuilder(pub PreferenceId);
+
+impl PreferenceGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<PreferenceResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client.start_request(Method::GET, format!("/checkout/preferences/{}", self.0)),
+            )
+            .await?;
+
+        mp_client.resolve_json::<PreferenceResponse>(res).await
+    }
+}
+
+impl<T: Into<PreferenceId>> From<T> for PreferenceGetBuilder {
+    fn from(id: T) -> Self {
+        PreferenceGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for PreferenceGetBuilder {
+    type Output = PreferenceResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        PreferenceGetBuilder::send(self, mp_client)
+    }
+}
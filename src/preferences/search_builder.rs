@@ -0,0 +1,113 @@
+use futures_core::Stream;
+use std::{borrow::Borrow, pin::Pin};
+
+use super::types::{PartialPreferenceResult, PreferenceSearchOptions, PreferenceSearchResponse};
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    pagination::{collect_all, paginate, search_query, SearchPage, SearchStream},
+};
+
+/// Builder for search checkout preferences
+///
+/// # Arguments
+///
+/// * `options` - Options to search for preferences
+///
+/// # Example
+/// ```
+/// use mpago::preferences::PreferenceSearchBuilder;
+///
+/// PreferenceSearchBuilder(
+///     PreferenceSearchOptions {
+///         external_reference: Some(ExternalReference::new("order-123")?),
+///         begin_date: Some("NOW-7DAYS".to_string()),
+///         ..Default::default()
+///     }
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences_search/get>
+#[derive(Debug, Clone)]
+pub struct PreferenceSearchBuilder(pub PreferenceSearchOptions);
+
+impl PreferenceSearchBuilder {
+    /// This function creates a stream of preferences, it goes through all the pages.
+    ///
+    /// Works like [`PaymentSearchBuilder::fetch_all_streamed`](crate::payments::PaymentSearchBuilder::fetch_all_streamed).
+    pub async fn fetch_all_streamed<'a>(
+        self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Pin<
+        Box<
+            dyn Stream<Item = Result<PartialPreferenceResult, MercadoPagoRequestError>> + Send + 'a,
+        >,
+    > {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Same as [`fetch_all_streamed`](Self::fetch_all_streamed), but the stream owns the client.
+    pub async fn fetch_all_streamed_owned(
+        self,
+        mp_client: MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<PartialPreferenceResult, MercadoPagoRequestError>> + Send>>
+    {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Fetches every page and collects the preferences into a [`Vec`].
+    ///
+    /// Stops at the first error, or once `max_items` preferences were collected.
+    pub async fn collect_all(
+        self,
+        mp_client: &MercadoPagoClient,
+        max_items: Option<usize>,
+    ) -> Result<Vec<PartialPreferenceResult>, MercadoPagoRequestError> {
+        collect_all(stream_pages(self.0, mp_client), max_items).await
+    }
+}
+
+search_query!(PreferenceSearchOptions);
+
+impl SearchPage for PreferenceSearchResponse {
+    type Item = PartialPreferenceResult;
+
+    fn into_results(self) -> (Vec<Self::Item>, usize) {
+        (self.elements, self.total)
+    }
+}
+
+/// Streams every result matching `options`, going through all the pages.
+fn stream_pages<'a, C>(
+    options: PreferenceSearchOptions,
+    mp_client: C,
+) -> SearchStream<'a, PartialPreferenceResult>
+where
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    paginate::<PreferenceSearchResponse, _, _>("/checkout/preferences/search", options, mp_client)
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::{PreferenceSearchBuilder, PreferenceSearchOptions};
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn search_preferences() {
+        let mp_client = create_test_client();
+
+        let preferences = PreferenceSearchBuilder(PreferenceSearchOptions {
+            limit: Some(2),
+            begin_date: Some("NOW-30DAYS".to_string()),
+            ..Default::default()
+        })
+        .collect_all(&mp_client, Some(3))
+        .await
+        .unwrap();
+
+        assert!(preferences.len() <= 3);
+    }
+}
@@ -0,0 +1,365 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    common::{ExternalReference, PreferenceLimits},
+    ids::{PreferenceId, UserId},
+    payments::types::{CurrencyId, PaymentMethodId, PaymentTypeId, Shipments},
+};
+
+/// # PreferenceCreateOptions
+/// Used as the request body for creating preferences
+///
+/// See [`PreferenceCreateBuilder`](crate::preferences::PreferenceCreateBuilder) if you want to create preferences.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PreferenceCreateOptions {
+    /// Items being sold.
+    pub items: Vec<PreferenceItem>,
+    /// Payer's information, used to prefill the checkout.
+    pub payer: Option<PreferencePayer>,
+    /// URLs the buyer returns to after the checkout.
+    pub back_urls: Option<BackUrls>,
+    /// Redirects the buyer automatically to `back_urls`. Requires `back_urls.success`.
+    pub auto_return: Option<AutoReturn>,
+    /// Payment methods and installments offered in the checkout.
+    pub payment_methods: Option<PreferencePaymentMethods>,
+    /// URL that will receive the payment notifications.
+    pub notification_url: Option<String>,
+    /// It is an external reference for the preference, like the ID of the order in your system.
+    pub external_reference: Option<ExternalReference<PreferenceLimits>>,
+    /// Description that the payment will appear with in the card statement.
+    pub statement_descriptor: Option<String>,
+    /// Shipping mode and cost of the purchase.
+    pub shipments: Option<Shipments>,
+    /// Validity interval of the preference.
+    #[serde(flatten)]
+    pub expiration: PreferenceExpiration,
+}
+
+/// # PreferenceResponse
+/// Preference returned by `/checkout/preferences`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PreferenceResponse {
+    pub id: PreferenceId,
+    pub collector_id: Option<UserId>,
+    pub client_id: Option<String>,
+    /// Preference create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// URL of the checkout.
+    pub init_point: String,
+    /// URL of the checkout for test users.
+    pub sandbox_init_point: String,
+    pub items: Vec<PreferenceItem>,
+    pub payer: Option<PreferencePayer>,
+    pub back_urls: Option<BackUrls>,
+    /// Empty when the preference does not redirect automatically, and parsed as [`AutoReturn::Unknown`].
+    pub auto_return: Option<AutoReturn>,
+    pub payment_methods: Option<PreferencePaymentMethods>,
+    pub notification_url: Option<String>,
+    pub external_reference: Option<String>,
+    pub statement_descriptor: Option<String>,
+    pub shipments: Option<Shipments>,
+    #[serde(flatten)]
+    pub expiration: PreferenceExpiration,
+}
+
+/// Item of a preference.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PreferenceItem {
+    /// Item identifier in your system.
+    pub id: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub picture_url: Option<String>,
+    pub category_id: Option<String>,
+    pub quantity: u32,
+    pub currency_id: Option<CurrencyId>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unit_price: Decimal,
+}
+
+/// Payer's information of a preference.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PreferencePayer {
+    pub name: Option<String>,
+    pub surname: Option<String>,
+    pub email: Option<String>,
+}
+
+/// URLs the buyer returns to after the checkout.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct BackUrls {
+    /// Used when the payment is approved.
+    pub success: Option<String>,
+    /// Used when the payment is pending.
+    pub pending: Option<String>,
+    /// Used when the payment is rejected.
+    pub failure: Option<String>,
+}
+
+/// Which payments redirect the buyer automatically to `back_urls`.
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoReturn {
+    /// Only approved payments.
+    Approved,
+    /// Every payment.
+    All,
+    /// For untracked values
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Payment methods and installments offered in the checkout.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PreferencePaymentMethods {
+    /// Payment methods that will not be offered.
+    #[serde(default)]
+    pub excluded_payment_methods: Vec<ExcludedPaymentMethod>,
+    /// Payment types that will not be offered.
+    #[serde(default)]
+    pub excluded_payment_types: Vec<ExcludedPaymentType>,
+    /// Payment method selected by default.
+    pub default_payment_method_id: Option<PaymentMethodId>,
+    /// Maximum number of installments.
+    pub installments: Option<u32>,
+    /// Number of installments selected by default.
+    pub default_installments: Option<u32>,
+}
+
+impl PreferencePaymentMethods {
+    /// Excludes the given payment methods
+    pub fn exclude_methods(mut self, methods: impl IntoIterator<Item = PaymentMethodId>) -> Self {
+        self.excluded_payment_methods
+            .extend(methods.into_iter().map(|id| ExcludedPaymentMethod { id }));
+        self
+    }
+
+    /// Excludes the given payment types
+    pub fn exclude_types(mut self, types: impl IntoIterator<Item = PaymentTypeId>) -> Self {
+        self.excluded_payment_types
+            .extend(types.into_iter().map(|id| ExcludedPaymentType { id }));
+        self
+    }
+}
+
+/// Payment method excluded from the checkout.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedPaymentMethod {
+    pub id: PaymentMethodId,
+}
+
+/// Payment type excluded from the checkout.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedPaymentType {
+    pub id: PaymentTypeId,
+}
+
+/// Validity interval of a preference.
+///
+/// Outside of it, the checkout does not accept payments.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct PreferenceExpiration {
+    /// Whether the preference has a validity interval.
+    pub expires: Option<bool>,
+    /// Start of the interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub expiration_date_from: Option<String>,
+    /// End of the interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub expiration_date_to: Option<String>,
+}
+
+impl PreferenceExpiration {
+    /// Creates an interval between two [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) dates
+    pub fn between(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            expires: Some(true),
+            expiration_date_from: Some(from.into()),
+            expiration_date_to: Some(to.into()),
+        }
+    }
+
+    /// Creates an interval between two dates
+    #[cfg(feature = "chrono")]
+    pub fn between_dates<Tz>(from: chrono::DateTime<Tz>, to: chrono::DateTime<Tz>) -> Self
+    where
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        Self::between(
+            crate::common::format_date(&from),
+            crate::common::format_date(&to),
+        )
+    }
+
+    /// Creates an interval from now to `duration` from now, `None` when the end would overflow
+    #[cfg(feature = "chrono")]
+    pub fn for_duration(duration: chrono::Duration) -> Option<Self> {
+        let from = chrono::Utc::now();
+        let to = from.checked_add_signed(duration)?;
+
+        Some(Self::between_dates(from, to))
+    }
+}
+
+/// # PreferenceSearchOptions
+/// Struct to use in [`PreferenceSearchBuilder`](crate::preferences::PreferenceSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PreferenceSearchOptions {
+    /// Quantity of preferences returned.
+    pub limit: Option<usize>,
+    /// Quantity of preferences to skip.
+    pub offset: Option<usize>,
+    /// External reference sent when the preference was created.
+    pub external_reference: Option<ExternalReference<PreferenceLimits>>,
+    /// ID of the site, like `"MLB"`.
+    pub site_id: Option<String>,
+    /// Marketplace the preference was created on. `"NONE"` when it was not created on a marketplace.
+    pub marketplace: Option<String>,
+    /// ID of the sponsor account, used by integrators.
+    pub sponsor_id: Option<u64>,
+    /// Sets the start of the search interval, by creation date.
+    ///
+    /// Its format can be a relative date - `"NOW-XDAYS"`, `"NOW-XMONTHS"` - or an absolute date in [`ISO8601`](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub begin_date: Option<String>,
+    /// Sets the end of the search interval, by creation date.
+    ///
+    /// Its format can be a relative date - `"NOW-XDAYS"`, `"NOW-XMONTHS"` - or an absolute date in [`ISO8601`](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub end_date: Option<String>,
+}
+
+/// # PreferenceSearchResponse
+/// Used in pagination of [`PreferenceSearchBuilder`](crate::preferences::PreferenceSearchBuilder)
+///
+/// Response from `/checkout/preferences/search`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/preferences/_checkout_preferences_search/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PreferenceSearchResponse {
+    pub elements: Vec<PartialPreferenceResult>,
+    /// Offset of the next page.
+    pub next_offset: usize,
+    /// Total number of preferences matching the search.
+    pub total: usize,
+}
+
+/// # PartialPreferenceResult
+/// Essential information of a preference.
+///
+/// Used in [`PreferenceSearchResponse`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PartialPreferenceResult {
+    pub id: PreferenceId,
+    pub client_id: Option<String>,
+    pub collector_id: Option<UserId>,
+    /// Preference create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: String,
+    /// Date when preference was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_updated: Option<String>,
+    /// Whether the preference has an expiration interval.
+    pub expires: bool,
+    /// Start of the validity interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub expiration_date_from: Option<String>,
+    /// End of the validity interval. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub expiration_date_to: Option<String>,
+    pub external_reference: Option<String>,
+    /// Titles of the items of the preference.
+    #[serde(default)]
+    pub items: Vec<String>,
+    pub live_mode: bool,
+    pub marketplace: Option<String>,
+    pub operation_type: Option<String>,
+    pub payer_email: Option<String>,
+    pub payer_id: Option<u64>,
+    pub site_id: Option<String>,
+    pub sponsor_id: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AutoReturn, PreferenceCreateOptions, PreferenceExpiration, PreferencePaymentMethods,
+    };
+    use crate::payments::types::{PaymentMethodId, PaymentTypeId};
+
+    #[test]
+    fn serializes_typed_fields() {
+        let options = PreferenceCreateOptions {
+            auto_return: Some(AutoReturn::Approved),
+            payment_methods: Some(
+                PreferencePaymentMethods {
+                    installments: Some(6),
+                    ..Default::default()
+                }
+                .exclude_methods([PaymentMethodId::Boleto])
+                .exclude_types([PaymentTypeId::Ticket]),
+            ),
+            expiration: PreferenceExpiration::between(
+                "2024-01-01T00:00:00.000-03:00",
+                "2024-01-02T00:00:00.000-03:00",
+            ),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&options).unwrap();
+
+        assert_eq!(json["auto_return"], "approved");
+        assert_eq!(json["expires"], true);
+        assert_eq!(json["expiration_date_to"], "2024-01-02T00:00:00.000-03:00");
+        assert_eq!(
+            json["payment_methods"]["excluded_payment_methods"][0]["id"],
+            "bolbradesco"
+        );
+        assert_eq!(
+            json["payment_methods"]["excluded_payment_types"][0]["id"],
+            "ticket"
+        );
+        assert!(json.get("back_urls").is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn expiration_from_chrono() {
+        use chrono::{Duration, FixedOffset, TimeZone};
+
+        let offset = FixedOffset::west_opt(3 * 3600).unwrap();
+        let expiration = PreferenceExpiration::between_dates(
+            offset.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            offset.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            expiration,
+            PreferenceExpiration::between(
+                "2024-01-01T00:00:00.000-03:00",
+                "2024-01-02T00:00:00.000-03:00",
+            )
+        );
+
+        let expiration = PreferenceExpiration::for_duration(Duration::hours(2)).unwrap();
+        assert_eq!(expiration.expires, Some(true));
+        assert!(expiration.expiration_date_from < expiration.expiration_date_to);
+
+        assert_eq!(PreferenceExpiration::for_duration(Duration::MAX), None);
+    }
+
+    #[test]
+    fn empty_auto_return_is_unknown() {
+        let auto_return = serde_json::from_str::<AutoReturn>("\"\"").unwrap();
+
+        assert_eq!(auto_return, AutoReturn::Unknown(String::new()));
+    }
+}
@@ -0,0 +1,135 @@
+//! QR images of Pix codes, rendered locally from the "copia e cola" code.
+//!
+//! ```
+//! use mpago::qr::PixQr;
+//!
+//! let qr = PixQr::new(payment.pix_qr_code().unwrap())?;
+//!
+//! let svg = qr.to_svg(256);
+//! let png = qr.to_png(8)?;
+//! ```
+
+use std::io::Cursor;
+
+use qrcode::{render::svg, types::Color, EcLevel, QrCode};
+use thiserror::Error;
+
+use crate::payments::types::PaymentResponse;
+
+/// Modules of blank border around the QR, as required by the QR specification.
+const QUIET_ZONE: usize = 4;
+
+/// Largest `module_size` of [`PixQr::to_png`], about 12000 pixels of width for the largest QR.
+pub const MAX_MODULE_SIZE: u32 = 64;
+
+/// QR of a Pix code, with the error correction level `M` recommended by the Pix specification
+pub struct PixQr {
+    code: QrCode,
+}
+
+impl PixQr {
+    /// Encodes `qr_code`, the Pix "copia e cola" code.
+    pub fn new(qr_code: &str) -> Result<Self, QrError> {
+        Ok(PixQr {
+            code: QrCode::with_error_correction_level(qr_code, EcLevel::M)?,
+        })
+    }
+
+    /// SVG document of at least `min_size` pixels of width and height.
+    pub fn to_svg(&self, min_size: u32) -> String {
+        self.code
+            .render::<svg::Color>()
+            .min_dimensions(min_size, min_size)
+            .build()
+    }
+
+    /// Grayscale PNG with `module_size` pixels per module, the quiet zone included.
+    ///
+    /// Fails with [`QrError::ModuleSizeTooLarge`] when `module_size` is over [`MAX_MODULE_SIZE`].
+    pub fn to_png(&self, module_size: u32) -> Result<Vec<u8>, QrError> {
+        if module_size > MAX_MODULE_SIZE {
+            return Err(QrError::ModuleSizeTooLarge(module_size));
+        }
+        let module_size = module_size.max(1) as usize;
+        let modules = self.code.width() + 2 * QUIET_ZONE;
+        let size = modules * module_size;
+        let colors = self.code.to_colors();
+
+        let mut pixels = vec![u8::MAX; size * size];
+        for (i, color) in colors.iter().enumerate() {
+            if *color != Color::Dark {
+                continue;
+            }
+
+            let x = (i % self.code.width() + QUIET_ZONE) * module_size;
+            let y = (i / self.code.width() + QUIET_ZONE) * module_size;
+            for row in y..y + module_size {
+                pixels[row * size + x..row * size + x + module_size].fill(0);
+            }
+        }
+
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(Cursor::new(&mut png), size as u32, size as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&pixels)?;
+
+        Ok(png)
+    }
+}
+
+impl<M> PaymentResponse<M> {
+    /// QR of the Pix code of the payment, `None` when it isn't a pending Pix payment.
+    pub fn pix_qr(&self) -> Option<Result<PixQr, QrError>> {
+        self.pix_qr_code().map(PixQr::new)
+    }
+}
+
+/// Error when rendering a [`PixQr`]
+#[derive(Error, Debug)]
+pub enum QrError {
+    #[error("Code can't be encoded as QR: {0}")]
+    Encode(#[from] qrcode::types::QrError),
+    #[error("{0}")]
+    Png(#[from] png::EncodingError),
+    #[error("Module size {0} is over the limit of {MAX_MODULE_SIZE} pixels")]
+    ModuleSizeTooLarge(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PixQr, QrError, MAX_MODULE_SIZE, QUIET_ZONE};
+    use crate::{fixtures, payments::types::PaymentResponse};
+
+    #[test]
+    fn render_pix_qr() {
+        let payment =
+            serde_json::from_str::<PaymentResponse>(fixtures::PAYMENT_APPROVED_PIX).unwrap();
+        let qr = payment.pix_qr().unwrap().unwrap();
+
+        let png = qr.to_png(4).unwrap();
+        assert_eq!(&png[..4], b"\x89PNG");
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(png));
+        let info = decoder.read_info().unwrap().info().clone();
+        assert_eq!(info.width as usize, (qr.code.width() + 2 * QUIET_ZONE) * 4);
+
+        assert!(qr.to_svg(256).starts_with("<?xml"));
+    }
+
+    #[test]
+    fn fail_with_too_large_module_size() {
+        let qr = PixQr::new("00020126580014br.gov.bcb.pix").unwrap();
+
+        assert!(qr.to_png(MAX_MODULE_SIZE).is_ok());
+        assert!(matches!(
+            qr.to_png(u32::MAX),
+            Err(QrError::ModuleSizeTooLarge(u32::MAX))
+        ));
+    }
+
+    #[test]
+    fn fail_with_too_long_code() {
+        assert!(PixQr::new(&"0".repeat(8000)).is_err());
+    }
+}
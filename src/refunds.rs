@@ -0,0 +1,10 @@
+pub use self::create_builder::RefundCreateBuilder;
+pub use self::get_builder::RefundGetBuilder;
+pub use self::list_builder::RefundListBuilder;
+pub use self::to_total_builder::RefundToTotalBuilder;
+
+mod create_builder;
+mod get_builder;
+mod list_builder;
+mod to_total_builder;
+pub mod types;
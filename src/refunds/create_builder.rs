@@ -0,0 +1,100 @@
+use std::future::Future;
+
+use reqwest::Method;
+use rust_decimal::Decimal;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
+};
+
+use super::types::{RefundCreateOptions, RefundResponse};
+
+/// Builder for refunding a payment, fully or partially
+///
+/// # Arguments
+///
+/// * `payment_id` - Unique payment identifier, automatically generated by Mercado Pago.
+/// * `amount` - Amount to refund. If `None`, refunds the full amount of the payment.
+/// * `idempotency_key` - Idempotency key is a unique value that is used to prevent duplicate processing of requests
+///
+/// # Example
+/// ```
+/// use mpago::refunds::RefundCreateBuilder;
+///
+/// RefundCreateBuilder {
+///     payment_id: 8972364.into(),
+///     amount: Some(Decimal::new(1050, 2)), // 10.50
+///     idempotency_key: Some("refund-8972364".to_string()),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/chargebacks/_payments_id_refunds/post>
+#[derive(Debug, Clone)]
+pub struct RefundCreateBuilder {
+    pub payment_id: PaymentId,
+    pub amount: Option<Decimal>,
+    pub idempotency_key: Option<String>,
+}
+
+impl RefundCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<RefundResponse, MercadoPagoRequestError> {
+        let mut req = mp_client.json_body(
+            mp_client.start_request(
+                Method::POST,
+                format!("/v1/payments/{}/refunds", self.payment_id),
+            ),
+            &RefundCreateOptions {
+                amount: self.amount,
+            },
+        )?;
+
+        if let Some(idempotency_key) = self.idempotency_key {
+            req = req.header("X-Idempotency-Key", idempotency_key);
+        }
+
+        let res = mp_client.execute(req).await?;
+
+        mp_client.resolve_json::<RefundResponse>(res).await
+    }
+}
+
+impl SendRequest for RefundCreateBuilder {
+    type Output = RefundResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        RefundCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use crate::common::create_test_client;
+
+    use super::RefundCreateBuilder;
+
+    #[tokio::test]
+    async fn fail_refund_create() {
+        let mp_client = create_test_client();
+
+        let res = RefundCreateBuilder {
+            payment_id: 1234567890.into(),
+            amount: None,
+            idempotency_key: None,
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::{PaymentId, RefundId},
+};
+
+use super::types::RefundResponse;
+
+/// Builder for get a refund of a payment
+///
+/// # Arguments
+///
+/// * `payment_id` - Unique payment identifier, automatically generated by Mercado Pago.
+/// * `refund_id` - Unique refund identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::refunds::RefundGetBuilder;
+///
+/// RefundGetBuilder(87891224.into(), 1234567.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/chargebacks/_payments_id_refunds_refund_id/get>
+#[derive(Debug, Clone)]
+pub struct RefundGetBuilder(pub PaymentId, pub RefundId);
+
+impl RefundGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<RefundResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(
+                Method::GET,
+                format!("/v1/payments/{}/refunds/{}", self.0, self.1),
+            ))
+            .await?;
+
+        mp_client.resolve_json::<RefundResponse>(res).await
+    }
+}
+
+impl SendRequest for RefundGetBuilder {
+    type Output = RefundResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        RefundGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use crate::common::create_test_client;
+
+    use super::RefundGetBuilder;
+
+    #[tokio::test]
+    async fn fail_get_refund() {
+        let mp_client = create_test_client();
+
+        let get_refund = RefundGetBuilder(1234567890.into(), 1234567890.into())
+            .send(&mp_client)
+            .await;
+
+        assert!(get_refund.is_err());
+    }
+}
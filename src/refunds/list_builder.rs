@@ -0,0 +1,62 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
+};
+
+use super::types::RefundResponse;
+
+/// Builder for list the refunds of a payment
+///
+/// # Arguments
+///
+/// * `payment_id` - Unique payment identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::refunds::RefundListBuilder;
+///
+/// RefundListBuilder(87891224.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/chargebacks/_payments_id_refunds/get>
+#[derive(Debug, Clone)]
+pub struct RefundListBuilder(pub PaymentId);
+
+impl RefundListBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Vec<RefundResponse>, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client.start_request(Method::GET, format!("/v1/payments/{}/refunds", self.0)),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Vec<RefundResponse>>(res).await
+    }
+}
+
+impl<T: Into<PaymentId>> From<T> for RefundListBuilder {
+    fn from(id: T) -> Self {
+        RefundListBuilder(id.into())
+    }
+}
+
+impl SendRequest for RefundListBuilder {
+    type Output = Vec<RefundResponse>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        RefundListBuilder::send(self, mp_client)
+    }
+}
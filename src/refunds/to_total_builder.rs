@@ -0,0 +1,153 @@
+use std::future::Future;
+
+use rust_decimal::Decimal;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::PaymentId,
+};
+
+use super::{
+    create_builder::RefundCreateBuilder,
+    list_builder::RefundListBuilder,
+    types::{RefundResponse, RefundStatus},
+};
+
+/// Builder for refunding a payment until its refunded total reaches a target
+///
+/// Lists the existing refunds and only refunds the missing difference, with an idempotency key derived from the payment and the target. This makes "refund to X" safe to retry: running it twice, or after a timeout, never refunds more than `target_total`.
+///
+/// # Arguments
+///
+/// * `payment_id` - Unique payment identifier, automatically generated by Mercado Pago.
+/// * `target_total` - Total amount that should be refunded after the request.
+///
+/// # Example
+/// ```
+/// use mpago::{refunds::RefundToTotalBuilder, Decimal};
+///
+/// let refund = RefundToTotalBuilder {
+///     payment_id: 8972364.into(),
+///     target_total: Decimal::new(30, 0),
+/// }
+/// .send(&mp_client)
+/// .await?;
+///
+/// // `None` if 30 were already refunded
+/// ```
+#[derive(Debug, Clone)]
+pub struct RefundToTotalBuilder {
+    pub payment_id: PaymentId,
+    pub target_total: Decimal,
+}
+
+impl RefundToTotalBuilder {
+    /// Send the requests
+    ///
+    /// Returns `None` when the payment was already refunded up to `target_total`.
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Option<RefundResponse>, MercadoPagoRequestError> {
+        let refunds = RefundListBuilder(self.payment_id).send(mp_client).await?;
+
+        let missing = self.target_total - refunded_total(&refunds);
+        if missing <= Decimal::ZERO {
+            return Ok(None);
+        }
+
+        RefundCreateBuilder {
+            payment_id: self.payment_id,
+            amount: Some(missing),
+            idempotency_key: Some(idempotency_key(self.payment_id, self.target_total)),
+        }
+        .send(mp_client)
+        .await
+        .map(Some)
+    }
+}
+
+impl SendRequest for RefundToTotalBuilder {
+    type Output = Option<RefundResponse>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        RefundToTotalBuilder::send(self, mp_client)
+    }
+}
+
+/// Sums the refunds that were made or are still being made.
+fn refunded_total(refunds: &[RefundResponse]) -> Decimal {
+    refunds
+        .iter()
+        .filter(|refund| {
+            !matches!(
+                refund.status,
+                RefundStatus::Rejected | RefundStatus::Cancelled
+            )
+        })
+        .map(|refund| refund.amount)
+        .sum()
+}
+
+/// Same target, same key, so `10` and `10.00` are normalized first.
+fn idempotency_key(payment_id: PaymentId, target_total: Decimal) -> String {
+    format!("refund-{}-to-{}", payment_id, target_total.normalize())
+}
+
+#[cfg(test)]
+mod ledger_tests {
+    use rust_decimal::Decimal;
+
+    use super::{idempotency_key, refunded_total};
+    use crate::refunds::types::{RefundResponse, RefundStatus};
+
+    fn refund(amount: Decimal, status: RefundStatus) -> RefundResponse {
+        RefundResponse {
+            id: 1.into(),
+            payment_id: 1.into(),
+            amount,
+            amount_refunded_to_payer: None,
+            adjustment_amount: None,
+            date_created: "2024-01-01T00:00:00.000-03:00".to_string(),
+            status,
+            source: None,
+            refund_mode: None,
+            reason: None,
+            unique_sequence_number: None,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn ignores_failed_refunds() {
+        let refunds = [
+            refund(Decimal::new(10, 0), RefundStatus::Approved),
+            refund(Decimal::new(550, 2), RefundStatus::InProcess),
+            refund(Decimal::new(20, 0), RefundStatus::Rejected),
+            refund(Decimal::new(20, 0), RefundStatus::Cancelled),
+        ];
+
+        assert_eq!(refunded_total(&refunds), Decimal::new(1550, 2));
+        assert_eq!(refunded_total(&[]), Decimal::ZERO);
+    }
+
+    #[test]
+    fn key_ignores_scale() {
+        assert_eq!(
+            idempotency_key(42.into(), Decimal::new(10, 0)),
+            idempotency_key(42.into(), Decimal::new(1000, 2))
+        );
+        assert_ne!(
+            idempotency_key(42.into(), Decimal::new(10, 0)),
+            idempotency_key(42.into(), Decimal::new(20, 0))
+        );
+        assert_eq!(
+            idempotency_key(42.into(), Decimal::new(1050, 2)),
+            "refund-42-to-10.5"
+        );
+    }
+}
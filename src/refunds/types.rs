@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::ids::{PaymentId, RefundId};
+
+/// # RefundCreateOptions
+/// Used as the request body of [`RefundCreateBuilder`](crate::refunds::RefundCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/chargebacks/_payments_id_refunds/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct RefundCreateOptions {
+    /// Amount to refund. If `None`, refunds the full amount of the payment.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub amount: Option<Decimal>,
+}
+
+/// # RefundResponse
+/// Response from `/v1/payments/{id}/refunds`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/chargebacks/_payments_id_refunds/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RefundResponse {
+    /// Unique refund identifier, automatically generated by Mercado Pago.
+    pub id: RefundId,
+    /// Identifier of the refunded payment.
+    pub payment_id: PaymentId,
+    /// Refunded amount.
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    /// Amount that was actually returned to the payer.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub amount_refunded_to_payer: Option<Decimal>,
+    /// Amount adjusted by Mercado Pago in the refund, like fees that are not returned.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub adjustment_amount: Option<Decimal>,
+    /// Refund create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: String,
+    pub status: RefundStatus,
+    /// Who requested the refund.
+    pub source: Option<RefundSource>,
+    /// Mode in which the refund was made, usually `"standard"`.
+    pub refund_mode: Option<String>,
+    /// Reason of the refund.
+    pub reason: Option<String>,
+    /// Number that identifies the refund in the card network. It can be used by the payer to track the refund with the card issuer.
+    pub unique_sequence_number: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Status of a refund
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    /// The refund was made.
+    Approved,
+    /// The refund is being processed.
+    InProcess,
+    /// The refund was rejected.
+    Rejected,
+    /// The refund was cancelled.
+    Cancelled,
+    /// The refund was authorized and waits to be made.
+    Authorized,
+    /// For untracked refund status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Who requested the refund
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RefundSource {
+    /// Identifier of the requester.
+    pub id: Option<String>,
+    /// Name of the requester.
+    pub name: Option<String>,
+    pub r#type: Option<RefundSourceType>,
+}
+
+/// Type of the refund requester
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundSourceType {
+    /// The seller who received the payment.
+    Collector,
+    /// A Mercado Pago operator.
+    Operator,
+    /// Mercado Pago administrative process.
+    Admin,
+    /// Mercado Pago's buyer protection program.
+    Bpp,
+    /// For untracked source type
+    #[serde(other)]
+    Unknown(String),
+}
@@ -0,0 +1,4 @@
+pub use self::reader::ReportReader;
+
+mod reader;
+pub mod types;
@@ -0,0 +1,46 @@
+use std::io::Read;
+
+use serde::de::DeserializeOwned;
+
+/// Parses the CSV of a report into typed records, one per row
+///
+/// The columns of a report are configured in the dashboard, so the record fields are optional, columns missing from the file are `None` and unknown columns are ignored. Download the file with [`MercadoPagoClient::download`](crate::client::MercadoPagoClient::download) first.
+///
+/// # Example
+/// ```
+/// use mpago::reports::{types::ReleaseReportRecord, ReportReader};
+///
+/// let file = std::fs::File::open("release-report.csv")?;
+///
+/// for record in ReportReader::new(file).records::<ReleaseReportRecord>() {
+///     let record = record?;
+///     println!("{:?}: {:?}", record.source_id, record.net_credit_amount);
+/// }
+/// ```
+pub struct ReportReader<R> {
+    reader: csv::Reader<R>,
+}
+
+impl<R: Read> ReportReader<R> {
+    /// Reader of a report with the default `,` separator.
+    pub fn new(reader: R) -> Self {
+        Self::with_separator(reader, b',')
+    }
+
+    /// Reader of a report with the separator configured in the report settings, like `b';'`.
+    pub fn with_separator(reader: R, separator: u8) -> Self {
+        ReportReader {
+            reader: csv::ReaderBuilder::new()
+                .delimiter(separator)
+                .trim(csv::Trim::All)
+                .from_reader(reader),
+        }
+    }
+
+    /// Records of the report, like [`ReleaseReportRecord`](super::types::ReleaseReportRecord) or a struct of your own for custom columns.
+    ///
+    /// Rows are read one at a time, so big reports are not loaded in memory.
+    pub fn records<T: DeserializeOwned>(self) -> impl Iterator<Item = Result<T, csv::Error>> {
+        self.reader.into_deserialize()
+    }
+}
@@ -0,0 +1,173 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+/// # ReleaseReportRecord
+/// Row of the release report (relatório de liberações), with the money released to the account balance
+///
+/// Used with [`ReportReader::records`](crate::reports::ReportReader::records).
+///
+/// <https://www.mercadopago.com.br/developers/pt/docs/reports/released-money/glossary>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct ReleaseReportRecord {
+    /// [ISO8601](https://pt.wikipedia.org/wiki/ISO_8601) date
+    pub date: Option<String>,
+    /// Id of the payment, refund or withdrawal of the row.
+    pub source_id: Option<String>,
+    pub external_reference: Option<String>,
+    pub record_type: Option<ReleaseRecordType>,
+    pub description: Option<String>,
+    pub net_credit_amount: Option<Decimal>,
+    pub net_debit_amount: Option<Decimal>,
+    pub gross_amount: Option<Decimal>,
+    pub mp_fee_amount: Option<Decimal>,
+    pub financing_fee_amount: Option<Decimal>,
+    pub shipping_fee_amount: Option<Decimal>,
+    pub taxes_amount: Option<Decimal>,
+    pub coupon_amount: Option<Decimal>,
+    pub installments: Option<u32>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    /// Available balance after the row.
+    pub balance_amount: Option<Decimal>,
+}
+
+/// Kind of row of the release report
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseRecordType {
+    /// Balance at the start of the period.
+    InitialAvailableBalance,
+    /// Money released to the balance.
+    Release,
+    /// Money blocked, like by a dispute.
+    Block,
+    Unblock,
+    /// Totals of the period.
+    Total,
+    AvailableBalance,
+    /// For untracked record types
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// # SettlementReportRecord
+/// Row of the settlement report (relatório de todas as transações), with every transaction of the account
+///
+/// Used with [`ReportReader::records`](crate::reports::ReportReader::records).
+///
+/// <https://www.mercadopago.com.br/developers/pt/docs/reports/account-money/glossary>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct SettlementReportRecord {
+    pub external_reference: Option<String>,
+    /// Id of the payment, refund or withdrawal of the row.
+    pub source_id: Option<String>,
+    pub user_id: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub payment_method: Option<String>,
+    pub site: Option<String>,
+    pub transaction_type: Option<SettlementTransactionType>,
+    pub transaction_amount: Option<Decimal>,
+    pub transaction_currency: Option<String>,
+    /// [ISO8601](https://pt.wikipedia.org/wiki/ISO_8601) date
+    pub transaction_date: Option<String>,
+    pub fee_amount: Option<Decimal>,
+    pub settlement_net_amount: Option<Decimal>,
+    pub settlement_currency: Option<String>,
+    /// [ISO8601](https://pt.wikipedia.org/wiki/ISO_8601) date
+    pub settlement_date: Option<String>,
+    pub real_amount: Option<Decimal>,
+    pub coupon_amount: Option<Decimal>,
+    pub mkp_fee_amount: Option<Decimal>,
+    pub financing_fee_amount: Option<Decimal>,
+    pub shipping_fee_amount: Option<Decimal>,
+    pub taxes_amount: Option<Decimal>,
+    pub installments: Option<u32>,
+    pub order_id: Option<String>,
+}
+
+/// Kind of transaction of the settlement report
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SettlementTransactionType {
+    Settlement,
+    Refund,
+    Chargeback,
+    Dispute,
+    Withdrawal,
+    WithdrawalCancel,
+    Payout,
+    /// For untracked transaction types
+    #[serde(other)]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{
+        ReleaseRecordType, ReleaseReportRecord, SettlementReportRecord, SettlementTransactionType,
+    };
+    use crate::reports::ReportReader;
+
+    #[test]
+    fn parse_release_report() {
+        let csv = "\
+DATE,SOURCE_ID,EXTERNAL_REFERENCE,RECORD_TYPE,DESCRIPTION,NET_CREDIT_AMOUNT,NET_DEBIT_AMOUNT,GROSS_AMOUNT,MP_FEE_AMOUNT,INSTALLMENTS,BALANCE_AMOUNT,EXTRA
+2024-05-01T00:00:00.000-03:00,,,initial_available_balance,,0.00,0.00,0.00,,,150.00,
+2024-05-02T13:10:26.000-03:00,87891224,order-4711,release,payment,96.01,0.00,100.00,-3.99,1,246.01,x
+";
+
+        let records = ReportReader::new(csv.as_bytes())
+            .records::<ReleaseReportRecord>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0].record_type,
+            Some(ReleaseRecordType::InitialAvailableBalance)
+        );
+        assert_eq!(records[0].source_id, None);
+        assert_eq!(records[1].source_id.as_deref(), Some("87891224"));
+        assert_eq!(records[1].mp_fee_amount, Some(Decimal::new(-399, 2)));
+        assert_eq!(records[1].balance_amount, Some(Decimal::new(24601, 2)));
+        assert_eq!(records[1].taxes_amount, None);
+    }
+
+    #[test]
+    fn parse_settlement_report_with_separator() {
+        let csv = "\
+SOURCE_ID;TRANSACTION_TYPE;TRANSACTION_AMOUNT;FEE_AMOUNT;SETTLEMENT_NET_AMOUNT;TRANSACTION_DATE
+87891224;SETTLEMENT;100.00;-3.99;96.01;2024-05-02T13:10:26.000-03:00
+87891224;REFUND;-100.00;3.99;-96.01;2024-05-03T09:00:00.000-03:00
+";
+
+        let records = ReportReader::with_separator(csv.as_bytes(), b';')
+            .records::<SettlementReportRecord>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            records[0].transaction_type,
+            Some(SettlementTransactionType::Settlement)
+        );
+        assert_eq!(
+            records[1].transaction_type,
+            Some(SettlementTransactionType::Refund)
+        );
+        assert_eq!(
+            records
+                .iter()
+                .filter_map(|record| record.settlement_net_amount)
+                .sum::<Decimal>(),
+            Decimal::ZERO
+        );
+    }
+}
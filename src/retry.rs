@@ -0,0 +1,311 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    client::{MercadoPagoClient, RateLimit, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+/// Retries of failed requests, with exponential backoff and an optional shared [`RetryBudget`].
+///
+/// Only errors where [`MercadoPagoRequestError::is_retryable`] is `true` are retried. Creating resources without an idempotency key is not safe to retry, the first attempt may have succeeded.
+///
+/// # Example
+/// ```
+/// use mpago::{client::SendRequest, payments::PaymentGetBuilder, retry::{RetryBudget, RetryPolicy}};
+///
+/// // One budget for the whole process
+/// let budget = Arc::new(RetryBudget::default());
+/// let policy = RetryPolicy::new(3).with_budget(budget.clone());
+///
+/// let payment = PaymentGetBuilder(87891224.into())
+///     .send_with_retry(&mp_client, &policy)
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    budget: Option<Arc<RetryBudget>>,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times, waiting 100ms before the first retry and doubling up to 5s.
+    pub fn new(max_retries: u32) -> Self {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            budget: None,
+        }
+    }
+
+    /// Wait `base_delay` before the first retry, doubling it on every retry up to `max_delay`.
+    pub fn with_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Only retry while `budget` allows it. Share the same budget between every policy of the process.
+    pub fn with_budget(mut self, budget: Arc<RetryBudget>) -> Self {
+        self.budget = Some(budget);
+
+        self
+    }
+
+    fn delay(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
+    }
+}
+
+/// Limits retries to a share of the requests, like the retry budget of Finagle.
+///
+/// Every request deposits `percent_can_retry` of a retry and every retry withdraws one, over a sliding window of `ttl`. A minimum of `min_retries_per_sec` is always allowed, so low traffic can still retry. When Mercado Pago is degraded and every request fails, retries stay at a fraction of the traffic instead of multiplying it.
+pub struct RetryBudget {
+    ttl: Duration,
+    min_retries_per_sec: u32,
+    percent_can_retry: f64,
+    window: Mutex<BudgetWindow>,
+    metrics_hook: Option<Box<dyn Fn(RetryBudgetState) + Send + Sync>>,
+}
+
+#[derive(Default)]
+struct BudgetWindow {
+    deposits: VecDeque<Instant>,
+    withdrawals: VecDeque<Instant>,
+    rate_limit: Option<RateLimit>,
+}
+
+/// Snapshot of a [`RetryBudget`], passed to the hook of [`RetryBudget::with_metrics_hook`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryBudgetState {
+    /// Requests in the window.
+    pub requests: usize,
+    /// Retries in the window.
+    pub retries: usize,
+    /// Retries still allowed in the window.
+    pub balance: usize,
+    /// Whether the last retry was refused by the budget.
+    pub exhausted: bool,
+    /// Quota of the access token when the last retry was asked, see [`MercadoPagoClient::rate_limit`].
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl RetryBudget {
+    /// Budget over a window of `ttl`, allowing `min_retries_per_sec` plus `percent_can_retry` (from `0.0` to `1.0`) of the requests.
+    pub fn new(ttl: Duration, min_retries_per_sec: u32, percent_can_retry: f64) -> Self {
+        RetryBudget {
+            ttl,
+            min_retries_per_sec,
+            percent_can_retry: percent_can_retry.clamp(0.0, 1.0),
+            window: Mutex::new(BudgetWindow::default()),
+            metrics_hook: None,
+        }
+    }
+
+    /// Call `hook` with the state of the budget after every retry, allowed or refused, to export it as metrics and alert when it's exhausted.
+    ///
+    /// The state carries the [`RateLimit`] of the last response, so the retries can be compared with the real quota of the account.
+    pub fn with_metrics_hook(
+        mut self,
+        hook: impl Fn(RetryBudgetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.metrics_hook = Some(Box::new(hook));
+
+        self
+    }
+
+    /// Current state of the budget.
+    pub fn state(&self) -> RetryBudgetState {
+        let mut window = self.window.lock().unwrap_or_else(|err| err.into_inner());
+        self.prune(&mut window, Instant::now());
+
+        self.state_of(&window, false)
+    }
+
+    /// Registers a request.
+    pub(crate) fn deposit(&self) {
+        let now = Instant::now();
+        let mut window = self.window.lock().unwrap_or_else(|err| err.into_inner());
+
+        self.prune(&mut window, now);
+        window.deposits.push_back(now);
+    }
+
+    /// Registers a retry if the budget allows it, with the quota reported by the API when it was asked.
+    pub(crate) fn try_withdraw(&self, rate_limit: Option<RateLimit>) -> bool {
+        let now = Instant::now();
+        let state = {
+            let mut window = self.window.lock().unwrap_or_else(|err| err.into_inner());
+            self.prune(&mut window, now);
+            window.rate_limit = rate_limit.or(window.rate_limit);
+
+            let allowed = self.state_of(&window, false).balance > 0;
+            if allowed {
+                window.withdrawals.push_back(now);
+            }
+
+            self.state_of(&window, !allowed)
+        };
+
+        if let Some(hook) = &self.metrics_hook {
+            hook(state);
+        }
+
+        !state.exhausted
+    }
+
+    fn prune(&self, window: &mut BudgetWindow, now: Instant) {
+        let expired = |at: &Instant| now.duration_since(*at) > self.ttl;
+
+        while window.deposits.front().is_some_and(expired) {
+            window.deposits.pop_front();
+        }
+        while window.withdrawals.front().is_some_and(expired) {
+            window.withdrawals.pop_front();
+        }
+    }
+
+    fn state_of(&self, window: &BudgetWindow, exhausted: bool) -> RetryBudgetState {
+        let reserve = (self.min_retries_per_sec as f64 * self.ttl.as_secs_f64()) as usize;
+        let earned = (window.deposits.len() as f64 * self.percent_can_retry) as usize;
+
+        RetryBudgetState {
+            requests: window.deposits.len(),
+            retries: window.withdrawals.len(),
+            balance: (reserve + earned).saturating_sub(window.withdrawals.len()),
+            exhausted,
+            rate_limit: window.rate_limit,
+        }
+    }
+}
+
+impl Default for RetryBudget {
+    /// Same defaults as Finagle: 10 seconds window, 10 retries per second and 20% of the requests.
+    fn default() -> Self {
+        RetryBudget::new(Duration::from_secs(10), 10, 0.2)
+    }
+}
+
+impl std::fmt::Debug for RetryBudget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryBudget")
+            .field("ttl", &self.ttl)
+            .field("min_retries_per_sec", &self.min_retries_per_sec)
+            .field("percent_can_retry", &self.percent_can_retry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sends `request`, retrying it as configured in `policy`. See [`SendRequest::send_with_retry`].
+pub async fn send_with_retry<R>(
+    request: R,
+    mp_client: &MercadoPagoClient,
+    policy: &RetryPolicy,
+) -> Result<R::Output, MercadoPagoRequestError>
+where
+    R: SendRequest + Clone,
+{
+    if let Some(budget) = &policy.budget {
+        budget.deposit();
+    }
+
+    let mut retry = 0;
+    loop {
+        let err = match request.clone().send(mp_client).await {
+            Ok(output) => return Ok(output),
+            Err(err) => err,
+        };
+
+        let allowed = retry < policy.max_retries
+            && err.is_retryable()
+            && policy
+                .budget
+                .as_ref()
+                .map_or(true, |budget| budget.try_withdraw(mp_client.rate_limit()));
+        if !allowed {
+            return Err(err);
+        }
+
+        tokio::time::sleep(policy.delay(retry)).await;
+        retry += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use super::{RetryBudget, RetryPolicy};
+    use crate::client::RateLimit;
+
+    #[test]
+    fn budget_is_a_share_of_requests() {
+        let states = Arc::new(Mutex::new(vec![]));
+        let hook_states = states.clone();
+        let budget = RetryBudget::new(Duration::from_secs(10), 0, 0.2)
+            .with_metrics_hook(move |state| hook_states.lock().unwrap().push(state));
+
+        for _ in 0..10 {
+            budget.deposit();
+        }
+
+        assert!(budget.try_withdraw(None));
+        assert!(budget.try_withdraw(None));
+        assert!(!budget.try_withdraw(None));
+
+        let states = states.lock().unwrap();
+        assert_eq!(states.len(), 3);
+        assert_eq!(states[1].balance, 0);
+        assert!(!states[1].exhausted);
+        assert!(states[2].exhausted);
+        assert_eq!(budget.state().retries, 2);
+    }
+
+    #[test]
+    fn budget_reports_the_last_rate_limit() {
+        let budget = RetryBudget::new(Duration::from_secs(2), 1, 0.0);
+        let rate_limit = RateLimit {
+            limit: Some(1000),
+            remaining: Some(0),
+            reset: Some(60),
+        };
+
+        assert!(budget.try_withdraw(Some(rate_limit)));
+        assert!(budget.try_withdraw(None));
+        assert_eq!(budget.state().rate_limit, Some(rate_limit));
+    }
+
+    #[test]
+    fn budget_has_a_reserve() {
+        let budget = RetryBudget::new(Duration::from_secs(2), 1, 0.0);
+
+        assert_eq!(budget.state().balance, 2);
+        assert!(budget.try_withdraw(None));
+        assert!(budget.try_withdraw(None));
+        assert!(!budget.try_withdraw(None));
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::new(5)
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(500));
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(2), Duration::from_millis(400));
+        assert_eq!(policy.delay(3), Duration::from_millis(500));
+        assert_eq!(policy.delay(40), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,12 @@
+pub use self::create_builder::StoreCreateBuilder;
+pub use self::delete_builder::StoreDeleteBuilder;
+pub use self::get_builder::StoreGetBuilder;
+pub use self::search_builder::StoreSearchBuilder;
+pub use self::update_builder::StoreUpdateBuilder;
+
+mod create_builder;
+mod delete_builder;
+mod get_builder;
+mod search_builder;
+pub mod types;
+mod update_builder;
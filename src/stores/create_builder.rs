@@ -0,0 +1,104 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+};
+
+use super::types::{Store, StoreCreateOptions};
+
+/// Builder for creating a physical store of a seller
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that owns the store.
+/// * `options` - Name, location and business hours of the store.
+///
+/// # Example
+/// ```
+/// use mpago::stores::{types::{BusinessHours, OpeningHours, StoreCreateOptions, StoreLocation}, StoreCreateBuilder};
+///
+/// StoreCreateBuilder {
+///     user_id: 1234567890.into(),
+///     options: StoreCreateOptions::new(
+///         "Loja Centro",
+///         StoreLocation {
+///             street_number: "3039".to_string(),
+///             street_name: "Avenida Paulista".to_string(),
+///             city_name: "São Paulo".to_string(),
+///             state_name: "São Paulo".to_string(),
+///             latitude: -23.5614,
+///             longitude: -46.6559,
+///             ..Default::default()
+///         },
+///     )
+///     .with_business_hours(BusinessHours {
+///         monday: Some(vec![OpeningHours::new("08:00", "18:00")]),
+///         ..Default::default()
+///     })
+///     .with_external_id("SUC001"),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores/post>
+#[derive(Debug, Clone)]
+pub struct StoreCreateBuilder {
+    pub user_id: UserId,
+    pub options: StoreCreateOptions,
+}
+
+impl StoreCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Store, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, format!("/users/{}/stores", self.user_id)),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<Store>(res).await
+    }
+}
+
+impl SendRequest for StoreCreateBuilder {
+    type Output = Store;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StoreCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::StoreCreateBuilder;
+    use crate::{
+        common::create_test_client,
+        stores::types::{StoreCreateOptions, StoreLocation},
+    };
+
+    #[tokio::test]
+    async fn fail_without_location() {
+        let mp_client = create_test_client();
+
+        let res = StoreCreateBuilder {
+            user_id: 1.into(),
+            options: StoreCreateOptions::new("Loja", StoreLocation::default()),
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::{StoreId, UserId},
+};
+
+use super::types::Store;
+
+/// Builder for deleting a store
+///
+/// Returns the deleted store.
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that owns the store.
+/// * `id` - Id of the store, generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::stores::StoreDeleteBuilder;
+///
+/// StoreDeleteBuilder {
+///     user_id: 1234567890.into(),
+///     id: "1234567".into(),
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores_id/delete>
+#[derive(Debug, Clone)]
+pub struct StoreDeleteBuilder {
+    pub user_id: UserId,
+    pub id: StoreId,
+}
+
+impl StoreDeleteBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Store, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(
+                Method::DELETE,
+                format!("/users/{}/stores/{}", self.user_id, self.id),
+            ))
+            .await?;
+
+        mp_client.resolve_json::<Store>(res).await
+    }
+}
+
+impl SendRequest for StoreDeleteBuilder {
+    type Output = Store;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StoreDeleteBuilder::send(self, mp_client)
+    }
+}
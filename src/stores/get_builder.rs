@@ -0,0 +1,76 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::StoreId,
+};
+
+use super::types::Store;
+
+/// Builder for getting a store
+///
+/// # Arguments
+///
+/// * `id` - Id of the store, generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::stores::StoreGetBuilder;
+///
+/// StoreGetBuilder("1234567".into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_stores_id/get>
+#[derive(Debug, Clone)]
+pub struct StoreGetBuilder(pub StoreId);
+
+impl StoreGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Store, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/stores/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<Store>(res).await
+    }
+}
+
+impl<T: Into<StoreId>> From<T> for StoreGetBuilder {
+    fn from(id: T) -> Self {
+        StoreGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for StoreGetBuilder {
+    type Output = Store;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StoreGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::StoreGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_with_unknown_store() {
+        let mp_client = create_test_client();
+
+        let res = StoreGetBuilder("0".into()).send(&mp_client).await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+};
+
+use super::types::{StoreSearchOptions, StoreSearchResponse};
+
+/// Builder for search the stores of a seller
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that owns the stores.
+/// * `options` - Options to search for stores.
+///
+/// # Example
+/// ```
+/// use mpago::stores::{types::StoreSearchOptions, StoreSearchBuilder};
+///
+/// StoreSearchBuilder {
+///     user_id: 1234567890.into(),
+///     options: StoreSearchOptions {
+///         external_id: Some("SUC001".to_string()),
+///         ..Default::default()
+///     },
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores_search/get>
+#[derive(Debug, Clone)]
+pub struct StoreSearchBuilder {
+    pub user_id: UserId,
+    pub options: StoreSearchOptions,
+}
+
+impl StoreSearchBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<StoreSearchResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_request(
+                        Method::GET,
+                        format!("/users/{}/stores/search", self.user_id),
+                    )
+                    .query(&self.options),
+            )
+            .await?;
+
+        mp_client.resolve_json::<StoreSearchResponse>(res).await
+    }
+}
+
+impl SendRequest for StoreSearchBuilder {
+    type Output = StoreSearchResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StoreSearchBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::StoreSearchBuilder;
+    use crate::{common::create_test_client, stores::types::StoreSearchOptions};
+
+    #[tokio::test]
+    async fn fail_with_other_user() {
+        let mp_client = create_test_client();
+
+        let res = StoreSearchBuilder {
+            user_id: 1.into(),
+            options: StoreSearchOptions {
+                limit: Some(1),
+                ..Default::default()
+            },
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{ids::StoreId, payments::types::Paging};
+
+/// # StoreCreateOptions
+/// Struct to use in [`StoreCreateBuilder`](crate::stores::StoreCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StoreCreateOptions {
+    pub name: String,
+    pub business_hours: Option<BusinessHours>,
+    pub location: StoreLocation,
+    /// Id of the store in your system, used to link its points of sale.
+    pub external_id: Option<String>,
+}
+
+impl StoreCreateOptions {
+    /// Store named `name` at `location`, without business hours.
+    pub fn new(name: impl ToString, location: StoreLocation) -> Self {
+        StoreCreateOptions {
+            name: name.to_string(),
+            business_hours: None,
+            location,
+            external_id: None,
+        }
+    }
+
+    /// Sets `business_hours`.
+    pub fn with_business_hours(mut self, business_hours: BusinessHours) -> Self {
+        self.business_hours = Some(business_hours);
+
+        self
+    }
+
+    /// Sets `external_id`.
+    pub fn with_external_id(mut self, external_id: impl ToString) -> Self {
+        self.external_id = Some(external_id.to_string());
+
+        self
+    }
+}
+
+/// # StoreUpdateOptions
+/// Struct to use in [`StoreUpdateBuilder`](crate::stores::StoreUpdateBuilder)
+///
+/// Only the fields set are updated.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores_id/put>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct StoreUpdateOptions {
+    pub name: Option<String>,
+    pub business_hours: Option<BusinessHours>,
+    pub location: Option<StoreLocation>,
+    pub external_id: Option<String>,
+}
+
+/// Opening hours of a store for each day of the week. Days without hours are closed.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct BusinessHours {
+    pub monday: Option<Vec<OpeningHours>>,
+    pub tuesday: Option<Vec<OpeningHours>>,
+    pub wednesday: Option<Vec<OpeningHours>>,
+    pub thursday: Option<Vec<OpeningHours>>,
+    pub friday: Option<Vec<OpeningHours>>,
+    pub saturday: Option<Vec<OpeningHours>>,
+    pub sunday: Option<Vec<OpeningHours>>,
+}
+
+/// Period a store is open, like `08:00` to `12:00`. A day accepts at most two periods.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OpeningHours {
+    /// Opening time, in `HH:MM` format.
+    pub open: String,
+    /// Closing time, in `HH:MM` format.
+    pub close: String,
+}
+
+impl OpeningHours {
+    pub fn new(open: impl ToString, close: impl ToString) -> Self {
+        OpeningHours {
+            open: open.to_string(),
+            close: close.to_string(),
+        }
+    }
+}
+
+/// Address of a store.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct StoreLocation {
+    pub street_number: String,
+    pub street_name: String,
+    pub city_name: String,
+    /// Name of the state, as in the Mercado Pago list of cities, like `"São Paulo"`.
+    pub state_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Reference to help find the store, like a landmark.
+    pub reference: Option<String>,
+    /// Full address, only in responses.
+    pub address_line: Option<String>,
+}
+
+/// # Store
+/// Response from `/stores/{id}` and `/users/{user_id}/stores`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Store {
+    pub id: StoreId,
+    pub name: String,
+    /// Creation date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_creation: Option<String>,
+    #[serde(default)]
+    pub business_hours: BusinessHours,
+    pub location: Option<StoreLocation>,
+    pub external_id: Option<String>,
+}
+
+/// # StoreSearchOptions
+/// Struct to use in [`StoreSearchBuilder`](crate::stores::StoreSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct StoreSearchOptions {
+    /// Quantity of stores returned.
+    pub limit: Option<usize>,
+    /// Quantity of stores to skip.
+    pub offset: Option<usize>,
+    pub external_id: Option<String>,
+}
+
+/// # StoreSearchResponse
+/// Response from `/users/{user_id}/stores/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StoreSearchResponse {
+    pub paging: Paging,
+    pub results: Vec<Store>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BusinessHours, OpeningHours, Store, StoreCreateOptions, StoreLocation};
+
+    #[test]
+    fn create_body() {
+        let options = StoreCreateOptions::new(
+            "Loja Centro",
+            StoreLocation {
+                street_number: "3039".to_string(),
+                street_name: "Avenida Paulista".to_string(),
+                city_name: "São Paulo".to_string(),
+                state_name: "São Paulo".to_string(),
+                latitude: -23.5614,
+                longitude: -46.6559,
+                ..Default::default()
+            },
+        )
+        .with_business_hours(BusinessHours {
+            monday: Some(vec![
+                OpeningHours::new("08:00", "12:00"),
+                OpeningHours::new("14:00", "18:00"),
+            ]),
+            ..Default::default()
+        })
+        .with_external_id("SUC001");
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "name": "Loja Centro",
+                "business_hours": {
+                    "monday": [{"open": "08:00", "close": "12:00"}, {"open": "14:00", "close": "18:00"}]
+                },
+                "location": {
+                    "street_number": "3039",
+                    "street_name": "Avenida Paulista",
+                    "city_name": "São Paulo",
+                    "state_name": "São Paulo",
+                    "latitude": -23.5614,
+                    "longitude": -46.6559
+                },
+                "external_id": "SUC001"
+            })
+        );
+    }
+
+    #[test]
+    fn parse_store() {
+        let store = serde_json::from_str::<Store>(
+            r#"{
+                "id": "1234567",
+                "name": "Loja Centro",
+                "date_creation": "2024-05-02T13:10:26.000Z",
+                "business_hours": {
+                    "monday": [{"open": "08:00", "close": "12:00"}]
+                },
+                "location": {
+                    "address_line": "Avenida Paulista, 3039, São Paulo, São Paulo",
+                    "reference": "Próximo ao metrô",
+                    "latitude": -23.5614,
+                    "longitude": -46.6559,
+                    "id": "BR-SP",
+                    "type": "city",
+                    "city_name": "São Paulo",
+                    "state_name": "São Paulo",
+                    "street_number": "3039",
+                    "street_name": "Avenida Paulista"
+                },
+                "external_id": "SUC001"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(store.id, "1234567");
+        assert_eq!(
+            store.business_hours.monday,
+            Some(vec![OpeningHours::new("08:00", "12:00")])
+        );
+        assert!(store.business_hours.tuesday.is_none());
+        assert_eq!(store.location.unwrap().street_number, "3039");
+    }
+}
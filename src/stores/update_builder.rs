@@ -0,0 +1,101 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::{StoreId, UserId},
+};
+
+use super::types::{Store, StoreUpdateOptions};
+
+/// Builder for updating a store
+///
+/// # Arguments
+///
+/// * `user_id` - User id of the seller that owns the store.
+/// * `id` - Id of the store, generated by Mercado Pago.
+/// * `options` - Fields to update.
+///
+/// # Example
+/// ```
+/// use mpago::stores::{types::{BusinessHours, OpeningHours, StoreUpdateOptions}, StoreUpdateBuilder};
+///
+/// StoreUpdateBuilder {
+///     user_id: 1234567890.into(),
+///     id: "1234567".into(),
+///     options: StoreUpdateOptions {
+///         business_hours: Some(BusinessHours {
+///             saturday: Some(vec![OpeningHours::new("09:00", "13:00")]),
+///             ..Default::default()
+///         }),
+///         ..Default::default()
+///     },
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/stores/_users_user_id_stores_id/put>
+#[derive(Debug, Clone)]
+pub struct StoreUpdateBuilder {
+    pub user_id: UserId,
+    pub id: StoreId,
+    pub options: StoreUpdateOptions,
+}
+
+impl StoreUpdateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Store, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(
+                    Method::PUT,
+                    format!("/users/{}/stores/{}", self.user_id, self.id),
+                ),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<Store>(res).await
+    }
+}
+
+impl SendRequest for StoreUpdateBuilder {
+    type Output = Store;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        StoreUpdateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::StoreUpdateBuilder;
+    use crate::{common::create_test_client, stores::types::StoreUpdateOptions};
+
+    #[tokio::test]
+    async fn fail_with_unknown_store() {
+        let mp_client = create_test_client();
+
+        let res = StoreUpdateBuilder {
+            user_id: 1.into(),
+            id: "0".into(),
+            options: StoreUpdateOptions {
+                name: Some("Loja".to_string()),
+                ..Default::default()
+            },
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,13 @@
+pub use self::create_builder::SubscriptionCreateBuilder;
+pub use self::get_builder::SubscriptionGetBuilder;
+pub use self::invoice_builder::{InvoiceGetBuilder, InvoiceRetryBuilder};
+pub use self::search_builder::SubscriptionSearchBuilder;
+pub use self::update_builder::SubscriptionUpdateBuilder;
+
+mod create_builder;
+mod get_builder;
+mod invoice_builder;
+mod payments_stream;
+mod search_builder;
+pub mod types;
+mod update_builder;
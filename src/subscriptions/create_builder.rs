@@ -0,0 +1,275 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    plans::types::FreeTrial,
+};
+
+use super::types::{
+    Subscription, SubscriptionAutoRecurring, SubscriptionBilling, SubscriptionCreateOptions,
+    SubscriptionStatus, SubscriptionValidationError,
+};
+
+/// Builder for creating a subscription
+///
+/// # Arguments
+///
+/// * `options` - Options to create the subscription.
+///
+/// # Example
+/// ```
+/// use mpago::subscriptions::SubscriptionCreateBuilder;
+///
+/// SubscriptionCreateBuilder::with_trial(
+///     "Plano mensal",
+///     SubscriptionAutoRecurring {
+///         frequency: 1,
+///         frequency_type: FrequencyType::Months,
+///         transaction_amount: Some(Decimal::new(50, 0)),
+///         currency_id: CurrencyId::BRL,
+///         start_date: None,
+///         end_date: None,
+///         free_trial: None,
+///     },
+///     "test_user@testmail.com",
+///     card_token.id,
+///     FreeTrial {
+///         frequency: 7,
+///         frequency_type: FrequencyType::Days,
+///     },
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval/post>
+#[derive(Debug, Clone)]
+pub struct SubscriptionCreateBuilder(pub SubscriptionCreateOptions);
+
+impl SubscriptionCreateBuilder {
+    /// Pending subscription, the subscriber authorizes it in the checkout of [`Subscription::init_point`].
+    pub fn pending(billing: SubscriptionBilling, payer_email: impl ToString) -> Self {
+        let mut options = SubscriptionCreateOptions {
+            payer_email: payer_email.to_string(),
+            status: Some(SubscriptionStatus::Pending),
+            ..Default::default()
+        };
+        match billing {
+            SubscriptionBilling::Plan(plan_id) => options.preapproval_plan_id = Some(plan_id),
+            SubscriptionBilling::Recurring {
+                reason,
+                auto_recurring,
+            } => {
+                options.reason = Some(reason);
+                options.auto_recurring = Some(auto_recurring);
+            }
+        }
+
+        SubscriptionCreateBuilder(options)
+    }
+
+    /// Authorized subscription, charging the card of `card_token_id` right away.
+    pub fn with_card(
+        billing: SubscriptionBilling,
+        payer_email: impl ToString,
+        card_token_id: impl ToString,
+    ) -> Self {
+        let mut builder = Self::pending(billing, payer_email);
+        builder.0.card_token_id = Some(card_token_id.to_string());
+        builder.0.status = Some(SubscriptionStatus::Authorized);
+        builder
+    }
+
+    /// Authorized subscription with the card of `card_token_id`, charged only after the `trial`.
+    ///
+    /// The trial is part of the recurrence, so it only takes a recurrence of its own. A subscription with a plan gets the trial of the plan.
+    pub fn with_trial(
+        reason: impl ToString,
+        auto_recurring: SubscriptionAutoRecurring,
+        payer_email: impl ToString,
+        card_token_id: impl ToString,
+        trial: FreeTrial,
+    ) -> Self {
+        let billing = SubscriptionBilling::Recurring {
+            reason: reason.to_string(),
+            auto_recurring: SubscriptionAutoRecurring {
+                free_trial: Some(trial),
+                ..auto_recurring
+            },
+        };
+
+        Self::with_card(billing, payer_email, card_token_id)
+    }
+
+    /// Checks that an authorized subscription has a card token, that a subscription without a plan has its recurrence and that a subscription with a plan has no trial of its own.
+    pub fn validate(&self) -> Result<(), SubscriptionValidationError> {
+        let options = &self.0;
+
+        if options.status == Some(SubscriptionStatus::Authorized) && options.card_token_id.is_none()
+        {
+            return Err(SubscriptionValidationError::MissingCardToken);
+        }
+        if options.preapproval_plan_id.is_none()
+            && (options.reason.is_none() || options.auto_recurring.is_none())
+        {
+            return Err(SubscriptionValidationError::MissingRecurrence);
+        }
+        if options.preapproval_plan_id.is_some()
+            && options
+                .auto_recurring
+                .as_ref()
+                .is_some_and(|auto_recurring| auto_recurring.free_trial.is_some())
+        {
+            return Err(SubscriptionValidationError::TrialWithPlan);
+        }
+
+        Ok(())
+    }
+
+    /// Send the request, after checking it with [`validate`](Self::validate)
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.validate()?;
+
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/preapproval"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<Subscription>(res).await
+    }
+}
+
+impl SendRequest for SubscriptionCreateBuilder {
+    type Output = Subscription;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        SubscriptionCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use rust_decimal::Decimal;
+
+    use super::SubscriptionCreateBuilder;
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        common::MercadoPagoRequestError,
+        payments::types::CurrencyId,
+        plans::types::{FreeTrial, FrequencyType},
+        subscriptions::types::{
+            SubscriptionAutoRecurring, SubscriptionBilling, SubscriptionStatus,
+            SubscriptionValidationError,
+        },
+        test_support::serve,
+    };
+
+    fn monthly_recurrence() -> SubscriptionAutoRecurring {
+        SubscriptionAutoRecurring {
+            frequency: 1,
+            frequency_type: FrequencyType::Months,
+            transaction_amount: Some(Decimal::new(50, 0)),
+            currency_id: CurrencyId::BRL,
+            start_date: None,
+            end_date: None,
+            free_trial: None,
+        }
+    }
+
+    fn monthly() -> SubscriptionBilling {
+        SubscriptionBilling::Recurring {
+            reason: "Plano mensal".to_string(),
+            auto_recurring: monthly_recurrence(),
+        }
+    }
+
+    fn trial() -> FreeTrial {
+        FreeTrial {
+            frequency: 7,
+            frequency_type: FrequencyType::Days,
+        }
+    }
+
+    #[test]
+    fn trial_is_authorized_with_card() {
+        let builder = SubscriptionCreateBuilder::with_trial(
+            "Plano mensal",
+            monthly_recurrence(),
+            "test_user@testmail.com",
+            "9b2d63e00d66a8c721607214cedaecda",
+            trial(),
+        );
+
+        assert_eq!(builder.validate(), Ok(()));
+        assert_eq!(builder.0.reason.as_deref(), Some("Plano mensal"));
+        assert_eq!(builder.0.status, Some(SubscriptionStatus::Authorized));
+        assert_eq!(
+            builder
+                .0
+                .auto_recurring
+                .unwrap()
+                .free_trial
+                .unwrap()
+                .frequency,
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn trial_with_plan_is_not_sent() {
+        let url = serve(|_| panic!("an invalid subscription was sent")).await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+
+        let mut builder = SubscriptionCreateBuilder::with_card(
+            SubscriptionBilling::Plan("2c938084726fca480172750000000000".into()),
+            "test_user@testmail.com",
+            "9b2d63e00d66a8c721607214cedaecda",
+        );
+        builder.0.auto_recurring = Some(SubscriptionAutoRecurring {
+            free_trial: Some(trial()),
+            ..monthly_recurrence()
+        });
+
+        assert!(matches!(
+            builder.send(&mp_client).await,
+            Err(MercadoPagoRequestError::SubscriptionValidation(
+                SubscriptionValidationError::TrialWithPlan
+            ))
+        ));
+    }
+
+    #[test]
+    fn authorized_without_card_token() {
+        let mut builder = SubscriptionCreateBuilder::pending(monthly(), "test_user@testmail.com");
+        assert_eq!(builder.validate(), Ok(()));
+
+        builder.0.status = Some(SubscriptionStatus::Authorized);
+        assert_eq!(
+            builder.validate(),
+            Err(SubscriptionValidationError::MissingCardToken)
+        );
+    }
+
+    #[test]
+    fn recurrence_without_plan() {
+        let mut builder = SubscriptionCreateBuilder::pending(monthly(), "test_user@testmail.com");
+        builder.0.auto_recurring = None;
+
+        assert_eq!(
+            builder.validate(),
+            Err(SubscriptionValidationError::MissingRecurrence)
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::SubscriptionId,
+};
+
+use super::types::Subscription;
+
+/// Builder for get a subscription
+///
+/// # Arguments
+///
+/// * `subscription_id` - Unique subscription identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::subscriptions::SubscriptionGetBuilder;
+///
+/// SubscriptionGetBuilder("2c938084726fca480172750000000000".into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_id/get>
+#[derive(Debug, Clone)]
+pub struct SubscriptionGetBuilder(pub SubscriptionId);
+
+impl SubscriptionGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, format!("/preapproval/{}", self.0)))
+            .await?;
+
+        mp_client.resolve_json::<Subscription>(res).await
+    }
+}
+
+impl<T: Into<SubscriptionId>> From<T> for SubscriptionGetBuilder {
+    fn from(id: T) -> Self {
+        SubscriptionGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for SubscriptionGetBuilder {
+    type Output = Subscription;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        SubscriptionGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::SubscriptionGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_get_subscription() {
+        let mp_client = create_test_client();
+
+        let res = SubscriptionGetBuilder("invalid".into())
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
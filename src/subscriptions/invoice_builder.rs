@@ -0,0 +1,143 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::InvoiceId,
+};
+
+use super::types::{Invoice, InvoiceRetryError};
+
+/// Builder for get an invoice (authorized payment) of a subscription
+///
+/// # Arguments
+///
+/// * `invoice_id` - Unique invoice identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::subscriptions::InvoiceGetBuilder;
+///
+/// InvoiceGetBuilder(6114264375.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_authorized_payments_id/get>
+#[derive(Debug, Clone)]
+pub struct InvoiceGetBuilder(pub InvoiceId);
+
+impl InvoiceGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Invoice, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client.start_request(Method::GET, format!("/authorized_payments/{}", self.0)),
+            )
+            .await?;
+
+        mp_client.resolve_json::<Invoice>(res).await
+    }
+}
+
+impl<T: Into<InvoiceId>> From<T> for InvoiceGetBuilder {
+    fn from(id: T) -> Self {
+        InvoiceGetBuilder(id.into())
+    }
+}
+
+impl SendRequest for InvoiceGetBuilder {
+    type Output = Invoice;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        InvoiceGetBuilder::send(self, mp_client)
+    }
+}
+
+/// Builder for retrying the charge of a failed invoice
+///
+/// Prefer [`Invoice::retry`], which checks if the invoice can be retried before sending the request.
+///
+/// # Arguments
+///
+/// * `invoice_id` - Unique invoice identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::subscriptions::InvoiceRetryBuilder;
+///
+/// InvoiceRetryBuilder(6114264375.into())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_authorized_payments_id/get>
+#[derive(Debug, Clone)]
+pub struct InvoiceRetryBuilder(pub InvoiceId);
+
+impl InvoiceRetryBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Invoice, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(
+                Method::POST,
+                format!("/authorized_payments/{}/retry", self.0),
+            ))
+            .await?;
+
+        mp_client.resolve_json::<Invoice>(res).await
+    }
+}
+
+impl<T: Into<InvoiceId>> From<T> for InvoiceRetryBuilder {
+    fn from(id: T) -> Self {
+        InvoiceRetryBuilder(id.into())
+    }
+}
+
+impl SendRequest for InvoiceRetryBuilder {
+    type Output = Invoice;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        InvoiceRetryBuilder::send(self, mp_client)
+    }
+}
+
+impl Invoice {
+    /// Send a request to charge the invoice again
+    ///
+    /// Fails with [`InvoiceRetryError::NotRetryable`] without sending a request when the invoice was not charged yet, was already paid or was cancelled.
+    pub async fn retry(&self, mp_client: &MercadoPagoClient) -> Result<Invoice, InvoiceRetryError> {
+        self.check_retryable()?;
+
+        Ok(InvoiceRetryBuilder(self.id).send(mp_client).await?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::InvoiceGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_get_invoice() {
+        let mp_client = create_test_client();
+
+        let res = InvoiceGetBuilder(1.into()).send(&mp_client).await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use std::pin::Pin;
+
+use futures_core::Stream;
+
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    payments::{
+        types::{PartialPaymentResult, PaymentSearchOptions},
+        PaymentSearchBuilder,
+    },
+};
+
+use super::types::Subscription;
+
+impl Subscription {
+    /// Creates a stream of the payments charged by the subscription, it goes through all the pages.
+    ///
+    /// Works like [`PaymentSearchBuilder::fetch_all_streamed`], filtering `/v1/payments/search` by the subscription id.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut payments = subscription.fetch_payments(&mp_client).await;
+    ///
+    /// while let Some(payment) = payments.next().await {
+    ///     println!("{:?}", payment?.status);
+    /// }
+    /// ```
+    pub async fn fetch_payments<'a>(
+        &self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Pin<
+        Box<dyn Stream<Item = Result<PartialPaymentResult, MercadoPagoRequestError>> + Send + 'a>,
+    > {
+        PaymentSearchBuilder(self.payments_search_options())
+            .fetch_all_streamed(mp_client)
+            .await
+    }
+
+    /// Search options matching the payments of the subscription.
+    fn payments_search_options(&self) -> PaymentSearchOptions {
+        PaymentSearchOptions {
+            preapproval_id: Some(self.id.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use crate::{client::MercadoPagoClientBuilder, subscriptions::types::Subscription};
+
+    #[test]
+    fn payments_filtered_by_subscription() {
+        let subscription: Subscription = serde_json::from_value(serde_json::json!({
+            "id": "2c938084726fca480172750000000000",
+            "collector_id": 1,
+            "payer_id": 2,
+            "reason": "Plano mensal",
+            "auto_recurring": { "frequency": 1, "frequency_type": "months", "currency_id": "BRL" },
+            "status": "authorized",
+            "date_created": "2024-01-01T00:00:00.000-03:00",
+        }))
+        .unwrap();
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+
+        let request = mp_client
+            .start_request(reqwest::Method::GET, "/v1/payments/search")
+            .query(&subscription.payments_search_options())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.url().query(),
+            Some("preapproval_id=2c938084726fca480172750000000000")
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use crate::{common::create_test_client, subscriptions::SubscriptionGetBuilder};
+
+    #[tokio::test]
+    async fn fetch_subscription_payments() {
+        let mp_client = create_test_client();
+
+        let subscription =
+            SubscriptionGetBuilder(std::env::var("MERCADO_PAGO_SUBSCRIPTION").unwrap().into())
+                .send(&mp_client)
+                .await
+                .unwrap();
+
+        let mut payments = subscription.fetch_payments(&mp_client).await;
+
+        while let Some(payment) = payments.next().await {
+            assert!(payment.is_ok());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use futures_core::Stream;
+use std::{borrow::Borrow, pin::Pin};
+
+use super::types::{Subscription, SubscriptionSearchParams, SubscriptionSearchResponse};
+use crate::{
+    client::MercadoPagoClient,
+    common::MercadoPagoRequestError,
+    pagination::{collect_all, paginate, search_query, SearchPage, SearchStream},
+};
+
+/// Builder for search subscriptions
+///
+/// # Arguments
+///
+/// * `options` - Options to search for subscriptions
+///
+/// # Example
+/// ```
+/// use mpago::subscriptions::SubscriptionSearchBuilder;
+///
+/// SubscriptionSearchBuilder(
+///     SubscriptionSearchParams {
+///         status: Some(SubscriptionStatus::Authorized),
+///         ..Default::default()
+///     }
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_search/get>
+#[derive(Debug, Clone)]
+pub struct SubscriptionSearchBuilder(pub SubscriptionSearchParams);
+
+impl SubscriptionSearchBuilder {
+    /// Streams the subscriptions, fetching the next page once the current one is consumed.
+    ///
+    /// The stream ends after yielding an error.
+    pub async fn fetch_all_streamed<'a>(
+        self,
+        mp_client: &'a MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<Subscription, MercadoPagoRequestError>> + Send + 'a>>
+    {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Same as [`fetch_all_streamed`](Self::fetch_all_streamed), but the stream owns the client.
+    pub async fn fetch_all_streamed_owned(
+        self,
+        mp_client: MercadoPagoClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<Subscription, MercadoPagoRequestError>> + Send>> {
+        stream_pages(self.0, mp_client)
+    }
+
+    /// Collects the subscriptions of every page, up to `max_items`, or returns the first error.
+    pub async fn collect_all(
+        self,
+        mp_client: &MercadoPagoClient,
+        max_items: Option<usize>,
+    ) -> Result<Vec<Subscription>, MercadoPagoRequestError> {
+        collect_all(stream_pages(self.0, mp_client), max_items).await
+    }
+}
+
+search_query!(SubscriptionSearchParams);
+
+impl SearchPage for SubscriptionSearchResponse {
+    type Item = Subscription;
+
+    fn into_results(self) -> (Vec<Self::Item>, usize) {
+        (self.results, self.paging.total)
+    }
+}
+
+/// Streams every result matching `options`, going through all the pages.
+fn stream_pages<'a, C>(
+    options: SubscriptionSearchParams,
+    mp_client: C,
+) -> SearchStream<'a, Subscription>
+where
+    C: Borrow<MercadoPagoClient> + Send + Sync + 'a,
+{
+    paginate::<SubscriptionSearchResponse, _, _>("/preapproval/search", options, mp_client)
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::{SubscriptionSearchBuilder, SubscriptionSearchParams};
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn search_subscriptions() {
+        let mp_client = create_test_client();
+
+        let subscriptions = SubscriptionSearchBuilder(SubscriptionSearchParams {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .collect_all(&mp_client, Some(3))
+        .await
+        .unwrap();
+
+        assert!(subscriptions.len() <= 3);
+    }
+}
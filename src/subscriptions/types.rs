@@ -0,0 +1,489 @@
+use reqwest::Url;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+use thiserror::Error;
+
+use crate::{
+    common::{ExternalReference, MercadoPagoError, MercadoPagoRequestError, SubscriptionLimits},
+    ids::{CardId, InvoiceId, PaymentId, PlanId, SubscriptionId, UserId},
+    payments::types::{CurrencyId, Paging, PaymentSearchCriteria, PaymentStatus},
+    plans::types::{FreeTrial, FrequencyType},
+};
+
+/// # SubscriptionCreateOptions
+/// Struct to use in [`SubscriptionCreateBuilder`](crate::subscriptions::SubscriptionCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct SubscriptionCreateOptions {
+    /// Plan of the subscription. Without a plan, `reason` and `auto_recurring` are required.
+    pub preapproval_plan_id: Option<PlanId>,
+    /// Description of the subscription, shown to the subscriber.
+    pub reason: Option<String>,
+    pub external_reference: Option<ExternalReference<SubscriptionLimits>>,
+    pub payer_email: String,
+    /// Token of the card that will be charged, see [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder). Required when `status` is `Authorized`.
+    pub card_token_id: Option<String>,
+    pub auto_recurring: Option<SubscriptionAutoRecurring>,
+    pub back_url: Option<String>,
+    /// `Authorized` charges the card right away, `Pending` waits for the subscriber to authorize it in the checkout.
+    pub status: Option<SubscriptionStatus>,
+}
+
+/// What a new subscription charges, see [`SubscriptionCreateBuilder`](crate::subscriptions::SubscriptionCreateBuilder).
+#[derive(Debug, Clone)]
+pub enum SubscriptionBilling {
+    /// The amount and frequency of the plan.
+    Plan(PlanId),
+    /// A recurrence of its own, for subscriptions without a plan.
+    Recurring {
+        reason: String,
+        auto_recurring: SubscriptionAutoRecurring,
+    },
+}
+
+/// Error when validating a [`SubscriptionCreateBuilder`](crate::subscriptions::SubscriptionCreateBuilder)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionValidationError {
+    #[error("Card token is required to create an authorized subscription")]
+    MissingCardToken,
+    #[error("Reason and auto_recurring are required for a subscription without a plan")]
+    MissingRecurrence,
+    /// The free trial of a subscription with a plan is the one of the plan.
+    #[error("Free trial can't be set on a subscription with a plan")]
+    TrialWithPlan,
+}
+
+/// # UpdateSubscriptionRequest
+/// Struct to use in [`SubscriptionUpdateBuilder`](crate::subscriptions::SubscriptionUpdateBuilder)
+///
+/// Only the fields that are `Some` are updated.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_id/put>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct UpdateSubscriptionRequest {
+    pub reason: Option<String>,
+    pub external_reference: Option<ExternalReference<SubscriptionLimits>>,
+    pub back_url: Option<String>,
+    pub status: Option<SubscriptionStatus>,
+    /// New amount of the next charges.
+    pub auto_recurring: Option<UpdateAutoRecurring>,
+    /// Token of the card that will be charged from now on, see [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder).
+    pub card_token_id: Option<String>,
+}
+
+/// Amount of the next charges of a subscription.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct UpdateAutoRecurring {
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub transaction_amount: Option<Decimal>,
+    pub currency_id: Option<CurrencyId>,
+}
+
+/// # SubscriptionSearchParams
+/// Struct to use in [`SubscriptionSearchBuilder`](crate::subscriptions::SubscriptionSearchBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_search/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct SubscriptionSearchParams {
+    /// Quantity of subscriptions returned.
+    pub limit: Option<usize>,
+    /// Quantity of subscriptions to skip.
+    pub offset: Option<usize>,
+    /// Parameter used for sorting the subscriptions.
+    pub sort: Option<SubscriptionSearchSort>,
+    /// Sorts the subscriptions in ascending or descending order.
+    pub criteria: Option<PaymentSearchCriteria>,
+    pub status: Option<SubscriptionStatus>,
+    pub payer_id: Option<u64>,
+    pub payer_email: Option<String>,
+    pub preapproval_plan_id: Option<PlanId>,
+    /// Text to search in the subscriptions, like part of the `reason`.
+    pub q: Option<String>,
+}
+
+/// Parameter used for sorting a list of subscriptions.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionSearchSort {
+    DateCreated,
+    LastModified,
+    NextPaymentDate,
+    Id,
+}
+
+/// # SubscriptionSearchResponse
+/// Used in pagination of [`SubscriptionSearchBuilder`](crate::subscriptions::SubscriptionSearchBuilder)
+///
+/// Response from `/preapproval/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SubscriptionSearchResponse {
+    pub paging: Paging,
+    pub results: Vec<Subscription>,
+}
+
+/// # Subscription
+/// Subscription (preapproval) returned by `/preapproval`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Subscription {
+    pub id: SubscriptionId,
+    /// Plan of the subscription, if it was created from one.
+    pub preapproval_plan_id: Option<PlanId>,
+    pub application_id: Option<u64>,
+    pub collector_id: UserId,
+    pub payer_id: UserId,
+    pub payer_email: Option<String>,
+    /// Description of the subscription, shown to the subscriber.
+    pub reason: String,
+    pub external_reference: Option<String>,
+    pub back_url: Option<String>,
+    /// URL of the checkout where the subscriber authorizes the subscription.
+    pub init_point: Option<String>,
+    /// URL of the checkout for test users.
+    pub sandbox_init_point: Option<String>,
+    pub auto_recurring: SubscriptionAutoRecurring,
+    pub status: SubscriptionStatus,
+    /// Identifier of the card charged.
+    pub card_id: Option<CardId>,
+    pub payment_method_id: Option<String>,
+    /// Charges made and pending.
+    pub summarized: Option<SubscriptionSummary>,
+    /// Date of the next charge. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub next_payment_date: Option<String>,
+    /// Subscription create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: String,
+    /// Date when subscription was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_modified: Option<String>,
+}
+
+impl Subscription {
+    /// Checkout where the subscriber authorizes the subscription: `init_point` when `live`, `sandbox_init_point` for test users.
+    ///
+    /// The URL is checked to be an `https` page of Mercado Pago before redirecting the subscriber to it.
+    pub fn checkout_url(&self, live: bool) -> Result<Url, CheckoutUrlError> {
+        let (field, url) = if live {
+            ("init_point", &self.init_point)
+        } else {
+            ("sandbox_init_point", &self.sandbox_init_point)
+        };
+        let url = url.as_deref().ok_or(CheckoutUrlError::Missing(field))?;
+
+        let parsed = Url::parse(url).map_err(|_| CheckoutUrlError::Invalid(url.to_string()))?;
+        let is_mercado_pago = parsed.host_str().is_some_and(is_mercado_pago_host);
+        if parsed.scheme() != "https" || !is_mercado_pago {
+            return Err(CheckoutUrlError::Invalid(url.to_string()));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Whether `host` is `mercadopago.com` or the domain of a country, like `www.mercadopago.com.br`.
+fn is_mercado_pago_host(host: &str) -> bool {
+    let mut labels = host.rsplit('.');
+    let country = labels.next().filter(|country| country.len() == 2);
+    if country.is_none() {
+        labels = host.rsplit('.');
+    }
+
+    labels.next() == Some("com") && labels.next() == Some("mercadopago")
+}
+
+/// Error of [`Subscription::checkout_url`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CheckoutUrlError {
+    /// The subscription has no checkout for the environment, like an already authorized subscription.
+    #[error("Subscription has no {0}")]
+    Missing(&'static str),
+    #[error("{0} is not a checkout of Mercado Pago")]
+    Invalid(String),
+}
+
+/// Status of a subscription
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// Waiting for the subscriber to authorize it.
+    Pending,
+    /// Active, the subscriber is being charged.
+    Authorized,
+    /// Charges are paused.
+    Paused,
+    /// The subscription ended and can't be reactivated.
+    Cancelled,
+    /// For untracked subscription status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// How and how much the subscriber is charged.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SubscriptionAutoRecurring {
+    /// Number of `frequency_type` units between charges, like `1` month.
+    pub frequency: u32,
+    pub frequency_type: FrequencyType,
+    /// Amount of each charge.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub transaction_amount: Option<Decimal>,
+    pub currency_id: CurrencyId,
+    /// Date of the first charge. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub start_date: Option<String>,
+    /// Date of the last charge. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub end_date: Option<String>,
+    /// Period without charges at the start of the subscription.
+    pub free_trial: Option<FreeTrial>,
+}
+
+/// Charges made and pending of a subscription.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SubscriptionSummary {
+    /// Total number of charges, if the subscription has an end.
+    pub quotas: Option<u32>,
+    pub charged_quantity: Option<u32>,
+    pub pending_charge_quantity: Option<u32>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub charged_amount: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub pending_charge_amount: Option<Decimal>,
+    /// Health of the charges, like `"green"` or `"yellow"` when charges are failing.
+    pub semaphore: Option<String>,
+    /// Date of the last charge. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_charged_date: Option<String>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub last_charged_amount: Option<Decimal>,
+}
+
+/// Error when replacing the card of a subscription
+#[derive(Error, Debug)]
+pub enum SubscriptionCardError {
+    /// Mercado Pago did not accept the card token, like an expired or already used token. Ask the subscriber for the card again.
+    #[error("Card token was rejected: {}", .0.message)]
+    TokenRejected(MercadoPagoError),
+    #[error("{0}")]
+    Request(MercadoPagoRequestError),
+}
+
+impl From<MercadoPagoRequestError> for SubscriptionCardError {
+    fn from(err: MercadoPagoRequestError) -> Self {
+        match err {
+            MercadoPagoRequestError::MercadoPago(err) if matches!(err.status, 400 | 422) => {
+                SubscriptionCardError::TokenRejected(err)
+            }
+            err => SubscriptionCardError::Request(err),
+        }
+    }
+}
+
+/// # Invoice
+/// Charge of a subscription (authorized payment) returned by `/authorized_payments`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_authorized_payments_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Invoice {
+    pub id: InvoiceId,
+    /// Subscription charged by the invoice.
+    pub preapproval_id: SubscriptionId,
+    pub status: InvoiceStatus,
+    pub reason: Option<String>,
+    pub external_reference: Option<String>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub transaction_amount: Option<Decimal>,
+    pub currency_id: Option<CurrencyId>,
+    /// Date of the charge. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub debit_date: Option<String>,
+    /// Number of charge attempts already made.
+    pub retry_attempt: Option<u32>,
+    /// Date of the next charge attempt. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub next_retry_date: Option<String>,
+    /// Payment of the last charge attempt.
+    pub payment: Option<InvoicePayment>,
+    /// Invoice create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// Date when invoice was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_modified: Option<String>,
+}
+
+impl Invoice {
+    /// Checks if the charge of the invoice failed and can be made again
+    pub fn check_retryable(&self) -> Result<(), InvoiceRetryError> {
+        let payment_status = self.payment.as_ref().and_then(|p| p.status.clone());
+        let retryable = match self.status {
+            InvoiceStatus::Recycling => true,
+            InvoiceStatus::Processed => {
+                matches!(
+                    payment_status,
+                    Some(PaymentStatus::Rejected | PaymentStatus::Cancelled)
+                )
+            }
+            _ => false,
+        };
+
+        if retryable {
+            Ok(())
+        } else {
+            Err(InvoiceRetryError::NotRetryable {
+                status: self.status.clone(),
+                payment_status,
+            })
+        }
+    }
+}
+
+/// Status of an invoice
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// Waiting for the charge date.
+    Scheduled,
+    /// Charged, see the status of the [`payment`](Invoice::payment).
+    Processed,
+    /// The charge failed and Mercado Pago will try again.
+    Recycling,
+    /// The invoice will not be charged.
+    Cancelled,
+    /// For untracked invoice status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// Payment of a charge attempt of an [`Invoice`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InvoicePayment {
+    pub id: PaymentId,
+    pub status: Option<PaymentStatus>,
+    pub status_detail: Option<String>,
+}
+
+/// Error when retrying the charge of an invoice
+#[derive(Error, Debug)]
+pub enum InvoiceRetryError {
+    /// The invoice was not charged yet, was already paid or was cancelled.
+    #[error("Invoice can't be retried, status {status}")]
+    NotRetryable {
+        status: InvoiceStatus,
+        payment_status: Option<PaymentStatus>,
+    },
+    #[error("{0}")]
+    Request(#[from] MercadoPagoRequestError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CheckoutUrlError, Invoice, InvoiceRetryError, InvoiceStatus, Subscription,
+        SubscriptionCardError, SubscriptionSearchParams, SubscriptionSearchSort,
+        SubscriptionStatus,
+    };
+    use crate::{
+        fixtures::SUBSCRIPTION_AUTHORIZED,
+        payments::types::{PaymentSearchCriteria, PaymentStatus},
+        test_support::api_error,
+    };
+
+    #[test]
+    fn checkout_url_per_environment() {
+        let subscription: Subscription = serde_json::from_str(SUBSCRIPTION_AUTHORIZED).unwrap();
+
+        let live = subscription.checkout_url(true).unwrap();
+        assert_eq!(live.host_str(), Some("www.mercadopago.com.br"));
+        let sandbox = subscription.checkout_url(false).unwrap();
+        assert_eq!(sandbox.host_str(), Some("sandbox.mercadopago.com.br"));
+    }
+
+    #[test]
+    fn missing_or_foreign_checkout_url() {
+        let mut subscription: Subscription = serde_json::from_str(SUBSCRIPTION_AUTHORIZED).unwrap();
+
+        subscription.sandbox_init_point = None;
+        assert_eq!(
+            subscription.checkout_url(false),
+            Err(CheckoutUrlError::Missing("sandbox_init_point"))
+        );
+
+        for url in [
+            "http://www.mercadopago.com.br/subscriptions/checkout",
+            "https://mercadopago.example.com/subscriptions/checkout",
+            "not a url",
+        ] {
+            subscription.init_point = Some(url.to_string());
+            assert_eq!(
+                subscription.checkout_url(true),
+                Err(CheckoutUrlError::Invalid(url.to_string()))
+            );
+        }
+    }
+
+    fn invoice(status: &str, payment_status: Option<&str>) -> Invoice {
+        serde_json::from_value(serde_json::json!({
+            "id": 6114264375u64,
+            "preapproval_id": "2c938084726fca480172750000000000",
+            "status": status,
+            "payment": payment_status.map(|s| serde_json::json!({ "id": 1, "status": s })),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn only_failed_invoices_are_retryable() {
+        assert!(invoice("recycling", Some("rejected"))
+            .check_retryable()
+            .is_ok());
+        assert!(invoice("processed", Some("rejected"))
+            .check_retryable()
+            .is_ok());
+        assert!(matches!(
+            invoice("processed", Some("approved")).check_retryable(),
+            Err(InvoiceRetryError::NotRetryable {
+                status: InvoiceStatus::Processed,
+                payment_status: Some(PaymentStatus::Approved),
+            })
+        ));
+        assert!(invoice("scheduled", None).check_retryable().is_err());
+        assert!(invoice("cancelled", None).check_retryable().is_err());
+    }
+
+    #[test]
+    fn search_params_query() {
+        let mp_client =
+            crate::client::MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        let params = SubscriptionSearchParams {
+            sort: Some(SubscriptionSearchSort::NextPaymentDate),
+            criteria: Some(PaymentSearchCriteria::Descending),
+            status: Some(SubscriptionStatus::Authorized),
+            payer_id: Some(4_000_000_000),
+            ..Default::default()
+        };
+
+        let request = mp_client
+            .start_request(reqwest::Method::GET, "/preapproval/search")
+            .query(&params)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.url().query(),
+            Some("sort=next_payment_date&criteria=desc&status=authorized&payer_id=4000000000")
+        );
+    }
+
+    #[test]
+    fn card_errors_are_classified() {
+        assert!(matches!(
+            SubscriptionCardError::from(api_error(400)),
+            SubscriptionCardError::TokenRejected(err) if err.status == 400
+        ));
+        assert!(matches!(
+            SubscriptionCardError::from(api_error(500)),
+            SubscriptionCardError::Request(_)
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::SubscriptionId,
+};
+
+use super::types::{
+    Subscription, SubscriptionCardError, SubscriptionStatus, UpdateSubscriptionRequest,
+};
+
+/// Builder for update a subscription
+///
+/// # Arguments
+///
+/// * `id` - Unique subscription identifier, automatically generated by Mercado Pago.
+/// * `options` - Options to update the subscription.
+///
+/// # Example
+/// ```
+/// use mpago::{subscriptions::SubscriptionUpdateBuilder, Decimal};
+///
+/// SubscriptionUpdateBuilder {
+///     id: "2c938084726fca480172750000000000".into(),
+///     options: UpdateSubscriptionRequest {
+///         auto_recurring: Some(UpdateAutoRecurring {
+///             transaction_amount: Some(Decimal::new(60, 0)),
+///             currency_id: Some(CurrencyId::BRL),
+///         }),
+///         ..Default::default()
+///     }
+/// }
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/subscriptions/_preapproval_id/put>
+#[derive(Debug, Clone)]
+pub struct SubscriptionUpdateBuilder {
+    pub id: SubscriptionId,
+    pub options: UpdateSubscriptionRequest,
+}
+
+impl SubscriptionUpdateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::PUT, format!("/preapproval/{}", self.id)),
+                &self.options,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<Subscription>(res).await
+    }
+
+    /// Send the request, pausing the charges of the subscription
+    pub async fn pause(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.with_status(SubscriptionStatus::Paused)
+            .send(mp_client)
+            .await
+    }
+
+    /// Send the request, resuming the charges of a paused subscription
+    pub async fn reauthorize(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.with_status(SubscriptionStatus::Authorized)
+            .send(mp_client)
+            .await
+    }
+
+    /// Send the request, cancelling the subscription. A cancelled subscription can't be reactivated.
+    pub async fn cancel(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.with_status(SubscriptionStatus::Cancelled)
+            .send(mp_client)
+            .await
+    }
+
+    /// Send the request, replacing the card charged by the subscription
+    ///
+    /// # Arguments
+    ///
+    /// * `card_token_id` - Token of the new card, see [`CardTokenCreateBuilder`](crate::card_tokens::CardTokenCreateBuilder).
+    ///
+    /// # Example
+    /// ```
+    /// match SubscriptionUpdateBuilder { id, options: Default::default() }
+    ///     .update_card(&mp_client, card_token.id)
+    ///     .await
+    /// {
+    ///     Ok(subscription) => { /* ... */ }
+    ///     Err(SubscriptionCardError::TokenRejected(_)) => { /* ask for the card again */ }
+    ///     Err(err) => return Err(err.into()),
+    /// }
+    /// ```
+    pub async fn update_card(
+        mut self,
+        mp_client: &MercadoPagoClient,
+        card_token_id: impl ToString,
+    ) -> Result<Subscription, SubscriptionCardError> {
+        self.options.card_token_id = Some(card_token_id.to_string());
+
+        Ok(self.send(mp_client).await?)
+    }
+
+    fn with_status(mut self, status: SubscriptionStatus) -> Self {
+        self.options.status = Some(status);
+        self
+    }
+}
+
+impl SendRequest for SubscriptionUpdateBuilder {
+    type Output = Subscription;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        SubscriptionUpdateBuilder::send(self, mp_client)
+    }
+}
+
+impl Subscription {
+    /// Send a request to pause the charges of the subscription
+    pub async fn pause(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.update_builder().pause(mp_client).await
+    }
+
+    /// Send a request to resume the charges of a paused subscription
+    pub async fn reauthorize(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.update_builder().reauthorize(mp_client).await
+    }
+
+    /// Send a request to cancel the subscription. A cancelled subscription can't be reactivated.
+    pub async fn cancel(
+        &self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Subscription, MercadoPagoRequestError> {
+        self.update_builder().cancel(mp_client).await
+    }
+
+    /// Send a request to replace the card charged by the subscription
+    pub async fn update_card(
+        &self,
+        mp_client: &MercadoPagoClient,
+        card_token_id: impl ToString,
+    ) -> Result<Subscription, SubscriptionCardError> {
+        self.update_builder()
+            .update_card(mp_client, card_token_id)
+            .await
+    }
+
+    fn update_builder(&self) -> SubscriptionUpdateBuilder {
+        SubscriptionUpdateBuilder {
+            id: self.id.clone(),
+            options: UpdateSubscriptionRequest::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::SubscriptionUpdateBuilder;
+    use crate::{common::create_test_client, subscriptions::types::UpdateSubscriptionRequest};
+
+    #[tokio::test]
+    async fn fail_update_subscription() {
+        let mp_client = create_test_client();
+
+        let res = SubscriptionUpdateBuilder {
+            id: "invalid".into(),
+            options: UpdateSubscriptionRequest {
+                reason: Some("Test".to_string()),
+                ..Default::default()
+            },
+        }
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Helpers shared by the unit tests: a local HTTP server and error factories.
+
+// Some helpers are only used by the tests of optional features.
+#![allow(dead_code)]
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+use crate::common::{MercadoPagoError, MercadoPagoRequestError};
+
+/// Response written by [`serve`].
+pub(crate) struct MockResponse {
+    status: &'static str,
+    content_type: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Response with a raw `body`, `status` being the status line like `"200 OK"`.
+    pub(crate) fn new(status: &'static str, content_type: &'static str, body: &[u8]) -> Self {
+        MockResponse {
+            status,
+            content_type,
+            headers: vec![],
+            body: body.to_vec(),
+        }
+    }
+
+    /// Adds the header `name` to the response.
+    pub(crate) fn with_header(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    /// Response with `body` serialized as JSON.
+    pub(crate) fn json(status: &'static str, body: serde_json::Value) -> Self {
+        Self::new(status, "application/json", body.to_string().as_bytes())
+    }
+}
+
+/// A request received by [`serve`].
+pub(crate) struct MockRequest {
+    /// Request line and headers, lowercased.
+    pub head: String,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Value of the header `name`, which must be lowercase.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key == name).then(|| value.trim())
+        })
+    }
+}
+
+/// Serves every connection with the response of `respond`, returning the server url.
+///
+/// Every response closes the connection, so each request arrives in its own connection.
+pub(crate) async fn serve<F>(respond: F) -> String
+where
+    F: FnMut(MockRequest) -> MockResponse + Send + 'static,
+{
+    serve_in_groups(1, respond).await
+}
+
+/// Like [`serve`], reading the requests of `group` connections before responding to any of them.
+///
+/// The requests of a group only get a response when all of them are in flight at the same time, so a client sending them one by one hangs.
+pub(crate) async fn serve_in_groups<F>(group: usize, mut respond: F) -> String
+where
+    F: FnMut(MockRequest) -> MockResponse + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let mut pending = Vec::with_capacity(group);
+            for _ in 0..group {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut socket).await;
+                pending.push((socket, request));
+            }
+
+            for (mut socket, request) in pending {
+                let response = respond(request);
+
+                let headers: String = response
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                let head = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: {}\r\ncontent-length: {}\r\n{headers}connection: close\r\n\r\n",
+                    response.status,
+                    response.content_type,
+                    response.body.len()
+                );
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&response.body).await.unwrap();
+            }
+        }
+    });
+
+    format!("http://{addr}")
+}
+
+async fn read_request(socket: &mut tokio::net::TcpStream) -> MockRequest {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 1024];
+
+    loop {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+
+        let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else {
+            if read == 0 {
+                break;
+            }
+            continue;
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..end]).to_lowercase();
+        let mut request = MockRequest {
+            head,
+            body: Vec::new(),
+        };
+        let len = request
+            .header("content-length")
+            .and_then(|len| len.parse::<usize>().ok())
+            .unwrap_or_default();
+
+        while buffer.len() < end + 4 + len {
+            let read = socket.read(&mut chunk).await.unwrap();
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+
+        request.body = buffer[end + 4..].to_vec();
+        return request;
+    }
+
+    MockRequest {
+        head: String::from_utf8_lossy(&buffer).to_lowercase(),
+        body: Vec::new(),
+    }
+}
+
+/// Error returned by the API with `status`.
+pub(crate) fn api_error(status: u16) -> MercadoPagoRequestError {
+    MercadoPagoRequestError::MercadoPago(MercadoPagoError::new("error", "error", status, vec![]))
+}
@@ -0,0 +1,62 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{TestUser, TestUserCreateOptions};
+
+/// Builder for creating a test user, a sandbox account to act as buyer or seller
+///
+/// Test users can't be listed or deleted through the API, only in the dashboard of the application, and there is a limit of test users per account. Create them once and reuse them across test runs.
+///
+/// # Arguments
+///
+/// * `0` - Site and description of the account.
+///
+/// # Example
+/// ```
+/// use mpago::{common::SiteId, test_users::{types::TestUserCreateOptions, TestUserCreateBuilder}};
+///
+/// let buyer = TestUserCreateBuilder(TestUserCreateOptions::new(SiteId::Brazil).with_description("buyer"))
+///     .send(&mp_client)
+///     .await?;
+///
+/// println!("{}: {}", buyer.nickname, buyer.password);
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/test_user/_users_test_user/post>
+#[derive(Debug, Clone)]
+pub struct TestUserCreateBuilder(pub TestUserCreateOptions);
+
+impl TestUserCreateBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<TestUser, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_request(Method::POST, "/users/test_user"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<TestUser>(res).await
+    }
+}
+
+impl SendRequest for TestUserCreateBuilder {
+    type Output = TestUser;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        TestUserCreateBuilder::send(self, mp_client)
+    }
+}
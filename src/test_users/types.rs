@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{common::SiteId, ids::UserId};
+
+/// # TestUserCreateOptions
+/// Struct to use in [`TestUserCreateBuilder`](crate::test_users::TestUserCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/test_user/_users_test_user/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TestUserCreateOptions {
+    /// Site of the account, the same as the seller's for a buyer to pay them.
+    pub site_id: SiteId,
+    /// Free text to tell the test users apart, like `"buyer"` or `"seller"`.
+    pub description: Option<String>,
+}
+
+impl TestUserCreateOptions {
+    /// Test user of `site_id`, without description.
+    pub fn new(site_id: SiteId) -> Self {
+        TestUserCreateOptions {
+            site_id,
+            description: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn with_description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+}
+
+/// # TestUser
+/// Struct to use in [`TestUserCreateBuilder`](crate::test_users::TestUserCreateBuilder)
+///
+/// Generated credentials of a sandbox account. The password is only returned on creation, store it to log in with the account.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/test_user/_users_test_user/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TestUser {
+    pub id: UserId,
+    /// Username to log in with the account.
+    pub nickname: String,
+    pub password: String,
+    pub site_status: Option<String>,
+    pub site_id: Option<SiteId>,
+    pub email: Option<String>,
+    pub description: Option<String>,
+    /// [ISO8601](https://pt.wikipedia.org/wiki/ISO_8601) date
+    pub date_created: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TestUser, TestUserCreateOptions};
+    use crate::common::SiteId;
+
+    #[test]
+    fn create_body() {
+        let body = serde_json::to_value(
+            TestUserCreateOptions::new(SiteId::Brazil).with_description("buyer"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({ "site_id": "MLB", "description": "buyer" })
+        );
+    }
+
+    #[test]
+    fn parse_test_user() {
+        let user = serde_json::from_value::<TestUser>(serde_json::json!({
+            "id": 1234567890,
+            "nickname": "TESTUSER1234567890",
+            "password": "qatest1234",
+            "site_status": "active",
+            "site_id": "MLB",
+            "email": "test_user_1234567890@testuser.com"
+        }))
+        .unwrap();
+
+        assert_eq!(user.id, 1234567890);
+        assert_eq!(user.site_id, Some(SiteId::Brazil));
+    }
+}
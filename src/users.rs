@@ -0,0 +1,4 @@
+pub use self::me_builder::UserMeBuilder;
+
+mod me_builder;
+pub mod types;
@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::User;
+
+/// Builder for getting the account of the access token
+///
+/// Useful in multi-tenant apps, to find the collector id and site of the seller of an OAuth token.
+///
+/// # Example
+/// ```
+/// use mpago::users::UserMeBuilder;
+///
+/// let seller = UserMeBuilder.send(&mp_client).await?;
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/online-payments/checkout-api-payments/get-user-info/get>
+#[derive(Debug, Clone)]
+pub struct UserMeBuilder;
+
+impl UserMeBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<User, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_request(Method::GET, "/users/me"))
+            .await?;
+
+        mp_client.resolve_json::<User>(res).await
+    }
+}
+
+impl SendRequest for UserMeBuilder {
+    type Output = User;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        UserMeBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::UserMeBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn get_user_of_token() {
+        let mp_client = create_test_client();
+
+        let res = UserMeBuilder.send(&mp_client).await;
+
+        assert!(res.is_ok());
+    }
+}
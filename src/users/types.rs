@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{common::SiteId, ids::UserId};
+
+/// # User
+/// Struct to use in [`UserMeBuilder`](crate::users::UserMeBuilder)
+///
+/// Account of the access token. The `id` is the collector id of the payments received by the account.
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/online-payments/checkout-api-payments/get-user-info/get>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct User {
+    pub id: UserId,
+    pub nickname: String,
+    pub site_id: SiteId,
+    /// Country of the account, like `"BR"`.
+    pub country_id: Option<String>,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::User;
+    use crate::common::SiteId;
+
+    #[test]
+    fn parse_user() {
+        let user = serde_json::from_value::<User>(serde_json::json!({
+            "id": 241983636,
+            "nickname": "LOJADOCENTRO",
+            "site_id": "MLB",
+            "country_id": "BR",
+            "email": "contato@lojadocentro.com.br",
+            "first_name": "Loja",
+            "last_name": "Centro",
+            "user_type": "normal"
+        }))
+        .unwrap();
+
+        assert_eq!(user.id, 241983636);
+        assert_eq!(user.site_id, SiteId::Brazil);
+        assert_eq!(user.country_id.as_deref(), Some("BR"));
+    }
+}
@@ -0,0 +1,10 @@
+pub use self::agreement_create_builder::AgreementCreateBuilder;
+pub use self::agreement_get_builder::AgreementGetBuilder;
+pub use self::agreement_search_builder::AgreementSearchBuilder;
+pub use self::payment_create_builder::WalletPaymentCreateBuilder;
+
+mod agreement_create_builder;
+mod agreement_get_builder;
+mod agreement_search_builder;
+mod payment_create_builder;
+pub mod types;
@@ -0,0 +1,107 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{AgreementCreateOptions, AgreementCreateResponse, AgreementData, ExternalUser};
+
+/// Builder for creating a Wallet Connect agreement
+///
+/// Redirect the payer to the returned `agreement_uri` to approve linking the wallet.
+///
+/// # Arguments
+///
+/// * `options` - Options to create the agreement.
+///
+/// # Example
+/// ```
+/// use mpago::wallet_connect::{
+///     types::{AgreementData, ExternalUser},
+///     AgreementCreateBuilder,
+/// };
+///
+/// AgreementCreateBuilder::new("https://example.com/wallet/callback", "checkout-9823")
+///     .external_user(ExternalUser::new("user-42"))
+///     .agreement_data(AgreementData::new(Decimal::new(1, 0)).with_description("Minha loja"))
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_agreements/post>
+#[derive(Debug, Clone)]
+pub struct AgreementCreateBuilder(pub AgreementCreateOptions);
+
+impl AgreementCreateBuilder {
+    /// Agreement of `external_flow_id`, returning the payer to `return_uri` after the approval.
+    pub fn new(return_uri: impl ToString, external_flow_id: impl ToString) -> Self {
+        AgreementCreateBuilder(AgreementCreateOptions {
+            return_uri: return_uri.to_string(),
+            external_flow_id: external_flow_id.to_string(),
+            external_user: None,
+            agreement_data: None,
+        })
+    }
+
+    /// Sets the user of your platform that owns the agreement.
+    pub fn external_user(mut self, external_user: ExternalUser) -> Self {
+        self.0.external_user = Some(external_user);
+
+        self
+    }
+
+    /// Sets the data shown to the payer when approving the agreement.
+    pub fn agreement_data(mut self, agreement_data: AgreementData) -> Self {
+        self.0.agreement_data = Some(agreement_data);
+
+        self
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<AgreementCreateResponse, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.json_body(
+                mp_client.start_versioned_request(Method::POST, "v2", "/wallet_connect/agreements"),
+                &self.0,
+            )?)
+            .await?;
+
+        mp_client.resolve_json::<AgreementCreateResponse>(res).await
+    }
+}
+
+impl SendRequest for AgreementCreateBuilder {
+    type Output = AgreementCreateResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        AgreementCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::AgreementCreateBuilder;
+    use crate::{common::create_test_client, wallet_connect::types::ExternalUser};
+
+    #[tokio::test]
+    async fn create_agreement() {
+        let mp_client = create_test_client();
+
+        let agreement = AgreementCreateBuilder::new("https://example.com/callback", "test-flow")
+            .external_user(ExternalUser::new("test-user"))
+            .send(&mp_client)
+            .await
+            .unwrap();
+
+        assert!(!agreement.agreement_uri.is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::Agreement;
+
+/// Builder for get a Wallet Connect agreement
+///
+/// # Arguments
+///
+/// * `agreement_id` - Unique agreement identifier, automatically generated by Mercado Pago.
+///
+/// # Example
+/// ```
+/// use mpago::wallet_connect::AgreementGetBuilder;
+///
+/// AgreementGetBuilder("2c93808478a4d5ae0178b4e0e9c00000".to_string())
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_agreements_agreement_id/get>
+#[derive(Debug, Clone)]
+pub struct AgreementGetBuilder(pub String);
+
+impl AgreementGetBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Agreement, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(mp_client.start_versioned_request(
+                Method::GET,
+                "v2",
+                &format!("/wallet_connect/agreements/{}", self.0),
+            ))
+            .await?;
+
+        mp_client.resolve_json::<Agreement>(res).await
+    }
+}
+
+impl SendRequest for AgreementGetBuilder {
+    type Output = Agreement;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        AgreementGetBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::AgreementGetBuilder;
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn fail_get_agreement() {
+        let mp_client = create_test_client();
+
+        let res = AgreementGetBuilder("invalid".to_string())
+            .send(&mp_client)
+            .await;
+
+        assert!(res.is_err());
+    }
+}
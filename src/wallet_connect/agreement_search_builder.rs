@@ -0,0 +1,85 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{Agreement, AgreementSearchOptions, AgreementSearchResponse};
+
+/// Builder for finding the agreements of an `external_flow_id`
+///
+/// Use it in the approval callback to reconcile the agreement with the flow that requested it.
+///
+/// # Arguments
+///
+/// * `options` - Options to search for agreements.
+///
+/// # Example
+/// ```
+/// use mpago::wallet_connect::AgreementSearchBuilder;
+///
+/// AgreementSearchBuilder(AgreementSearchOptions {
+///     external_flow_id: "checkout-9823".to_string(),
+/// })
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_agreements_search/get>
+#[derive(Debug, Clone)]
+pub struct AgreementSearchBuilder(pub AgreementSearchOptions);
+
+impl AgreementSearchBuilder {
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<Vec<Agreement>, MercadoPagoRequestError> {
+        let res = mp_client
+            .execute(
+                mp_client
+                    .start_versioned_request(Method::GET, "v2", "/wallet_connect/agreements/search")
+                    .query(&self.0),
+            )
+            .await?;
+
+        Ok(mp_client
+            .resolve_json::<AgreementSearchResponse>(res)
+            .await?
+            .results)
+    }
+}
+
+impl SendRequest for AgreementSearchBuilder {
+    type Output = Vec<Agreement>;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        AgreementSearchBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use super::{AgreementSearchBuilder, AgreementSearchOptions};
+    use crate::common::create_test_client;
+
+    #[tokio::test]
+    async fn search_unknown_flow() {
+        let mp_client = create_test_client();
+
+        let agreements = AgreementSearchBuilder(AgreementSearchOptions {
+            external_flow_id: "unknown-flow".to_string(),
+        })
+        .send(&mp_client)
+        .await
+        .unwrap();
+
+        assert!(agreements.is_empty());
+    }
+}
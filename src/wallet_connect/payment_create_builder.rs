@@ -0,0 +1,110 @@
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+};
+
+use super::types::{WalletPayer, WalletPayment, WalletPaymentCreateOptions, WalletPaymentResponse};
+
+/// Builder for charging a wallet linked with Wallet Connect
+///
+/// # Arguments
+///
+/// * `options` - Payer token of the linked wallet and the payment data.
+/// * `idempotency_key` - Idempotency key is a unique value that is used to prevent duplicate processing of requests
+///
+/// # Example
+/// ```
+/// use mpago::wallet_connect::{types::WalletPayment, WalletPaymentCreateBuilder};
+///
+/// WalletPaymentCreateBuilder::new(
+///     "abcdef1e23f4567d8e9123eb6591ff68df74c57d9a6e9e07b0e0a0e48e3c8c3b",
+///     WalletPayment {
+///         transaction_amount: Decimal::new(2550, 2),
+///         description: Some("Assinatura mensal".to_string()),
+///         ..Default::default()
+///     },
+/// )
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_payments/post>
+#[derive(Debug, Clone)]
+pub struct WalletPaymentCreateBuilder {
+    pub options: WalletPaymentCreateOptions,
+    pub idempotency_key: Option<String>,
+}
+
+impl WalletPaymentCreateBuilder {
+    /// Payment of `wallet_payment` charged to the wallet of `payer_token`.
+    pub fn new(payer_token: impl ToString, wallet_payment: WalletPayment) -> Self {
+        WalletPaymentCreateBuilder {
+            options: WalletPaymentCreateOptions {
+                payer: WalletPayer {
+                    token: payer_token.to_string(),
+                },
+                wallet_payment,
+            },
+            idempotency_key: None,
+        }
+    }
+
+    /// Send the request
+    pub async fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> Result<WalletPaymentResponse, MercadoPagoRequestError> {
+        let mut req = mp_client.json_body(
+            mp_client.start_versioned_request(Method::POST, "v2", "/wallet_connect/payments"),
+            &self.options,
+        )?;
+
+        if let Some(idempotency_key) = self.idempotency_key {
+            req = req.header("X-Idempotency-Key", idempotency_key);
+        }
+
+        let res = mp_client.execute(req).await?;
+
+        mp_client.resolve_json::<WalletPaymentResponse>(res).await
+    }
+}
+
+impl SendRequest for WalletPaymentCreateBuilder {
+    type Output = WalletPaymentResponse;
+
+    fn send(
+        self,
+        mp_client: &MercadoPagoClient,
+    ) -> impl Future<Output = Result<Self::Output, MercadoPagoRequestError>> + Send {
+        WalletPaymentCreateBuilder::send(self, mp_client)
+    }
+}
+
+#[cfg(test)]
+#[cfg(ignore)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::WalletPaymentCreateBuilder;
+    use crate::{common::create_test_client, wallet_connect::types::WalletPayment};
+
+    #[tokio::test]
+    async fn fail_with_invalid_payer_token() {
+        let mp_client = create_test_client();
+
+        let res = WalletPaymentCreateBuilder::new(
+            "invalid",
+            WalletPayment {
+                transaction_amount: Decimal::new(10, 0),
+                ..Default::default()
+            },
+        )
+        .send(&mp_client)
+        .await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_with::skip_serializing_none;
+
+use crate::{ids::PaymentId, payments::types::PaymentStatus};
+
+/// # WalletPaymentCreateOptions
+/// Struct to use in [`WalletPaymentCreateBuilder`](crate::wallet_connect::WalletPaymentCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_payments/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WalletPaymentCreateOptions {
+    pub payer: WalletPayer,
+    pub wallet_payment: WalletPayment,
+}
+
+/// Wallet linked with Wallet Connect.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WalletPayer {
+    /// Payer token received when the payer approved the agreement.
+    pub token: String,
+}
+
+/// Data of a payment charged to a linked wallet.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct WalletPayment {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub transaction_amount: Decimal,
+    /// Description shown to the payer.
+    pub description: Option<String>,
+    pub external_reference: Option<String>,
+}
+
+/// # WalletPaymentResponse
+/// Payment returned by `/v2/wallet_connect/payments`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_payments/post>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WalletPaymentResponse {
+    pub id: PaymentId,
+    pub status: PaymentStatus,
+    pub status_detail: Option<String>,
+    pub wallet_payment: Option<WalletPayment>,
+    /// Payment create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+}
+
+/// # Agreement
+/// Link between a Mercado Pago wallet and your platform, returned by `/v2/wallet_connect/agreements`
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_agreements_agreement_id/get>
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Agreement {
+    pub id: String,
+    pub status: AgreementStatus,
+    /// Identifier of the flow in your platform that requested the agreement.
+    pub external_flow_id: Option<String>,
+    pub external_user: Option<ExternalUser>,
+    pub agreement_data: Option<AgreementData>,
+    /// URL the payer returns to after approving the agreement.
+    pub return_uri: Option<String>,
+    /// Agreement create date. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub date_created: Option<String>,
+    /// Date when agreement was last updated. [ISO8601](https://www.ionos.com/digitalguide/websites/web-development/iso-8601/) format.
+    pub last_updated: Option<String>,
+}
+
+/// Status of an agreement
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgreementStatus {
+    /// Waiting for the payer to approve it.
+    Pending,
+    /// The wallet is linked and can be charged.
+    Approved,
+    Cancelled,
+    Expired,
+    /// For untracked agreement status
+    #[serde(other)]
+    Unknown(String),
+}
+
+/// User of your platform that owns the agreement.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ExternalUser {
+    /// Identifier of the user in your platform.
+    pub id: String,
+    pub description: Option<String>,
+}
+
+impl ExternalUser {
+    /// User `id` of your platform, without description.
+    pub fn new(id: impl ToString) -> Self {
+        ExternalUser {
+            id: id.to_string(),
+            description: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn with_description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+}
+
+/// Data shown to the payer when approving the agreement.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AgreementData {
+    /// Amount charged and refunded to validate the wallet.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub validation_amount: Option<Decimal>,
+    pub description: Option<String>,
+}
+
+impl AgreementData {
+    /// Data validating the wallet with `validation_amount`, without description.
+    pub fn new(validation_amount: Decimal) -> Self {
+        AgreementData {
+            validation_amount: Some(validation_amount),
+            description: None,
+        }
+    }
+
+    /// Sets `description`.
+    pub fn with_description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+}
+
+/// # AgreementCreateOptions
+/// Struct to use in [`AgreementCreateBuilder`](crate::wallet_connect::AgreementCreateBuilder)
+///
+/// <https://www.mercadopago.com.br/developers/pt/reference/wallet_connect/_wallet_connect_agreements/post>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AgreementCreateOptions {
+    /// URL the payer returns to after approving the agreement.
+    pub return_uri: String,
+    /// Identifier of the flow in your platform, used to find the agreement later with [`AgreementSearchBuilder`](crate::wallet_connect::AgreementSearchBuilder).
+    pub external_flow_id: String,
+    pub external_user: Option<ExternalUser>,
+    pub agreement_data: Option<AgreementData>,
+}
+
+/// # AgreementCreateResponse
+/// Response from `/v2/wallet_connect/agreements`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AgreementCreateResponse {
+    pub agreement_id: String,
+    /// URL where the payer approves the agreement.
+    pub agreement_uri: String,
+}
+
+/// # AgreementSearchOptions
+/// Struct to use in [`AgreementSearchBuilder`](crate::wallet_connect::AgreementSearchBuilder)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AgreementSearchOptions {
+    /// Identifier of the flow that requested the agreement.
+    pub external_flow_id: String,
+}
+
+/// # AgreementSearchResponse
+/// Response from `/v2/wallet_connect/agreements/search`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AgreementSearchResponse {
+    pub results: Vec<Agreement>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{
+        AgreementData, AgreementSearchResponse, AgreementStatus, ExternalUser, WalletPayment,
+        WalletPaymentResponse,
+    };
+    use crate::wallet_connect::AgreementCreateBuilder;
+    use crate::wallet_connect::WalletPaymentCreateBuilder;
+
+    #[test]
+    fn payer_token_in_body() {
+        let builder = WalletPaymentCreateBuilder::new(
+            "payer-token",
+            WalletPayment {
+                transaction_amount: Decimal::new(2550, 2),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            serde_json::to_value(&builder.options).unwrap(),
+            serde_json::json!({
+                "payer": { "token": "payer-token" },
+                "wallet_payment": { "transaction_amount": 25.5 },
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_response() {
+        let payment: WalletPaymentResponse = serde_json::from_value(serde_json::json!({
+            "id": 123,
+            "status": "approved",
+            "status_detail": "accredited",
+            "wallet_payment": { "transaction_amount": 25.5, "description": "Assinatura mensal" },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            payment.wallet_payment.unwrap().transaction_amount,
+            Decimal::new(2550, 2)
+        );
+    }
+
+    #[test]
+    fn deserialize_agreements() {
+        let response: AgreementSearchResponse = serde_json::from_value(serde_json::json!({
+            "results": [{
+                "id": "2c93808478a4d5ae0178b4e0e9c00000",
+                "status": "approved",
+                "external_flow_id": "checkout-9823",
+                "external_user": { "id": "user-42" },
+                "agreement_data": { "validation_amount": 1.0, "description": "Minha loja" },
+            }],
+        }))
+        .unwrap();
+
+        let agreement = &response.results[0];
+        assert_eq!(agreement.status, AgreementStatus::Approved);
+        assert_eq!(agreement.external_flow_id.as_deref(), Some("checkout-9823"));
+        assert_eq!(agreement.external_user.as_ref().unwrap().id, "user-42");
+    }
+
+    #[test]
+    fn agreement_body() {
+        let builder = AgreementCreateBuilder::new("https://example.com/callback", "checkout-9823")
+            .external_user(ExternalUser::new("user-42").with_description("João"))
+            .agreement_data(AgreementData::new(Decimal::new(1, 0)));
+
+        assert_eq!(
+            serde_json::to_value(&builder.0).unwrap(),
+            serde_json::json!({
+                "return_uri": "https://example.com/callback",
+                "external_flow_id": "checkout-9823",
+                "external_user": { "id": "user-42", "description": "João" },
+                "agreement_data": { "validation_amount": 1.0 },
+            })
+        );
+    }
+}
@@ -1,138 +1,589 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::num::ParseIntError;
-use std::str;
+use std::str::{self, FromStr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use hmac::{Hmac, Mac};
 use serde::Deserialize;
 use sha2::Sha256;
+use thiserror::Error;
+
+use crate::ids::{PaymentId, SubscriptionId, UserId};
+
+#[cfg(feature = "tokio")]
+pub use self::pipeline::{
+    AccessTokenResolver, WebhookEvent, WebhookPipeline, WebhookPipelineError, WebhookWorker,
+};
+
+#[cfg(feature = "tokio")]
+mod pipeline;
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Body of a notification.
+///
+/// Only `id` and `type` are required, the other fields fall back to their defaults when missing or `null`, and unknown fields are ignored, so a valid notification of any topic can be parsed.
+/// Fields that can't be defaulted without looking valid, like `user_id`, are `None` instead.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct WebhookBody {
     #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
     pub id: u64,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub live_mode: bool,
     pub r#type: WebhookType,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub date_created: String,
-    #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
-    pub user_id: u64,
+    /// Id of the seller that received the notification. `None` when missing or not numeric.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub user_id: Option<UserId>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub api_version: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub action: String,
+    #[serde(default)]
     pub data: Option<WebhookData>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct WebhookData {
-    #[serde(deserialize_with = "serde_aux::prelude::deserialize_option_number_from_string")]
-    pub id: Option<u64>,
+    /// Id of the resource, as sent. Numeric for payments and merchant orders, alphanumeric for subscriptions and orders.
+    ///
+    /// `None` when missing or `null`.
+    #[serde(default, deserialize_with = "number_or_string")]
+    pub id: Option<String>,
+}
+
+impl WebhookData {
+    /// [`id`](Self::id) as a number, `None` when it's missing or alphanumeric.
+    pub fn numeric_id(&self) -> Option<u64> {
+        self.id.as_deref()?.parse().ok()
+    }
+
+    /// [`id`](Self::id) as the id of a payment, for notifications of type [`WebhookType::Payment`].
+    pub fn payment_id(&self) -> Option<PaymentId> {
+        self.numeric_id().map(PaymentId::from)
+    }
+
+    /// [`id`](Self::id) as the id of a subscription, for notifications of type [`WebhookType::SubscriptionPreApproval`].
+    pub fn subscription_id(&self) -> Option<SubscriptionId> {
+        self.id.as_deref().map(SubscriptionId::from)
+    }
+}
+
+/// Deserializes `null` as the default value.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Deserializes an id sent as a number or a string into its string form, `None` for anything else.
+fn number_or_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u64),
+        String(String),
+        Other(serde::de::IgnoredAny),
+    }
+
+    Ok(match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(number) => Some(number.to_string()),
+        NumberOrString::String(string) => Some(string.trim().to_string()),
+        NumberOrString::Other(_) => None,
+    })
+}
+
+/// Deserializes a number sent as a number or a string, `None` for anything else.
+fn lenient_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: From<u64>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u64),
+        String(String),
+        Other(serde::de::IgnoredAny),
+    }
+
+    Ok(match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(number) => Some(number.into()),
+        NumberOrString::String(string) => string.trim().parse::<u64>().ok().map(T::from),
+        NumberOrString::Other(_) => None,
+    })
 }
 
 impl WebhookBody {
+    /// Checks if the notification was sent by Mercado Pago, signed with the `data.id` of the body.
+    ///
+    /// Returns `false` when the body has no `data.id`. Prefer [`valid_origin_with`](Self::valid_origin_with) with the `data.id` of the query string, which is what Mercado Pago signs.
     pub fn valid_origin(
         &self,
         key: &[u8],
         x_signature_header: String,
         x_request_id: Option<String>,
     ) -> bool {
-        if let Ok(v) = WebhookHeader::try_from(x_signature_header) {
-            let mut hasher =
-                HmacSha256::new_from_slice(key).expect("HMAC can take key of any size");
-
-            hasher.update(
-                format!(
-                    "id:{};{}ts:{};",
-                    self.id,
-                    if let Some(x_request_id) = x_request_id {
-                        format!("request-id:{};", x_request_id)
-                    } else {
-                        String::new()
-                    },
-                    v.ts
-                )
-                .as_bytes(),
-            );
-
-            let result = hasher.finalize().into_bytes();
-
-            let hash = result.as_slice();
-
-            let mut hash_hex = String::new();
-
-            for byte in hash {
-                hash_hex.push_str(&format!("{:02x}", byte));
-            }
+        self.data_id().is_some_and(|data_id| {
+            self.valid_origin_with(key, data_id, x_signature_header, x_request_id)
+        })
+    }
+}
 
-            hash_hex == v.v1
-        } else {
-            false
+impl WebhookBody {
+    /// `data.id` of the body, the id of the resource. The same as the `data.id` of the query string.
+    pub fn data_id(&self) -> Option<&str> {
+        self.data.as_ref()?.id.as_deref()
+    }
+
+    /// Parses the raw body of a notification and checks it was sent by Mercado Pago, with the secret found by `resolver`.
+    ///
+    /// The signed id is `data_id`, the `data.id` of the query string, or the `data.id` of the body when `None`. Respond with `400` to [`WebhookVerifyError::InvalidBody`] and `401` to [`WebhookVerifyError::InvalidSignature`].
+    ///
+    /// # Example
+    /// ```
+    /// let notification = WebhookBody::verify_and_parse(
+    ///     &body,
+    ///     &secret,
+    ///     query.data_id.as_deref(),
+    ///     &x_signature,
+    ///     x_request_id,
+    /// )?;
+    /// ```
+    pub fn verify_and_parse(
+        body: &[u8],
+        resolver: &(impl SecretResolver + ?Sized),
+        data_id: Option<&str>,
+        x_signature_header: &str,
+        x_request_id: Option<String>,
+    ) -> Result<WebhookBody, WebhookVerifyError> {
+        let notification = serde_json::from_slice::<WebhookBody>(body)?;
+
+        let Some(data_id) = data_id.or(notification.data_id()) else {
+            return Err(WebhookVerifyError::InvalidSignature);
+        };
+
+        if !notification.valid_origin_with(
+            resolver,
+            data_id,
+            x_signature_header.to_string(),
+            x_request_id,
+        ) {
+            return Err(WebhookVerifyError::InvalidSignature);
         }
+
+        Ok(notification)
+    }
+
+    /// Checks if the notification was sent by Mercado Pago, with the secret found by `resolver`.
+    ///
+    /// Returns `false` when `resolver` has no secret for the notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - Secrets of the applications, see [`SecretResolver`].
+    /// * `data_id` - `data.id` of the query string, or [`data_id`](Self::data_id) when the URL is not available.
+    /// * `x_signature_header` - Value of the `x-signature` header.
+    /// * `x_request_id` - Value of the `x-request-id` header, if there is one.
+    pub fn valid_origin_with(
+        &self,
+        resolver: &(impl SecretResolver + ?Sized),
+        data_id: &str,
+        x_signature_header: String,
+        x_request_id: Option<String>,
+    ) -> bool {
+        let Some(secret) = resolver.secret(self) else {
+            return false;
+        };
+        let Ok(header) = x_signature_header.parse::<WebhookHeader>() else {
+            return false;
+        };
+
+        verify(
+            &secret,
+            &SignatureParts {
+                data_id: data_id.to_string(),
+                request_id: x_request_id,
+                ts: header.ts,
+                v1: header.v1,
+            },
+        )
+    }
+}
+
+/// Error of [`WebhookBody::verify_and_parse`]
+#[derive(Error, Debug)]
+pub enum WebhookVerifyError {
+    /// The body is not a notification.
+    #[error("Invalid notification body: {0}")]
+    InvalidBody(#[from] serde_json::Error),
+    /// The notification has no `data.id` to check, or it was not signed by Mercado Pago.
+    #[error("Invalid notification signature")]
+    InvalidSignature,
+}
+
+#[cfg(feature = "point")]
+impl WebhookBody {
+    /// Parses `body`, the raw body of this notification, as a [`PointNotification`](crate::point::types::PointNotification).
+    ///
+    /// Returns `None` when the notification is not of type [`WebhookType::PointIntegrationWh`]. Point notifications that don't parse as [`WebhookBody`] can be parsed directly with [`PointNotification::from_body`](crate::point::types::PointNotification::from_body).
+    pub fn point_notification(
+        &self,
+        body: &[u8],
+    ) -> Option<Result<crate::point::types::PointNotification, serde_json::Error>> {
+        (self.r#type == WebhookType::PointIntegrationWh)
+            .then(|| crate::point::types::PointNotification::from_body(body))
+    }
+}
+
+/// Finds the secret signature used to verify a notification.
+///
+/// In multi-tenant platforms every connected application has its own secret, so one endpoint can verify the notifications of all of them. A single secret (`Vec<u8>`, `[u8]`, `String` or `str`) is a resolver that always returns itself, and a `HashMap<UserId, Vec<u8>>` finds the secret by `user_id`.
+///
+/// # Example
+/// ```
+/// use std::{borrow::Cow, collections::HashMap};
+/// use mpago::{ids::UserId, webhooks::{SecretResolver, WebhookBody}};
+///
+/// struct Tenants(HashMap<UserId, String>);
+///
+/// impl SecretResolver for Tenants {
+///     fn secret(&self, notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+///         notification
+///             .user_id
+///             .and_then(|user_id| self.0.get(&user_id))
+///             .map(|secret| Cow::Borrowed(secret.as_bytes()))
+///     }
+/// }
+///
+/// let valid = body.valid_origin_with(&tenants, &query_data_id, x_signature, x_request_id);
+/// ```
+pub trait SecretResolver: Send + Sync {
+    /// Secret of the application that sent `notification`, `None` if it's unknown.
+    fn secret(&self, notification: &WebhookBody) -> Option<Cow<'_, [u8]>>;
+}
+
+impl SecretResolver for [u8] {
+    fn secret(&self, _notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl SecretResolver for Vec<u8> {
+    fn secret(&self, _notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl SecretResolver for str {
+    fn secret(&self, _notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+        Some(Cow::Borrowed(self.as_bytes()))
+    }
+}
+
+impl SecretResolver for String {
+    fn secret(&self, _notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+        Some(Cow::Borrowed(self.as_bytes()))
+    }
+}
+
+impl SecretResolver for HashMap<UserId, Vec<u8>> {
+    fn secret(&self, notification: &WebhookBody) -> Option<Cow<'_, [u8]>> {
+        notification
+            .user_id
+            .and_then(|user_id| self.get(&user_id))
+            .map(|secret| Cow::Borrowed(secret.as_slice()))
     }
 }
 
+/// Values used to check the origin of a notification.
+pub struct SignatureParts {
+    /// `data.id` of the notification, sent in the query string. Alphanumeric ids are signed in lowercase.
+    pub data_id: String,
+    /// Value of the `x-request-id` header, if there is one.
+    pub request_id: Option<String>,
+    /// `ts` of the `x-signature` header.
+    pub ts: u64,
+    /// `v1` of the `x-signature` header, the hex encoded HMAC-SHA256.
+    pub v1: String,
+}
+
+/// Checks if a notification was sent by Mercado Pago, without needing to deserialize its body first.
+///
+/// # Arguments
+///
+/// * `secret` - Secret signature of your application, shown in the notifications settings.
+/// * `parts` - Values taken from the notification request.
+///
+/// # Example
+/// ```
+/// use mpago::webhooks::{self, SignatureParts, WebhookHeader};
+///
+/// let header = x_signature.parse::<WebhookHeader>()?;
+///
+/// let valid = webhooks::verify(
+///     secret.as_bytes(),
+///     &SignatureParts {
+///         data_id: query_data_id,
+///         request_id: Some(x_request_id.to_string()),
+///         ts: header.ts,
+///         v1: header.v1,
+///     },
+/// );
+/// ```
+///
+/// # Docs
+/// <https://www.mercadopago.com.br/developers/pt/docs/your-integrations/notifications/webhooks>
+pub fn verify(secret: &[u8], parts: &SignatureParts) -> bool {
+    let Some(signature) = decode_hex(&parts.v1) else {
+        return false;
+    };
+
+    let mut hasher = HmacSha256::new_from_slice(secret).expect("HMAC can take key of any size");
+
+    hasher.update(
+        format!(
+            "id:{};{}ts:{};",
+            parts.data_id.to_lowercase(),
+            if let Some(request_id) = &parts.request_id {
+                format!("request-id:{};", request_id)
+            } else {
+                String::new()
+            },
+            parts.ts
+        )
+        .as_bytes(),
+    );
+
+    // Constant time comparison
+    hasher.verify_slice(&signature).is_ok()
+}
+
+/// Decodes a hex string, returning `None` if it has an odd length or an invalid digit.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
 #[derive(Deserialize, PartialEq, Eq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookType {
     Payment,
+    #[serde(alias = "subscription_preapproval")]
     SubscriptionPreApproval,
     SubscriptionPreapprovalPlan,
     SubscriptionAuthorizedPayment,
     PointIntegrationWh,
     TopicClaimsIntegrationWh,
+    TopicMerchantOrderWh,
+    TopicChargebacksWh,
+    /// For untracked topics
+    #[serde(other)]
+    Unknown,
 }
 
+/// Parsed `x-signature` header, like `ts=1704908010,v1=618c85345248dd820d5fd456117c2ab2ef8eda45a0282ff693eac24131a5e839`.
+///
+/// Unknown entries (e.g. a future `v2=`) are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WebhookHeader {
     pub ts: u64,
     pub v1: String,
 }
 
-impl TryFrom<String> for WebhookHeader {
-    type Error = ParseIntError;
+/// Error when parsing an `x-signature` header
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SignatureHeaderError {
+    #[error("x-signature header has no ts")]
+    MissingTs,
+    #[error("x-signature header has no v1")]
+    MissingV1,
+    #[error("x-signature header has an invalid ts: {0}")]
+    InvalidTs(#[from] ParseIntError),
+    #[error("x-signature header has a malformed entry: {0:?}")]
+    MalformedPair(String),
+}
 
-    fn try_from(s: String) -> Result<Self, Self::Error> {
-        let mut ts = 0;
-        let mut v1 = String::new();
+impl FromStr for WebhookHeader {
+    type Err = SignatureHeaderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ts = None;
+        let mut v1 = None;
 
         for pair in s.split(',') {
-            let mut split = pair.split('=');
-            match split.next() {
-                Some("ts") => ts = split.next().unwrap_or("").parse()?,
-                Some("v1") => v1 = split.next().unwrap_or("").to_string(),
+            let Some((key, value)) = pair.split_once('=') else {
+                return Err(SignatureHeaderError::MalformedPair(pair.to_string()));
+            };
+
+            match key.trim() {
+                "ts" => ts = Some(value.trim().parse()?),
+                "v1" => v1 = Some(value.trim().to_string()),
                 _ => {}
             }
         }
 
-        Ok(WebhookHeader { ts, v1 })
+        Ok(WebhookHeader {
+            ts: ts.ok_or(SignatureHeaderError::MissingTs)?,
+            v1: v1
+                .filter(|v1| !v1.is_empty())
+                .ok_or(SignatureHeaderError::MissingV1)?,
+        })
+    }
+}
+
+/// Skips repeated deliveries of the same notification.
+///
+/// Mercado Pago redelivers notifications until it gets a `200`, sometimes more than once even after it, so handlers that need at-most-once side effects should check every notification with it. [`WebhookPipeline::with_deduplicator`] checks them before queueing.
+pub trait NotificationDeduplicator: Send + Sync {
+    /// Returns `true` the first time a notification with this `id` and `action` is seen, and `false` for duplicates.
+    fn should_process(&self, id: u64, action: &str) -> bool;
+}
+
+/// In-memory [`NotificationDeduplicator`] that remembers notifications for a time window.
+///
+/// Only works for a single process. For multiple instances, implement [`NotificationDeduplicator`] over a shared store, like Redis.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use mpago::webhooks::{InMemoryDeduplicator, NotificationDeduplicator};
+///
+/// let deduplicator = InMemoryDeduplicator::new(Duration::from_secs(60 * 10));
+///
+/// if deduplicator.should_process(body.id, &body.action) {
+///     // handle the notification
+/// }
+/// ```
+#[derive(Debug)]
+pub struct InMemoryDeduplicator {
+    window: Duration,
+    seen: Mutex<SeenNotifications>,
+}
+
+/// Notifications of the window, in the order they were received, so the expired ones are at the front.
+#[derive(Debug, Default)]
+struct SeenNotifications {
+    keys: HashSet<(u64, String)>,
+    received: VecDeque<(Instant, (u64, String))>,
+}
+
+impl InMemoryDeduplicator {
+    /// Creates a deduplicator that ignores repeated notifications received within `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            seen: Mutex::new(SeenNotifications::default()),
+        }
+    }
+
+    fn prune(&self, seen: &mut SeenNotifications, now: Instant) {
+        while let Some((received_at, _)) = seen.received.front() {
+            if now.duration_since(*received_at) < self.window {
+                break;
+            }
+
+            if let Some((_, key)) = seen.received.pop_front() {
+                seen.keys.remove(&key);
+            }
+        }
+    }
+}
+
+impl NotificationDeduplicator for InMemoryDeduplicator {
+    fn should_process(&self, id: u64, action: &str) -> bool {
+        let now = Instant::now();
+        let mut seen = self.seen.lock().unwrap_or_else(|err| err.into_inner());
+
+        self.prune(&mut seen, now);
+
+        let key = (id, action.to_string());
+        if !seen.keys.insert(key.clone()) {
+            return false;
+        }
+
+        seen.received.push_back((now, key));
+        true
     }
 }
 
 #[cfg(test)]
 mod webhook_tests {
-    use crate::webhooks::{WebhookBody, WebhookHeader, WebhookType};
+    use crate::webhooks::{
+        verify, InMemoryDeduplicator, NotificationDeduplicator, SignatureHeaderError,
+        SignatureParts, WebhookBody, WebhookData, WebhookHeader, WebhookType, WebhookVerifyError,
+    };
     use hex_literal::hex;
+    use std::{collections::HashMap, time::Duration};
 
     const KEY: &[u8] = &hex!("b00b15");
 
     #[test]
     fn test_webhook_header() {
-        let header =
-            WebhookHeader::try_from("ts=1234567890,v1=1234567890abcdef".to_string()).unwrap();
+        let header = "ts=1234567890,v1=1234567890abcdef"
+            .parse::<WebhookHeader>()
+            .unwrap();
 
         assert_eq!(header.ts, 1234567890);
         assert_eq!(header.v1, "1234567890abcdef");
+
+        let header = "ts=1234567890, v1=1234567890abcdef, v2=fedcba0987654321"
+            .parse::<WebhookHeader>()
+            .unwrap();
+
+        assert_eq!(header.v1, "1234567890abcdef");
+    }
+
+    #[test]
+    fn test_webhook_header_errors() {
+        assert_eq!(
+            "v1=1234567890abcdef".parse::<WebhookHeader>(),
+            Err(SignatureHeaderError::MissingTs)
+        );
+        assert_eq!(
+            "ts=1234567890".parse::<WebhookHeader>(),
+            Err(SignatureHeaderError::MissingV1)
+        );
+        assert_eq!(
+            "ts=1234567890,v1=".parse::<WebhookHeader>(),
+            Err(SignatureHeaderError::MissingV1)
+        );
+        assert!(matches!(
+            "ts=abc,v1=1234567890abcdef".parse::<WebhookHeader>(),
+            Err(SignatureHeaderError::InvalidTs(_))
+        ));
+        assert_eq!(
+            "ts=1234567890,v1:1234567890abcdef".parse::<WebhookHeader>(),
+            Err(SignatureHeaderError::MalformedPair(
+                "v1:1234567890abcdef".to_string()
+            ))
+        );
     }
 
     #[test]
     fn test_webhook_valid_without_request_id() {
         let body = WebhookBody {
-            id: 1234567890,
+            id: 112233445566,
             live_mode: false,
             r#type: WebhookType::Payment,
             date_created: "2021-01-0100:00:00Z".to_string(),
-            user_id: 1234567890,
+            user_id: Some(1234567890.into()),
             api_version: "v1".to_string(),
             action: "payment.created".to_string(),
-            data: None,
+            data: Some(WebhookData {
+                id: Some("1234567890".to_string()),
+            }),
         };
 
         assert!(body.valid_origin(
@@ -146,14 +597,16 @@ mod webhook_tests {
     #[test]
     fn test_webhook_valid_with_request_id() {
         let body = WebhookBody {
-            id: 1234567890,
+            id: 112233445566,
             live_mode: false,
             r#type: WebhookType::Payment,
             date_created: "2021-01-0100:00:00Z".to_string(),
-            user_id: 1234567890,
+            user_id: Some(1234567890.into()),
             api_version: "v1".to_string(),
             action: "payment.created".to_string(),
-            data: None,
+            data: Some(WebhookData {
+                id: Some("1234567890".to_string()),
+            }),
         };
 
         assert!(body.valid_origin(
@@ -163,4 +616,338 @@ mod webhook_tests {
             Some("69420".to_string())
         ));
     }
+
+    #[test]
+    fn test_verify_without_body() {
+        let parts = SignatureParts {
+            data_id: "1234567890".to_string(),
+            request_id: Some("69420".to_string()),
+            ts: 1717037131000,
+            v1: "72fc8fedd2bbe13efdfe045be61872f7ce6004ffda8d22c7440db5fc003503fb".to_string(),
+        };
+
+        assert!(verify(KEY, &parts));
+        assert!(!verify(b"another secret", &parts));
+        assert!(!verify(
+            KEY,
+            &SignatureParts {
+                v1: "not hex".to_string(),
+                ..parts
+            }
+        ));
+    }
+
+    #[test]
+    fn test_webhook_valid_with_resolver() {
+        let body = serde_json::from_str::<WebhookBody>(include_str!(
+            "webhooks/corpus/payment_created.json"
+        ))
+        .unwrap();
+        // Manifest `id:73491648524;request-id:bb56a2f1-6aae-46ac-982e-9dcd3581d08e;ts:1704908010;`, signed with `KEY`
+        let signature =
+            "ts=1704908010,v1=df4ba1c4dfd08bf8bbf497840ee9076055467f186779a74c79369a0dac34e668";
+        let request_id = || Some("bb56a2f1-6aae-46ac-982e-9dcd3581d08e".to_string());
+
+        let mut tenants = HashMap::new();
+        tenants.insert(1234567890.into(), KEY.to_vec());
+        assert!(body.valid_origin_with(
+            &tenants,
+            "73491648524",
+            signature.to_owned(),
+            request_id()
+        ));
+        assert!(body.valid_origin_with(
+            KEY,
+            body.data_id().unwrap(),
+            signature.to_owned(),
+            request_id()
+        ));
+
+        // Signed with `data.id`, not the id of the notification
+        assert!(!body.valid_origin_with(
+            KEY,
+            &body.id.to_string(),
+            signature.to_owned(),
+            request_id()
+        ));
+
+        tenants.insert(1234567890.into(), b"another secret".to_vec());
+        assert!(!body.valid_origin_with(
+            &tenants,
+            "73491648524",
+            signature.to_owned(),
+            request_id()
+        ));
+
+        tenants.clear();
+        assert!(!body.valid_origin_with(
+            &tenants,
+            "73491648524",
+            signature.to_owned(),
+            request_id()
+        ));
+    }
+
+    #[test]
+    fn test_verify_and_parse() {
+        let body = include_bytes!("webhooks/corpus/payment_created.json");
+        let signature =
+            "ts=1704908010,v1=df4ba1c4dfd08bf8bbf497840ee9076055467f186779a74c79369a0dac34e668";
+        let request_id = || Some("bb56a2f1-6aae-46ac-982e-9dcd3581d08e".to_string());
+
+        let notification =
+            WebhookBody::verify_and_parse(body, KEY, None, signature, request_id()).unwrap();
+        assert_eq!(notification.data_id(), Some("73491648524"));
+        assert!(WebhookBody::verify_and_parse(
+            body,
+            KEY,
+            Some("73491648524"),
+            signature,
+            request_id()
+        )
+        .is_ok());
+
+        assert!(matches!(
+            WebhookBody::verify_and_parse(body, KEY, Some("1"), signature, request_id()),
+            Err(WebhookVerifyError::InvalidSignature)
+        ));
+        assert!(matches!(
+            WebhookBody::verify_and_parse(
+                br#"{"id": 1, "type": "payment"}"#,
+                KEY,
+                None,
+                signature,
+                request_id()
+            ),
+            Err(WebhookVerifyError::InvalidSignature)
+        ));
+        assert!(matches!(
+            WebhookBody::verify_and_parse(b"{}", KEY, None, signature, request_id()),
+            Err(WebhookVerifyError::InvalidBody(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_alphanumeric_data_id() {
+        // Manifest `id:2c938084726fca480172750000000000;request-id:bb56a2f1-6aae-46ac-982e-9dcd3581d08e;ts:1704908010;`, signed with `KEY`
+        let parts = SignatureParts {
+            data_id: "2C938084726FCA480172750000000000".to_string(),
+            request_id: Some("bb56a2f1-6aae-46ac-982e-9dcd3581d08e".to_string()),
+            ts: 1704908010,
+            v1: "049851207a22e7a81c27d595624a1b00102371e83c4565c96b9ca9cfddda9971".to_string(),
+        };
+
+        assert!(verify(KEY, &parts));
+    }
+
+    /// Notifications as delivered by Mercado Pago, one per topic and quirk.
+    const CORPUS: &[(&str, &str)] = &[
+        (
+            "payment_created",
+            include_str!("webhooks/corpus/payment_created.json"),
+        ),
+        (
+            "payment_updated_numeric_ids",
+            include_str!("webhooks/corpus/payment_updated_numeric_ids.json"),
+        ),
+        (
+            "subscription_preapproval",
+            include_str!("webhooks/corpus/subscription_preapproval.json"),
+        ),
+        (
+            "subscription_authorized_payment",
+            include_str!("webhooks/corpus/subscription_authorized_payment.json"),
+        ),
+        (
+            "merchant_order_without_data",
+            include_str!("webhooks/corpus/merchant_order_without_data.json"),
+        ),
+        (
+            "chargeback_null_data",
+            include_str!("webhooks/corpus/chargeback_null_data.json"),
+        ),
+        (
+            "unknown_topic",
+            include_str!("webhooks/corpus/unknown_topic.json"),
+        ),
+    ];
+
+    #[test]
+    fn corpus_parses() {
+        for (name, body) in CORPUS {
+            if let Err(err) = serde_json::from_str::<WebhookBody>(body) {
+                panic!("{name} failed to parse: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn corpus_values() {
+        let parse = |name: &str| {
+            let (_, body) = CORPUS.iter().find(|(n, _)| *n == name).unwrap();
+            serde_json::from_str::<WebhookBody>(body).unwrap()
+        };
+
+        let body = parse("payment_created");
+        assert_eq!(body.r#type, WebhookType::Payment);
+        assert_eq!(body.user_id, Some(1234567890.into()));
+        assert_eq!(body.data.unwrap().payment_id(), Some(73491648524.into()));
+
+        let body = parse("payment_updated_numeric_ids");
+        assert_eq!(body.id, 112233445567);
+        assert_eq!(body.data.unwrap().id.as_deref(), Some("73491648524"));
+
+        let body = parse("subscription_preapproval");
+        assert_eq!(body.r#type, WebhookType::SubscriptionPreApproval);
+        let data = body.data.unwrap();
+        assert_eq!(data.numeric_id(), None);
+        assert_eq!(
+            data.subscription_id(),
+            Some("2c938084726fca480172750000000000".into())
+        );
+        assert_eq!(body.user_id, None);
+
+        let body = parse("merchant_order_without_data");
+        assert_eq!(body.r#type, WebhookType::TopicMerchantOrderWh);
+        assert!(body.data.is_none());
+        assert!(!body.live_mode);
+
+        assert!(parse("chargeback_null_data").data.is_none());
+
+        let body = parse("unknown_topic");
+        assert_eq!(body.r#type, WebhookType::Unknown);
+        assert_eq!(
+            body.data.unwrap().id.as_deref(),
+            Some("SDO-01HRSZ2J9Q0TQ5M4P2")
+        );
+    }
+
+    #[test]
+    fn deduplicates_within_window() {
+        let deduplicator = InMemoryDeduplicator::new(Duration::from_secs(60));
+
+        assert!(deduplicator.should_process(1, "payment.updated"));
+        assert!(!deduplicator.should_process(1, "payment.updated"));
+        assert!(deduplicator.should_process(1, "payment.created"));
+        assert!(deduplicator.should_process(2, "payment.updated"));
+    }
+
+    #[test]
+    fn forgets_after_window() {
+        let deduplicator = InMemoryDeduplicator::new(Duration::ZERO);
+
+        assert!(deduplicator.should_process(1, "payment.updated"));
+        assert!(deduplicator.should_process(1, "payment.updated"));
+    }
+
+    #[test]
+    fn deduplicator_evicts_expired_notifications() {
+        let deduplicator = InMemoryDeduplicator::new(Duration::from_millis(50));
+
+        assert!(deduplicator.should_process(1, "payment.updated"));
+        assert!(deduplicator.should_process(2, "payment.updated"));
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(deduplicator.should_process(3, "payment.updated"));
+        assert!(!deduplicator.should_process(3, "payment.updated"));
+
+        let seen = deduplicator.seen.lock().unwrap();
+        assert_eq!(seen.keys.len(), 1);
+        assert_eq!(seen.received.len(), 1);
+    }
+
+    mod properties {
+        use proptest::prelude::*;
+
+        use crate::{
+            fixtures::WEBHOOK_PAYMENT_UPDATED,
+            ids::UserId,
+            webhooks::{WebhookBody, WebhookHeader},
+        };
+
+        /// The notification of the fixture with `user_id` and `data.id` set to `value`.
+        fn body_with(value: serde_json::Value) -> serde_json::Value {
+            let mut json =
+                serde_json::from_str::<serde_json::Value>(WEBHOOK_PAYMENT_UPDATED).unwrap();
+            json["user_id"] = value.clone();
+            json["data"]["id"] = value;
+            json
+        }
+
+        /// JSON values that are neither a `u64` nor a string: negative and fractional numbers, booleans, null, arrays and objects.
+        fn junk() -> impl Strategy<Value = serde_json::Value> {
+            prop_oneof![
+                Just(serde_json::Value::Null),
+                any::<bool>().prop_map(serde_json::Value::from),
+                (i64::MIN..0).prop_map(serde_json::Value::from),
+                any::<f64>()
+                    .prop_filter("fractional", |n| n.fract() != 0.0)
+                    .prop_map(serde_json::Value::from),
+                prop::collection::vec(any::<u64>(), 0..4).prop_map(serde_json::Value::from),
+                prop::collection::hash_map("[a-z]{1,8}", any::<u64>(), 0..4).prop_map(|map| {
+                    serde_json::Value::Object(
+                        map.into_iter()
+                            .map(|(key, value)| (key, value.into()))
+                            .collect(),
+                    )
+                }),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn header_roundtrip(ts: u64, v1 in "[0-9a-f]{1,64}", v2 in "[0-9a-f]{0,64}") {
+                let header = format!("ts={ts},v1={v1},v2={v2}")
+                    .parse::<WebhookHeader>()
+                    .unwrap();
+
+                prop_assert_eq!(header.ts, ts);
+                prop_assert_eq!(header.v1, v1);
+            }
+
+            #[test]
+            fn header_order_does_not_matter(ts: u64, v1 in "[0-9a-f]{1,64}") {
+                let header = format!("v1={v1},ts={ts}").parse::<WebhookHeader>().unwrap();
+
+                prop_assert_eq!(header, WebhookHeader { ts, v1 });
+            }
+
+            #[test]
+            fn header_never_panics(s in "\\PC*") {
+                let _ = s.parse::<WebhookHeader>();
+            }
+
+            #[test]
+            fn ids_from_numbers(id: u64) {
+                let body = serde_json::from_value::<WebhookBody>(body_with(id.into())).unwrap();
+
+                prop_assert_eq!(body.user_id, Some(UserId::from(id)));
+                prop_assert_eq!(body.data.unwrap().id, Some(id.to_string()));
+            }
+
+            #[test]
+            fn ids_from_numeric_strings(id: u64, padding in "[ \t]{0,3}") {
+                let sent = format!("{padding}{id}{padding}");
+                let body = serde_json::from_value::<WebhookBody>(body_with(sent.into())).unwrap();
+
+                prop_assert_eq!(body.user_id, Some(UserId::from(id)));
+                prop_assert_eq!(body.data.unwrap().id, Some(id.to_string()));
+            }
+
+            #[test]
+            fn alphanumeric_ids_are_kept_as_data_id(id in "\\PC*") {
+                let body = serde_json::from_value::<WebhookBody>(body_with(id.clone().into())).unwrap();
+
+                prop_assert_eq!(body.user_id, id.trim().parse::<u64>().ok().map(UserId::from));
+                prop_assert_eq!(body.data.unwrap().id, Some(id.trim().to_string()));
+            }
+
+            #[test]
+            fn junk_ids_are_none(value in junk()) {
+                let body = serde_json::from_value::<WebhookBody>(body_with(value)).unwrap();
+
+                prop_assert_eq!(body.user_id, None);
+                prop_assert_eq!(body.data.unwrap().id, None);
+            }
+        }
+    }
 }
@@ -0,0 +1,581 @@
+use std::{borrow::Cow, collections::HashMap, pin::pin, sync::Arc};
+
+use futures_util::{stream, StreamExt};
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use thiserror::Error;
+
+use super::{
+    NotificationDeduplicator, SecretResolver, WebhookBody, WebhookType, WebhookVerifyError,
+};
+use crate::{
+    client::{MercadoPagoClient, SendRequest},
+    common::MercadoPagoRequestError,
+    ids::UserId,
+    payments::{types::PaymentResponse, PaymentGetBuilder},
+};
+
+/// Notification already verified, with its resource fetched.
+#[derive(Debug)]
+pub enum WebhookEvent {
+    /// Payment notification, with the current state of the payment.
+    Payment {
+        notification: WebhookBody,
+        payment: Box<PaymentResponse>,
+    },
+    /// Notification of a type the pipeline does not fetch.
+    Other(WebhookBody),
+    /// The resource of the notification could not be fetched.
+    FetchFailed {
+        notification: WebhookBody,
+        error: MercadoPagoRequestError,
+    },
+}
+
+/// Error when accepting a notification in [`WebhookPipeline::handle`]
+#[derive(Error, Debug)]
+pub enum WebhookPipelineError {
+    /// The body is not a notification. Respond with `400`.
+    #[error("Invalid notification body: {0}")]
+    InvalidBody(#[from] serde_json::Error),
+    /// The notification was not sent by Mercado Pago. Respond with `401`.
+    #[error("Invalid notification signature")]
+    InvalidSignature,
+    /// The queue is full. Respond with `503`, Mercado Pago will send it again.
+    #[error("Notification queue is full")]
+    QueueFull,
+    /// The [`WebhookWorker`] was dropped.
+    #[error("Notification queue is closed")]
+    Closed,
+}
+
+impl From<WebhookVerifyError> for WebhookPipelineError {
+    fn from(err: WebhookVerifyError) -> Self {
+        match err {
+            WebhookVerifyError::InvalidBody(err) => WebhookPipelineError::InvalidBody(err),
+            WebhookVerifyError::InvalidSignature => WebhookPipelineError::InvalidSignature,
+        }
+    }
+}
+
+/// Finds the access token used to fetch the resources of a notification.
+///
+/// In multi-tenant platforms only the token of the seller that received a notification can read its resources, like the OAuth token created when the seller connected. A `HashMap<UserId, String>` finds the token by `user_id`.
+///
+/// # Example
+/// ```
+/// use std::{borrow::Cow, collections::HashMap};
+/// use mpago::{ids::UserId, webhooks::{AccessTokenResolver, WebhookBody}};
+///
+/// struct Sellers(HashMap<UserId, Seller>);
+///
+/// impl AccessTokenResolver for Sellers {
+///     fn access_token(&self, notification: &WebhookBody) -> Option<Cow<'_, str>> {
+///         notification
+///             .user_id
+///             .and_then(|user_id| self.0.get(&user_id))
+///             .map(|seller| Cow::Borrowed(seller.access_token.as_str()))
+///     }
+/// }
+///
+/// let worker = worker.with_access_tokens(sellers);
+/// ```
+pub trait AccessTokenResolver: Send + Sync {
+    /// Access token of the seller that received `notification`, `None` to fetch with the token of the client.
+    fn access_token(&self, notification: &WebhookBody) -> Option<Cow<'_, str>>;
+}
+
+impl AccessTokenResolver for HashMap<UserId, String> {
+    fn access_token(&self, notification: &WebhookBody) -> Option<Cow<'_, str>> {
+        notification
+            .user_id
+            .and_then(|user_id| self.get(&user_id))
+            .map(|access_token| Cow::Borrowed(access_token.as_str()))
+    }
+}
+
+/// Hands notifications off from the web handler to your business logic.
+///
+/// [`handle`](Self::handle) only verifies and parses the notification, so the handler can respond `200` right away, like Mercado Pago recommends to avoid notification timeouts. The [`WebhookWorker`] fetches the resource out of band and sends a [`WebhookEvent`] to your channel.
+///
+/// # Example
+/// ```
+/// use tokio::sync::mpsc;
+/// use mpago::webhooks::WebhookPipeline;
+///
+/// let (events, mut receiver) = mpsc::channel(100);
+/// let (pipeline, worker) = WebhookPipeline::new(mp_client, secret, events, 100);
+/// tokio::spawn(worker.run());
+///
+/// // In the web handler
+/// match pipeline.handle(&body, query.data_id.as_deref(), &x_signature, x_request_id) {
+///     Ok(()) => StatusCode::OK,
+///     Err(WebhookPipelineError::QueueFull) => StatusCode::SERVICE_UNAVAILABLE,
+///     Err(_) => StatusCode::BAD_REQUEST,
+/// }
+///
+/// // In your business logic
+/// while let Some(event) = receiver.recv().await {
+///     // ...
+/// }
+/// ```
+#[derive(Clone)]
+pub struct WebhookPipeline {
+    secrets: Arc<dyn SecretResolver>,
+    deduplicator: Option<Arc<dyn NotificationDeduplicator>>,
+    queue: mpsc::Sender<WebhookBody>,
+}
+
+impl WebhookPipeline {
+    /// Creates the pipeline and its worker.
+    ///
+    /// # Arguments
+    ///
+    /// * `mp_client` - Client used to fetch the resources.
+    /// * `secret` - Secret signature of your application, shown in the notifications settings.
+    /// * `events` - Channel that receives the events.
+    /// * `capacity` - How many notifications can wait to be fetched before [`handle`](Self::handle) returns [`WebhookPipelineError::QueueFull`].
+    pub fn new(
+        mp_client: MercadoPagoClient,
+        secret: impl Into<Vec<u8>>,
+        events: mpsc::Sender<WebhookEvent>,
+        capacity: usize,
+    ) -> (Self, WebhookWorker) {
+        Self::with_secret_resolver(mp_client, secret.into(), events, capacity)
+    }
+
+    /// Same as [`new`](Self::new), verifying the notifications with the secrets of `secrets`, for endpoints serving many applications.
+    ///
+    /// Set the tokens of the sellers with [`WebhookWorker::with_access_tokens`], the token of `mp_client` can't read the resources of other sellers.
+    ///
+    /// # Example
+    /// ```
+    /// let (pipeline, worker) =
+    ///     WebhookPipeline::with_secret_resolver(mp_client, secrets_by_user_id, events, 100);
+    /// tokio::spawn(worker.with_access_tokens(tokens_by_user_id).run());
+    /// ```
+    pub fn with_secret_resolver(
+        mp_client: MercadoPagoClient,
+        secrets: impl SecretResolver + 'static,
+        events: mpsc::Sender<WebhookEvent>,
+        capacity: usize,
+    ) -> (Self, WebhookWorker) {
+        let (queue, notifications) = mpsc::channel(capacity);
+
+        (
+            Self {
+                secrets: Arc::new(secrets),
+                deduplicator: None,
+                queue,
+            },
+            WebhookWorker {
+                mp_client,
+                access_tokens: None,
+                notifications,
+                events,
+            },
+        )
+    }
+
+    /// Skips repeated deliveries of the same notification, checked by [`handle`](Self::handle) before queueing it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use mpago::webhooks::InMemoryDeduplicator;
+    ///
+    /// let (pipeline, worker) = WebhookPipeline::new(mp_client, secret, events, 100);
+    /// let pipeline =
+    ///     pipeline.with_deduplicator(InMemoryDeduplicator::new(Duration::from_secs(60 * 10)));
+    /// ```
+    pub fn with_deduplicator(
+        mut self,
+        deduplicator: impl NotificationDeduplicator + 'static,
+    ) -> Self {
+        self.deduplicator = Some(Arc::new(deduplicator));
+
+        self
+    }
+
+    /// Verifies and parses a notification, and queues it to be fetched.
+    ///
+    /// With a [deduplicator](Self::with_deduplicator), duplicates return `Ok` without being queued, so the handler still responds `200`. A notification is only marked as seen once there is room for it in the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - Raw body of the request.
+    /// * `data_id` - `data.id` of the query string, the id Mercado Pago signs. The `data.id` of the body is used when `None`.
+    /// * `x_signature` - Value of the `x-signature` header.
+    /// * `x_request_id` - Value of the `x-request-id` header, if there is one.
+    pub fn handle(
+        &self,
+        body: &[u8],
+        data_id: Option<&str>,
+        x_signature: &str,
+        x_request_id: Option<String>,
+    ) -> Result<(), WebhookPipelineError> {
+        let notification = WebhookBody::verify_and_parse(
+            body,
+            self.secrets.as_ref(),
+            data_id,
+            x_signature,
+            x_request_id,
+        )?;
+
+        let permit = self.queue.try_reserve().map_err(|err| match err {
+            TrySendError::Full(_) => WebhookPipelineError::QueueFull,
+            TrySendError::Closed(_) => WebhookPipelineError::Closed,
+        })?;
+
+        if let Some(deduplicator) = &self.deduplicator {
+            if !deduplicator.should_process(notification.id, &notification.action) {
+                return Ok(());
+            }
+        }
+
+        permit.send(notification);
+        Ok(())
+    }
+}
+
+/// Fetches the resources of the notifications accepted by a [`WebhookPipeline`].
+///
+/// Run it with [`run`](Self::run) or [`run_concurrent`](Self::run_concurrent), usually in a spawned task.
+pub struct WebhookWorker {
+    mp_client: MercadoPagoClient,
+    access_tokens: Option<Box<dyn AccessTokenResolver>>,
+    notifications: mpsc::Receiver<WebhookBody>,
+    events: mpsc::Sender<WebhookEvent>,
+}
+
+impl WebhookWorker {
+    /// Fetches the resources with the token of the seller that received each notification, found by `access_tokens`.
+    ///
+    /// Notifications of sellers without a token are fetched with the token of the client.
+    pub fn with_access_tokens(mut self, access_tokens: impl AccessTokenResolver + 'static) -> Self {
+        self.access_tokens = Some(Box::new(access_tokens));
+
+        self
+    }
+
+    /// Processes notifications one at a time until every [`WebhookPipeline`] or the events receiver is dropped.
+    ///
+    /// The events are sent in the order the notifications were accepted.
+    pub async fn run(self) {
+        self.run_concurrent(1).await
+    }
+
+    /// Like [`run`](Self::run), fetching up to `limit` resources at the same time, so a slow request doesn't hold the notifications behind it.
+    ///
+    /// The events are sent as soon as their resource is fetched, not in the order of the notifications. A `limit` of `0` is the same as `1`.
+    ///
+    /// # Example
+    /// ```
+    /// let (pipeline, worker) = WebhookPipeline::new(mp_client, secret, events, 100);
+    /// tokio::spawn(worker.run_concurrent(8));
+    /// ```
+    pub async fn run_concurrent(self, limit: usize) {
+        let WebhookWorker {
+            mp_client,
+            access_tokens,
+            notifications,
+            events,
+        } = self;
+        let access_tokens = access_tokens.as_deref();
+
+        let notifications = stream::unfold(notifications, |mut notifications| async move {
+            let notification = notifications.recv().await?;
+            Some((notification, notifications))
+        });
+        let mut fetched = pin!(notifications
+            .map(|notification| fetch(&mp_client, access_tokens, notification))
+            .buffer_unordered(limit.max(1)));
+
+        while let Some(event) = fetched.next().await {
+            if events.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+async fn fetch(
+    mp_client: &MercadoPagoClient,
+    access_tokens: Option<&dyn AccessTokenResolver>,
+    notification: WebhookBody,
+) -> WebhookEvent {
+    let payment_id = match (&notification.r#type, &notification.data) {
+        (WebhookType::Payment, Some(data)) => data.payment_id(),
+        _ => None,
+    };
+
+    let Some(payment_id) = payment_id else {
+        return WebhookEvent::Other(notification);
+    };
+
+    let access_token = access_tokens.and_then(|tokens| tokens.access_token(&notification));
+    let payment = match access_token {
+        Some(access_token) => {
+            PaymentGetBuilder(payment_id)
+                .send_as(mp_client, access_token)
+                .await
+        }
+        None => PaymentGetBuilder(payment_id).send(mp_client).await,
+    };
+
+    match payment {
+        Ok(payment) => WebhookEvent::Payment {
+            notification,
+            payment: Box::new(payment),
+        },
+        Err(error) => WebhookEvent::FetchFailed {
+            notification,
+            error,
+        },
+    }
+}
+
+#[cfg(test)]
+mod pipeline_tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use hex_literal::hex;
+    use tokio::sync::mpsc;
+
+    use super::{WebhookEvent, WebhookPipeline, WebhookPipelineError};
+    use crate::{
+        client::MercadoPagoClientBuilder,
+        fixtures::{PAYMENT_APPROVED_PIX, PAYMENT_PENDING_BOLETO, WEBHOOK_PAYMENT_UPDATED},
+        test_support::{serve, serve_in_groups, MockResponse},
+        webhooks::{InMemoryDeduplicator, WebhookBody},
+    };
+
+    /// Manifest `id:1234567890;ts:1717037131000;`, signed with `b00b15`.
+    const SIGNATURE: &str =
+        "ts=1717037131000,v1=aace269406ac439a100b7a06480cf7c1d84c46fab0ce24e5acd0ca363847953b";
+
+    const BODY: &[u8] = br#"{
+        "id": 112233445566,
+        "live_mode": false,
+        "type": "point_integration_wh",
+        "date_created": "2021-01-0100:00:00Z",
+        "user_id": 1234567890,
+        "api_version": "v1",
+        "action": "updated",
+        "data": {"id": "1234567890"}
+    }"#;
+
+    fn pipeline(
+        capacity: usize,
+    ) -> (
+        WebhookPipeline,
+        super::WebhookWorker,
+        mpsc::Receiver<WebhookEvent>,
+    ) {
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN").build();
+        let (events, receiver) = mpsc::channel(1);
+        let (pipeline, worker) = WebhookPipeline::new(mp_client, hex!("b00b15"), events, capacity);
+
+        (pipeline, worker, receiver)
+    }
+
+    #[test]
+    fn rejects_invalid_notifications() {
+        let (pipeline, _worker, _) = pipeline(1);
+
+        assert!(matches!(
+            pipeline.handle(b"{}", None, SIGNATURE, None),
+            Err(WebhookPipelineError::InvalidBody(_))
+        ));
+        assert!(matches!(
+            pipeline.handle(BODY, None, "ts=1717037131000,v1=00", None),
+            Err(WebhookPipelineError::InvalidSignature)
+        ));
+        // Signed with `data.id`, not the id of the notification
+        assert!(matches!(
+            pipeline.handle(BODY, Some("112233445566"), SIGNATURE, None),
+            Err(WebhookPipelineError::InvalidSignature)
+        ));
+        assert!(matches!(
+            pipeline.handle(
+                br#"{"id": 1234567890, "type": "point_integration_wh"}"#,
+                None,
+                SIGNATURE,
+                None
+            ),
+            Err(WebhookPipelineError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn queue_is_bounded() {
+        let (pipeline, worker, _) = pipeline(1);
+
+        assert!(pipeline
+            .handle(BODY, Some("1234567890"), SIGNATURE, None)
+            .is_ok());
+        assert!(matches!(
+            pipeline.handle(BODY, None, SIGNATURE, None),
+            Err(WebhookPipelineError::QueueFull)
+        ));
+
+        drop(worker);
+        assert!(matches!(
+            pipeline.handle(BODY, None, SIGNATURE, None),
+            Err(WebhookPipelineError::Closed)
+        ));
+    }
+
+    #[test]
+    fn deduplicator_skips_redeliveries() {
+        let (pipeline, mut worker, _) = pipeline(2);
+        let pipeline =
+            pipeline.with_deduplicator(InMemoryDeduplicator::new(Duration::from_secs(60)));
+
+        assert!(pipeline.handle(BODY, None, SIGNATURE, None).is_ok());
+        assert!(pipeline.handle(BODY, None, SIGNATURE, None).is_ok());
+        assert!(worker.notifications.try_recv().is_ok());
+        assert!(worker.notifications.try_recv().is_err());
+
+        // A notification refused with a full queue is accepted when delivered again
+        let other = std::str::from_utf8(BODY)
+            .unwrap()
+            .replace("112233445566", "112233445567");
+        for _ in 0..2 {
+            let notification = serde_json::from_slice(BODY).unwrap();
+            pipeline.queue.try_send(notification).unwrap();
+        }
+        assert!(matches!(
+            pipeline.handle(other.as_bytes(), None, SIGNATURE, None),
+            Err(WebhookPipelineError::QueueFull)
+        ));
+        assert!(worker.notifications.try_recv().is_ok());
+        assert!(pipeline
+            .handle(other.as_bytes(), None, SIGNATURE, None)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn worker_sends_events() {
+        let (pipeline, worker, mut receiver) = pipeline(1);
+        tokio::spawn(worker.run());
+
+        pipeline.handle(BODY, None, SIGNATURE, None).unwrap();
+
+        assert!(matches!(
+            receiver.recv().await,
+            Some(WebhookEvent::Other(notification)) if notification.action == "updated"
+        ));
+    }
+
+    #[tokio::test]
+    async fn concurrent_worker_overlaps_fetches() {
+        // Responds only when both payments are requested at the same time
+        let url = serve_in_groups(2, |request| {
+            let body = if request.head.starts_with("get /v1/payments/1319541963 ") {
+                PAYMENT_APPROVED_PIX
+            } else {
+                PAYMENT_PENDING_BOLETO
+            };
+            MockResponse::new("200 OK", "application/json", body.as_bytes())
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("TEST_ACCESS_TOKEN")
+            .with_base_url(url)
+            .build();
+        let (events, mut receiver) = mpsc::channel(2);
+        let (pipeline, worker) = WebhookPipeline::new(mp_client, hex!("b00b15"), events, 2);
+        tokio::spawn(worker.run_concurrent(2));
+
+        let pix: WebhookBody = serde_json::from_str(WEBHOOK_PAYMENT_UPDATED).unwrap();
+        let boleto: WebhookBody =
+            serde_json::from_str(&WEBHOOK_PAYMENT_UPDATED.replace("1319541963", "1319541964"))
+                .unwrap();
+        pipeline.queue.try_send(pix).unwrap();
+        pipeline.queue.try_send(boleto).unwrap();
+
+        let mut ids = vec![];
+        for _ in 0..2 {
+            let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+                .await
+                .expect("fetches should overlap");
+            match event {
+                Some(WebhookEvent::Payment { payment, .. }) => ids.push(u64::from(payment.id)),
+                event => panic!("unexpected event {event:?}"),
+            }
+        }
+        ids.sort();
+        assert_eq!(ids, [1319541963, 1319541964]);
+    }
+
+    #[tokio::test]
+    async fn worker_fetches_with_the_token_of_each_seller() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = serve({
+            let requests = requests.clone();
+            move |request| {
+                let (line, authorization) = (
+                    request.head.lines().next().unwrap().to_string(),
+                    request.header("authorization").unwrap().to_string(),
+                );
+                let body = if line.starts_with("get /v1/payments/1319541963 ") {
+                    PAYMENT_APPROVED_PIX
+                } else {
+                    PAYMENT_PENDING_BOLETO
+                };
+                requests.lock().unwrap().push((line, authorization));
+                MockResponse::new("200 OK", "application/json", body.as_bytes())
+            }
+        })
+        .await;
+        let mp_client = MercadoPagoClientBuilder::builder("platform_token")
+            .with_base_url(url)
+            .build();
+        let (events, mut receiver) = mpsc::channel(3);
+        let (pipeline, worker) = WebhookPipeline::new(mp_client, hex!("b00b15"), events, 3);
+        let tokens = HashMap::from([
+            (1822339427.into(), "seller_a_token".to_string()),
+            (1822339428.into(), "seller_b_token".to_string()),
+        ]);
+        tokio::spawn(worker.with_access_tokens(tokens).run());
+
+        for (user_id, payment_id) in [
+            ("1822339427", "1319541963"),
+            ("1822339428", "1319541964"),
+            ("1822339429", "1319541965"),
+        ] {
+            let notification: WebhookBody = serde_json::from_str(
+                &WEBHOOK_PAYMENT_UPDATED
+                    .replace("1822339427", user_id)
+                    .replace("1319541963", payment_id),
+            )
+            .unwrap();
+            pipeline.queue.try_send(notification).unwrap();
+        }
+        for _ in 0..3 {
+            assert!(matches!(
+                receiver.recv().await,
+                Some(WebhookEvent::Payment { .. })
+            ));
+        }
+
+        let requests = requests.lock().unwrap();
+        let sent = requests
+            .iter()
+            .map(|(line, authorization)| (line.split(' ').nth(1).unwrap(), authorization.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sent,
+            [
+                ("/v1/payments/1319541963", "bearer seller_a_token"),
+                ("/v1/payments/1319541964", "bearer seller_b_token"),
+                // Sellers without a token are fetched with the token of the client
+                ("/v1/payments/1319541965", "bearer platform_token"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+/// Checks the public API keeps the bounds integrators rely on, like `Send` futures.
+#[test]
+fn public_api_bounds() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/send_request_spawn.rs");
+    t.pass("tests/ui/search_stream_spawn.rs");
+}
@@ -0,0 +1,20 @@
+use futures_util::StreamExt;
+use mpago::{
+    client::MercadoPagoClient,
+    payments::{types::PaymentSearchOptions, PaymentSearchBuilder},
+};
+
+/// The owned stream must be movable into a spawned task.
+fn spawn_search(mp_client: MercadoPagoClient) -> tokio::task::JoinHandle<bool> {
+    tokio::spawn(async move {
+        let mut payments = PaymentSearchBuilder(PaymentSearchOptions::default())
+            .fetch_all_streamed_owned(mp_client)
+            .await;
+
+        payments.next().await.is_some()
+    })
+}
+
+fn main() {
+    let _ = spawn_search;
+}
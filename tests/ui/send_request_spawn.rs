@@ -0,0 +1,16 @@
+use mpago::{
+    client::{MercadoPagoClient, SendRequest},
+    payments::PaymentGetBuilder,
+};
+
+/// Generic code over any request must be able to await it in a spawned task.
+fn spawn_send<R>(request: R, mp_client: MercadoPagoClient) -> tokio::task::JoinHandle<bool>
+where
+    R: SendRequest + Send + 'static,
+{
+    tokio::spawn(async move { request.send(&mp_client).await.is_ok() })
+}
+
+fn main() {
+    let _ = spawn_send::<PaymentGetBuilder>;
+}